@@ -0,0 +1,9 @@
+// Code generated by wit-bindgen-go. DO NOT EDIT.
+
+package logging
+
+// This file contains wasmimport and wasmexport declarations for "example:reconciler@0.2.0".
+
+//go:wasmimport example:reconciler/logging@0.2.0 log
+//go:noescape
+func wasmimport_Log(level0 uint32, message0 *uint8, message1 uint32, fields0 *Field, fields1 uint32)
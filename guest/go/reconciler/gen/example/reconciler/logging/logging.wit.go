@@ -0,0 +1,70 @@
+// Code generated by wit-bindgen-go. DO NOT EDIT.
+
+// Package logging represents the imported interface "example:reconciler/logging@0.2.0".
+package logging
+
+import (
+	"go.bytecodealliance.org/cm"
+)
+
+// Level represents the enum "example:reconciler/logging@0.2.0#level".
+//
+//	enum level {
+//		trace,
+//		debug,
+//		info,
+//		warn,
+//		error
+//	}
+type Level uint8
+
+const (
+	LevelTrace Level = iota
+	LevelDebug
+	LevelInfo
+	LevelWarn
+	LevelError
+)
+
+var stringsLevel = [5]string{
+	"trace",
+	"debug",
+	"info",
+	"warn",
+	"error",
+}
+
+// String implements [fmt.Stringer], returning the enum case name of e.
+func (e Level) String() string {
+	return stringsLevel[e]
+}
+
+// Field represents the record "example:reconciler/logging@0.2.0#field".
+//
+// A structured key/value pair attached to a log record.
+//
+//	record field {
+//		key: string,
+//		value: string,
+//	}
+type Field struct {
+	_     cm.HostLayout
+	Key   string
+	Value string
+}
+
+// Log represents the imported function "log".
+//
+// Emit a record into the host log. The host attaches the component
+// and the key of the reconciled object, so records of a guest can be
+// filtered like the logs of the host.
+//
+//	log: func(level: level, message: string, fields: list<field>)
+//
+//go:nosplit
+func Log(level Level, message string, fields cm.List[Field]) {
+	message0, message1 := cm.LowerString(message)
+	fields0, fields1 := cm.LowerList(fields)
+	wasmimport_Log((uint32)(level), (*uint8)(message0), (uint32)(message1), (*Field)(fields0), (uint32)(fields1))
+	return
+}
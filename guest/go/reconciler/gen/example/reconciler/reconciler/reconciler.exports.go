@@ -6,12 +6,13 @@ import (
 	"go.bytecodealliance.org/cm"
 )
 
-// Exports represents the caller-defined exports from "example:reconciler/reconciler@0.1.0".
+// Exports represents the caller-defined exports from "example:reconciler/reconciler@0.2.0".
 var Exports struct {
 	// Reconcile represents the caller-defined, exported function "reconcile".
 	//
 	// The `reconcile` function is the main entry point for the reconciler.
-	// It takes a JSON input and returns a JSON output or an error.
+	// It takes the object to reconcile as JSON and returns its status,
+	// desired children and events, or an error.
 	//
 	//	reconcile: func(object: string) -> result<reconcile-result, reconcile-error>
 	Reconcile func(object string) (result cm.Result[ReconcileResultShape, ReconcileResult, ReconcileError])
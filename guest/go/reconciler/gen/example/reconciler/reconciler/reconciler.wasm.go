@@ -6,7 +6,7 @@ import (
 	"go.bytecodealliance.org/cm"
 )
 
-// This file contains wasmimport and wasmexport declarations for "example:reconciler@0.1.0".
+// This file contains wasmimport and wasmexport declarations for "example:reconciler@0.2.0".
 
 //go:wasmexport reconcile
 //export reconcile
@@ -1,6 +1,6 @@
 // Code generated by wit-bindgen-go. DO NOT EDIT.
 
-// Package reconciler represents the world "example:reconciler/reconciler@0.1.0".
+// Package reconciler represents the world "example:reconciler/reconciler@0.2.0".
 //
 // Represents the reconciler world
 package reconciler
@@ -9,21 +9,72 @@ import (
 	"go.bytecodealliance.org/cm"
 )
 
-// ReconcileResult represents the record "example:reconciler/reconciler@0.1.0#reconcile-result".
+// EventKind represents the enum "example:reconciler/reconciler@0.2.0#event-kind".
+//
+//	enum event-kind {
+//		normal,
+//		warning
+//	}
+type EventKind uint8
+
+const (
+	EventKindNormal EventKind = iota
+	EventKindWarning
+)
+
+var stringsEventKind = [2]string{
+	"normal",
+	"warning",
+}
+
+// String implements [fmt.Stringer], returning the enum case name of e.
+func (e EventKind) String() string {
+	return stringsEventKind[e]
+}
+
+// Event represents the record "example:reconciler/reconciler@0.2.0#event".
+//
+// An event about the reconciled object, modeled after Kubernetes events.
+//
+//	record event {
+//		kind: event-kind,
+//		reason: string,
+//		message: string,
+//	}
+type Event struct {
+	_    cm.HostLayout
+	Kind EventKind
+
+	// Short CamelCase reason, e.g. `NodesCreated`.
+	Reason  string
+	Message string
+}
+
+// ReconcileResult represents the record "example:reconciler/reconciler@0.2.0#reconcile-result".
 //
 //	record reconcile-result {
 //		requeue: bool,
 //		requeue-after: u32,
-//		object: string,
+//		status: option<string>,
+//		children: list<string>,
+//		events: list<event>,
 //	}
 type ReconcileResult struct {
 	_            cm.HostLayout
 	Requeue      bool
 	RequeueAfter uint32
-	Object       string
+
+	// The new `status` of the reconciled object as a JSON object,
+	// none leaves the stored status unchanged.
+	Status cm.Option[string]
+
+	// The desired child objects as JSON, the host creates or updates them.
+	// Children without a namespace get the namespace of the reconciled object.
+	Children cm.List[string]
+	Events   cm.List[Event]
 }
 
-// ReconcileError represents the record "example:reconciler/reconciler@0.1.0#reconcile-error".
+// ReconcileError represents the record "example:reconciler/reconciler@0.2.0#reconcile-error".
 //
 //	record reconcile-error {
 //		code: u32,
@@ -0,0 +1,33 @@
+// Code generated by wit-bindgen-go. DO NOT EDIT.
+
+package retrieve
+
+import (
+	"go.bytecodealliance.org/cm"
+	"unsafe"
+)
+
+// LookupErrorShape is used for storage in variant or result types.
+type LookupErrorShape struct {
+	_     cm.HostLayout
+	shape [unsafe.Sizeof(LookupError{})]byte
+}
+
+func lower_OptionString(v cm.Option[string]) (f0 uint32, f1 *uint8, f2 uint32) {
+	some := v.Some()
+	if some != nil {
+		f0 = 1
+		v1, v2 := cm.LowerString(*some)
+		f1 = (*uint8)(v1)
+		f2 = (uint32)(v2)
+	}
+	return
+}
+
+func lower_ObjectRef(v ObjectRef) (f0 *uint8, f1 uint32, f2 *uint8, f3 uint32, f4 uint32, f5 *uint8, f6 uint32, f7 *uint8, f8 uint32) {
+	f0, f1 = cm.LowerString(v.APIVersion)
+	f2, f3 = cm.LowerString(v.Kind)
+	f4, f5, f6 = lower_OptionString(v.Namespace)
+	f7, f8 = cm.LowerString(v.Name)
+	return
+}
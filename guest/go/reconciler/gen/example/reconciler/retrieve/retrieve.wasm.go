@@ -2,8 +2,16 @@
 
 package retrieve
 
-// This file contains wasmimport and wasmexport declarations for "example:reconciler@0.1.0".
+import (
+	"go.bytecodealliance.org/cm"
+)
 
-//go:wasmimport example:reconciler/retrieve@0.1.0 get
+// This file contains wasmimport and wasmexport declarations for "example:reconciler@0.2.0".
+
+//go:wasmimport example:reconciler/retrieve@0.2.0 get
+//go:noescape
+func wasmimport_Get(reference0 *uint8, reference1 uint32, reference2 *uint8, reference3 uint32, reference4 uint32, reference5 *uint8, reference6 uint32, reference7 *uint8, reference8 uint32, result *cm.Result[LookupErrorShape, string, LookupError])
+
+//go:wasmimport example:reconciler/retrieve@0.2.0 list-objects
 //go:noescape
-func wasmimport_Get(name0 *uint8, name1 uint32, result *string)
+func wasmimport_ListObjects(kind0 *uint8, kind1 uint32, namespace0 uint32, namespace1 *uint8, namespace2 uint32, labelSelector0 *uint8, labelSelector1 uint32, result *cm.Result[LookupErrorShape, cm.List[string], LookupError])
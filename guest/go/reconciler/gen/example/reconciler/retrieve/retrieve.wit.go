@@ -1,19 +1,106 @@
 // Code generated by wit-bindgen-go. DO NOT EDIT.
 
-// Package retrieve represents the imported interface "example:reconciler/retrieve@0.1.0".
+// Package retrieve represents the imported interface "example:reconciler/retrieve@0.2.0".
 package retrieve
 
 import (
 	"go.bytecodealliance.org/cm"
 )
 
+// ObjectRef represents the record "example:reconciler/retrieve@0.2.0#object-ref".
+//
+// Identifies an object in the host object store.
+//
+//	record object-ref {
+//		api-version: string,
+//		kind: string,
+//		namespace: option<string>,
+//		name: string,
+//	}
+type ObjectRef struct {
+	_          cm.HostLayout
+	APIVersion string
+	Kind       string
+
+	// None for cluster-scoped objects.
+	Namespace cm.Option[string]
+	Name      string
+}
+
+// LookupError represents the variant "example:reconciler/retrieve@0.2.0#lookup-error".
+//
+//	variant lookup-error {
+//		not-found,
+//		invalid(string),
+//	}
+type LookupError cm.Variant[uint8, string, string]
+
+// LookupErrorNotFound returns a [LookupError] of case "not-found".
+//
+// No object exists for the reference.
+func LookupErrorNotFound() LookupError {
+	var data struct{}
+	return cm.New[LookupError](0, data)
+}
+
+// NotFound returns true if [LookupError] represents the variant case "not-found".
+func (self *LookupError) NotFound() bool {
+	return self.Tag() == 0
+}
+
+// LookupErrorInvalid returns a [LookupError] of case "invalid".
+//
+// The reference or label selector is malformed.
+func LookupErrorInvalid(data string) LookupError {
+	return cm.New[LookupError](1, data)
+}
+
+// Invalid returns a non-nil *[string] if [LookupError] represents the variant case "invalid".
+func (self *LookupError) Invalid() *string {
+	return cm.Case[string](self, 1)
+}
+
+var stringsLookupError = [2]string{
+	"not-found",
+	"invalid",
+}
+
+// String implements [fmt.Stringer], returning the variant case name of v.
+func (v LookupError) String() string {
+	return stringsLookupError[v.Tag()]
+}
+
 // Get represents the imported function "get".
 //
-//	get: func(name: string) -> string
+// Look up an object in the host object store.
+// Returns the object as JSON.
+//
+//	get: func(reference: object-ref) -> result<string, lookup-error>
+//
+//go:nosplit
+func Get(reference ObjectRef) (result cm.Result[LookupErrorShape, string, LookupError]) {
+	reference0, reference1, reference2, reference3, reference4, reference5, reference6, reference7, reference8 := lower_ObjectRef(reference)
+	wasmimport_Get((*uint8)(reference0), (uint32)(reference1), (*uint8)(reference2), (uint32)(reference3), (uint32)(reference4), (*uint8)(reference5), (uint32)(reference6), (*uint8)(reference7), (uint32)(reference8), &result)
+	return
+}
+
+// ListObjects represents the imported function "list-objects".
+//
+// List the objects of a kind as JSON, sorted by namespace and name.
+// Objects of every apiVersion of the kind are listed, tell them apart by
+// their `apiVersion`.
+// Without a namespace objects of all namespaces are listed.
+// The label selector uses the Kubernetes syntax, e.g.
+// `topo.kubenet.dev/topology=kubenet,role in (leaf,spine)`,
+// an empty selector matches every object.
+//
+//	list-objects: func(kind: string, namespace: option<string>, label-selector: string) -> result<list<string>, lookup-error>
 //
 //go:nosplit
-func Get(name string) (result string) {
-	name0, name1 := cm.LowerString(name)
-	wasmimport_Get((*uint8)(name0), (uint32)(name1), &result)
+func ListObjects(kind string, namespace cm.Option[string], labelSelector string) (result cm.Result[LookupErrorShape, cm.List[string], LookupError]) {
+	kind0, kind1 := cm.LowerString(kind)
+	namespace0, namespace1, namespace2 := lower_OptionString(namespace)
+	labelSelector0, labelSelector1 := cm.LowerString(labelSelector)
+	wasmimport_ListObjects((*uint8)(kind0), (uint32)(kind1), (uint32)(namespace0), (*uint8)(namespace1), (uint32)(namespace2), (*uint8)(labelSelector0), (uint32)(labelSelector1), &result)
 	return
 }
@@ -0,0 +1,25 @@
+// Code generated by wit-bindgen-go. DO NOT EDIT.
+
+package writer
+
+import (
+	"go.bytecodealliance.org/cm"
+)
+
+// This file contains wasmimport and wasmexport declarations for "example:reconciler@0.2.0".
+
+//go:wasmimport example:reconciler/writer@0.2.0 create
+//go:noescape
+func wasmimport_Create(object0 *uint8, object1 uint32, result *cm.Result[WriteErrorShape, string, WriteError])
+
+//go:wasmimport example:reconciler/writer@0.2.0 update
+//go:noescape
+func wasmimport_Update(object0 *uint8, object1 uint32, result *cm.Result[WriteErrorShape, string, WriteError])
+
+//go:wasmimport example:reconciler/writer@0.2.0 patch
+//go:noescape
+func wasmimport_Patch(reference0 *uint8, reference1 uint32, reference2 *uint8, reference3 uint32, reference4 uint32, reference5 *uint8, reference6 uint32, reference7 *uint8, reference8 uint32, patch0 *uint8, patch1 uint32, result *cm.Result[WriteErrorShape, string, WriteError])
+
+//go:wasmimport example:reconciler/writer@0.2.0 delete
+//go:noescape
+func wasmimport_Delete(reference0 *uint8, reference1 uint32, reference2 *uint8, reference3 uint32, reference4 uint32, reference5 *uint8, reference6 uint32, reference7 *uint8, reference8 uint32, resourceVersion0 uint32, resourceVersion1 *uint8, resourceVersion2 uint32, result *cm.Result[WriteError, struct{}, WriteError])
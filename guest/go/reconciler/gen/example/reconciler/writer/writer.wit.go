@@ -0,0 +1,145 @@
+// Code generated by wit-bindgen-go. DO NOT EDIT.
+
+// Package writer represents the imported interface "example:reconciler/writer@0.2.0".
+package writer
+
+import (
+	"github.com/henderiw/reconciler/gen/example/reconciler/retrieve"
+	"go.bytecodealliance.org/cm"
+)
+
+// ObjectRef represents the type alias "example:reconciler/writer@0.2.0#object-ref".
+//
+// See [retrieve.ObjectRef] for more information.
+type ObjectRef = retrieve.ObjectRef
+
+// WriteError represents the variant "example:reconciler/writer@0.2.0#write-error".
+//
+//	variant write-error {
+//		not-found,
+//		already-exists,
+//		conflict(string),
+//		invalid(string),
+//	}
+type WriteError cm.Variant[uint8, string, string]
+
+// WriteErrorNotFound returns a [WriteError] of case "not-found".
+//
+// The object to update, patch or delete does not exist.
+func WriteErrorNotFound() WriteError {
+	var data struct{}
+	return cm.New[WriteError](0, data)
+}
+
+// NotFound returns true if [WriteError] represents the variant case "not-found".
+func (self *WriteError) NotFound() bool {
+	return self.Tag() == 0
+}
+
+// WriteErrorAlreadyExists returns a [WriteError] of case "already-exists".
+//
+// The object to create already exists.
+func WriteErrorAlreadyExists() WriteError {
+	var data struct{}
+	return cm.New[WriteError](1, data)
+}
+
+// AlreadyExists returns true if [WriteError] represents the variant case "already-exists".
+func (self *WriteError) AlreadyExists() bool {
+	return self.Tag() == 1
+}
+
+// WriteErrorConflict returns a [WriteError] of case "conflict".
+//
+// The resource version of the write does not match the stored object.
+func WriteErrorConflict(data string) WriteError {
+	return cm.New[WriteError](2, data)
+}
+
+// Conflict returns a non-nil *[string] if [WriteError] represents the variant case "conflict".
+func (self *WriteError) Conflict() *string {
+	return cm.Case[string](self, 2)
+}
+
+// WriteErrorInvalid returns a [WriteError] of case "invalid".
+//
+// The object or patch is malformed.
+func WriteErrorInvalid(data string) WriteError {
+	return cm.New[WriteError](3, data)
+}
+
+// Invalid returns a non-nil *[string] if [WriteError] represents the variant case "invalid".
+func (self *WriteError) Invalid() *string {
+	return cm.Case[string](self, 3)
+}
+
+var stringsWriteError = [4]string{
+	"not-found",
+	"already-exists",
+	"conflict",
+	"invalid",
+}
+
+// String implements [fmt.Stringer], returning the variant case name of v.
+func (v WriteError) String() string {
+	return stringsWriteError[v.Tag()]
+}
+
+// Create represents the imported function "create".
+//
+// Create an object given as JSON, `metadata.resourceVersion` must not be set.
+// Returns the stored object with its resource version.
+//
+//	create: func(object: string) -> result<string, write-error>
+//
+//go:nosplit
+func Create(object string) (result cm.Result[WriteErrorShape, string, WriteError]) {
+	object0, object1 := cm.LowerString(object)
+	wasmimport_Create((*uint8)(object0), (uint32)(object1), &result)
+	return
+}
+
+// Update represents the imported function "update".
+//
+// Replace an object given as JSON. When `metadata.resourceVersion` is set
+// it must match the stored object.
+// Returns the stored object with its new resource version.
+//
+//	update: func(object: string) -> result<string, write-error>
+//
+//go:nosplit
+func Update(object string) (result cm.Result[WriteErrorShape, string, WriteError]) {
+	object0, object1 := cm.LowerString(object)
+	wasmimport_Update((*uint8)(object0), (uint32)(object1), &result)
+	return
+}
+
+// Patch represents the imported function "patch".
+//
+// Apply a JSON merge patch (RFC 7386) to an object. When the patch sets
+// `metadata.resourceVersion` it must match the stored object.
+// Returns the stored object with its new resource version.
+//
+//	patch: func(reference: object-ref, patch: string) -> result<string, write-error>
+//
+//go:nosplit
+func Patch(reference ObjectRef, patch string) (result cm.Result[WriteErrorShape, string, WriteError]) {
+	reference0, reference1, reference2, reference3, reference4, reference5, reference6, reference7, reference8 := lower_ObjectRef(reference)
+	patch0, patch1 := cm.LowerString(patch)
+	wasmimport_Patch((*uint8)(reference0), (uint32)(reference1), (*uint8)(reference2), (uint32)(reference3), (uint32)(reference4), (*uint8)(reference5), (uint32)(reference6), (*uint8)(reference7), (uint32)(reference8), (*uint8)(patch0), (uint32)(patch1), &result)
+	return
+}
+
+// Delete represents the imported function "delete".
+//
+// Delete an object. When a resource version is given it must match the stored object.
+//
+//	delete: func(reference: object-ref, resource-version: option<string>) -> result<_, write-error>
+//
+//go:nosplit
+func Delete(reference ObjectRef, resourceVersion cm.Option[string]) (result cm.Result[WriteError, struct{}, WriteError]) {
+	reference0, reference1, reference2, reference3, reference4, reference5, reference6, reference7, reference8 := lower_ObjectRef(reference)
+	resourceVersion0, resourceVersion1, resourceVersion2 := lower_OptionString(resourceVersion)
+	wasmimport_Delete((*uint8)(reference0), (uint32)(reference1), (*uint8)(reference2), (uint32)(reference3), (uint32)(reference4), (*uint8)(reference5), (uint32)(reference6), (*uint8)(reference7), (uint32)(reference8), (uint32)(resourceVersion0), (*uint8)(resourceVersion1), (uint32)(resourceVersion2), &result)
+	return
+}
@@ -41,17 +41,51 @@ func init() {
 			})
 		}
 
-		topo.ObjectMeta.Name = "wim"
-		fmt.Println("topo object", topo)
+		nodeName := fmt.Sprintf("%s.node1", topo.ObjectMeta.Name)
+		getResponse := retrieve.Get(retrieve.ObjectRef{
+			APIVersion: "infra.kuid.dev/v1alpha1",
+			Kind:       "Node",
+			Namespace:  cm.Some(topo.ObjectMeta.Namespace),
+			Name:       nodeName,
+		})
+		switch {
+		case getResponse.IsOK():
+			fmt.Println("get_response", *getResponse.OK())
+		case getResponse.Err().NotFound():
+			fmt.Println("get_response:", nodeName, "not found")
+		default:
+			fmt.Println("get_response: invalid reference:", *getResponse.Err().Invalid())
+		}
 
-		get_response := retrieve.Get("Wim")
-		fmt.Println("get_response", get_response)
+		listResponse := retrieve.ListObjects("Node", cm.Some(topo.ObjectMeta.Namespace), "topo.kubenet.dev/topology="+topo.ObjectMeta.Name)
+		if listResponse.IsErr() {
+			return returnErr(reconciler.ReconcileError{
+				Code:    500,
+				Message: "cannot list nodes",
+			})
+		}
+		fmt.Println("list_response:", listResponse.OK().Len(), "nodes")
 
-		b, err := json.Marshal(topo)
+		// the host creates or updates the children, the namespace defaults to the one of the topology
+		node, err := json.Marshal(map[string]any{
+			"apiVersion": "infra.kuid.dev/v1alpha1",
+			"kind":       "Node",
+			"metadata": map[string]any{
+				"name":   nodeName,
+				"labels": map[string]string{"topo.kubenet.dev/topology": topo.ObjectMeta.Name},
+			},
+		})
 		if err != nil {
 			return returnErr(reconciler.ReconcileError{
-				Code:    400,
-				Message: "cannot marshal json",
+				Code:    500,
+				Message: "cannot marshal node",
+			})
+		}
+		status, err := json.Marshal(map[string]any{"nodes": 1})
+		if err != nil {
+			return returnErr(reconciler.ReconcileError{
+				Code:    500,
+				Message: "cannot marshal status",
 			})
 		}
 
@@ -59,7 +93,13 @@ func init() {
 		reconcileSuccess := reconciler.ReconcileResult{
 			Requeue:      false,
 			RequeueAfter: 0,
-			Object:       string(b),
+			Status:       cm.Some(string(status)),
+			Children:     cm.ToList([]string{string(node)}),
+			Events: cm.ToList([]reconciler.Event{{
+				Kind:    reconciler.EventKindNormal,
+				Reason:  "Reconciled",
+				Message: "desired 1 node",
+			}}),
 		}
 
 		// Return the success result using cm.OK
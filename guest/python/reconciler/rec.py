@@ -3,7 +3,8 @@ import json
 import reconciler
 from topology_types import Topology
 #from  user import User
-from reconciler.imports import retrieve
+
+TOPOLOGY_LABEL = "topo.kubenet.dev/topology"
 
 class Reconciler(reconciler.Reconciler):
   def reconcile(self, object: str) -> reconciler.ReconcileResult:
@@ -11,9 +12,6 @@ class Reconciler(reconciler.Reconciler):
     requeue = False  # Whether to requeue
     requeue_after = 30  # Requeue after 30 seconds  
 
-    x = retrieve.get("wim")
-    print(x)
-
     try:
       # Parse the JSON string into a dictionary
       topo_data = json.loads(object)
@@ -24,6 +22,20 @@ class Reconciler(reconciler.Reconciler):
       nodes = topo.construct_nodes()
       links = topo.construct_links()
 
+      for obj in [*nodes, *links]:
+        obj.metadata.labels = {TOPOLOGY_LABEL: topo.metadata.name}
+
+      # the host creates or updates the children and deletes
+      # the nodes and links this topology no longer has
+      children = [obj.model_dump_json(exclude_none=True) for obj in [*nodes, *links]]
+
+      status = {"nodes": len(nodes), "links": len(links)}
+      events = [reconciler.Event(
+          kind=reconciler.EventKind.NORMAL,
+          reason="Reconciled",
+          message=f"desired {len(nodes)} nodes and {len(links)} links",
+      )]
+
     except json.JSONDecodeError as e:
       # Handle JSON parsing errors
       raise reconciler.types.Err(reconciler.ReconcileError(
@@ -46,5 +58,7 @@ class Reconciler(reconciler.Reconciler):
     return reconciler.ReconcileResult(
         requeue=requeue,
         requeue_after=requeue_after,
-        object=f"Constructed: {links}"
+        status=json.dumps(status),
+        children=children,
+        events=events,
     )
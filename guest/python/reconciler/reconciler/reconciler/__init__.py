@@ -12,11 +12,26 @@ from .types import Result, Ok, Err, Some
 
 
 
+class EventKind(Enum):
+    NORMAL = 0
+    WARNING = 1
+
+@dataclass
+class Event:
+    """
+    An event about the reconciled object, modeled after Kubernetes events.
+    """
+    kind: EventKind
+    reason: str
+    message: str
+
 @dataclass
 class ReconcileResult:
     requeue: bool
     requeue_after: int
-    object: str
+    status: Optional[str]
+    children: List[str]
+    events: List[Event]
 
 @dataclass
 class ReconcileError:
@@ -29,7 +44,8 @@ class Reconciler(Protocol):
     def reconcile(self, object: str) -> ReconcileResult:
         """
         The `reconcile` function is the main entry point for the reconciler.
-        It takes a JSON input and returns a JSON output or an error.
+        It takes the object to reconcile as JSON and returns its status,
+        desired children and events, or an error.
         
         Raises: `reconciler.types.Err(reconciler.imports.ReconcileError)`
         """
@@ -0,0 +1,33 @@
+from typing import TypeVar, Generic, Union, Optional, Protocol, Tuple, List, Any, Self
+from types import TracebackType
+from enum import Flag, Enum, auto
+from dataclasses import dataclass
+from abc import abstractmethod
+import weakref
+
+from ..types import Result, Ok, Err, Some
+
+class Level(Enum):
+    TRACE = 0
+    DEBUG = 1
+    INFO = 2
+    WARN = 3
+    ERROR = 4
+
+@dataclass
+class Field:
+    """
+    A structured key/value pair attached to a log record.
+    """
+    key: str
+    value: str
+
+
+def log(level: Level, message: str, fields: List[Field]) -> None:
+    """
+    Emit a record into the host log. The host attaches the component
+    and the key of the reconciled object, so records of a guest can be
+    filtered like the logs of the host.
+    """
+    raise NotImplementedError
+
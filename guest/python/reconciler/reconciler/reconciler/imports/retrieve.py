@@ -8,7 +8,50 @@ import weakref
 from ..types import Result, Ok, Err, Some
 
 
+@dataclass
+class ObjectRef:
+    """
+    Identifies an object in the host object store.
+    """
+    api_version: str
+    kind: str
+    namespace: Optional[str]
+    name: str
 
-def get(name: str) -> str:
+
+@dataclass
+class LookupError_NotFound:
+    pass
+
+
+@dataclass
+class LookupError_Invalid:
+    value: str
+
+
+LookupError = Union[LookupError_NotFound, LookupError_Invalid]
+
+
+def get(reference: ObjectRef) -> str:
+    """
+    Look up an object in the host object store.
+    Returns the object as JSON.
+    
+    Raises: `reconciler.types.Err(reconciler.imports.retrieve.LookupError)`
+    """
+    raise NotImplementedError
+
+def list_objects(kind: str, namespace: Optional[str], label_selector: str) -> List[str]:
+    """
+    List the objects of a kind as JSON, sorted by namespace and name.
+    Objects of every apiVersion of the kind are listed, tell them apart by
+    their `apiVersion`.
+    Without a namespace objects of all namespaces are listed.
+    The label selector uses the Kubernetes syntax, e.g.
+    `topo.kubenet.dev/topology=kubenet,role in (leaf,spine)`,
+    an empty selector matches every object.
+    
+    Raises: `reconciler.types.Err(reconciler.imports.retrieve.LookupError)`
+    """
     raise NotImplementedError
 
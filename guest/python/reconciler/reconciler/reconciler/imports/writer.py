@@ -0,0 +1,71 @@
+from typing import TypeVar, Generic, Union, Optional, Protocol, Tuple, List, Any, Self
+from types import TracebackType
+from enum import Flag, Enum, auto
+from dataclasses import dataclass
+from abc import abstractmethod
+import weakref
+
+from ..types import Result, Ok, Err, Some
+from ..imports import retrieve
+
+
+@dataclass
+class WriteError_NotFound:
+    pass
+
+
+@dataclass
+class WriteError_AlreadyExists:
+    pass
+
+
+@dataclass
+class WriteError_Conflict:
+    value: str
+
+
+@dataclass
+class WriteError_Invalid:
+    value: str
+
+
+WriteError = Union[WriteError_NotFound, WriteError_AlreadyExists, WriteError_Conflict, WriteError_Invalid]
+
+
+def create(object: str) -> str:
+    """
+    Create an object given as JSON, `metadata.resourceVersion` must not be set.
+    Returns the stored object with its resource version.
+    
+    Raises: `reconciler.types.Err(reconciler.imports.writer.WriteError)`
+    """
+    raise NotImplementedError
+
+def update(object: str) -> str:
+    """
+    Replace an object given as JSON. When `metadata.resourceVersion` is set
+    it must match the stored object.
+    Returns the stored object with its new resource version.
+    
+    Raises: `reconciler.types.Err(reconciler.imports.writer.WriteError)`
+    """
+    raise NotImplementedError
+
+def patch(reference: retrieve.ObjectRef, patch: str) -> str:
+    """
+    Apply a JSON merge patch (RFC 7386) to an object. When the patch sets
+    `metadata.resourceVersion` it must match the stored object.
+    Returns the stored object with its new resource version.
+    
+    Raises: `reconciler.types.Err(reconciler.imports.writer.WriteError)`
+    """
+    raise NotImplementedError
+
+def delete(reference: retrieve.ObjectRef, resource_version: Optional[str]) -> None:
+    """
+    Delete an object. When a resource version is given it must match the stored object.
+    
+    Raises: `reconciler.types.Err(reconciler.imports.writer.WriteError)`
+    """
+    raise NotImplementedError
+
@@ -1,15 +1,75 @@
+// Generated by `wit-bindgen` 0.41.0. DO NOT EDIT!
+// Options used:
+//   * runtime_path: "wit_bindgen_rt"
+//   * bitflags_path: "wit_bindgen_rt::bitflags"
+#[repr(u8)]
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub enum EventKind {
+    Normal,
+    Warning,
+}
+impl ::core::fmt::Debug for EventKind {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            EventKind::Normal => f.debug_tuple("EventKind::Normal").finish(),
+            EventKind::Warning => f.debug_tuple("EventKind::Warning").finish(),
+        }
+    }
+}
+
+impl EventKind {
+    #[doc(hidden)]
+    pub unsafe fn _lift(val: u8) -> EventKind {
+        if !cfg!(debug_assertions) {
+            return ::core::mem::transmute(val);
+        }
+
+        match val {
+            0 => EventKind::Normal,
+            1 => EventKind::Warning,
+
+            _ => panic!("invalid enum discriminant"),
+        }
+    }
+}
+
+/// An event about the reconciled object, modeled after Kubernetes events.
+#[derive(Clone)]
+pub struct Event {
+    pub kind: EventKind,
+    /// Short CamelCase reason, e.g. `NodesCreated`.
+    pub reason: _rt::String,
+    pub message: _rt::String,
+}
+impl ::core::fmt::Debug for Event {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("Event")
+            .field("kind", &self.kind)
+            .field("reason", &self.reason)
+            .field("message", &self.message)
+            .finish()
+    }
+}
 #[derive(Clone)]
 pub struct ReconcileResult {
     pub requeue: bool,
     pub requeue_after: u32,
-    pub object: _rt::String,
+    /// The new `status` of the reconciled object as a JSON object,
+    /// none leaves the stored status unchanged.
+    pub status: Option<_rt::String>,
+    /// The desired child objects as JSON, the host creates or updates them.
+    /// Children without a namespace get the namespace of the reconciled object.
+    pub children: _rt::Vec<_rt::String>,
+    pub events: _rt::Vec<Event>,
 }
 impl ::core::fmt::Debug for ReconcileResult {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.debug_struct("ReconcileResult")
             .field("requeue", &self.requeue)
             .field("requeue-after", &self.requeue_after)
-            .field("object", &self.object)
+            .field("status", &self.status)
+            .field("children", &self.children)
+            .field("events", &self.events)
             .finish()
     }
 }
@@ -35,41 +95,155 @@ impl std::error::Error for ReconcileError {}
 #[doc(hidden)]
 #[allow(non_snake_case)]
 pub unsafe fn _export_reconcile_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
-    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
     let len0 = arg1;
     let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
     let result1 = T::reconcile(_rt::string_lift(bytes0));
-    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
     match result1 {
         Ok(e) => {
             *ptr2.add(0).cast::<u8>() = (0i32) as u8;
             let ReconcileResult {
                 requeue: requeue3,
                 requeue_after: requeue_after3,
-                object: object3,
+                status: status3,
+                children: children3,
+                events: events3,
             } = e;
-            *ptr2.add(4).cast::<u8>() = (match requeue3 {
+            *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (match requeue3 {
                 true => 1,
                 false => 0,
             }) as u8;
-            *ptr2.add(8).cast::<i32>() = _rt::as_i32(requeue_after3);
-            let vec4 = (object3.into_bytes()).into_boxed_slice();
-            let ptr4 = vec4.as_ptr().cast::<u8>();
-            let len4 = vec4.len();
-            ::core::mem::forget(vec4);
-            *ptr2.add(16).cast::<usize>() = len4;
-            *ptr2.add(12).cast::<*mut u8>() = ptr4.cast_mut();
+            *ptr2
+                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                .cast::<i32>() = _rt::as_i32(requeue_after3);
+            match status3 {
+                Some(e) => {
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (1i32) as u8;
+                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *ptr2
+                        .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len4;
+                    *ptr2
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr4.cast_mut();
+                }
+                None => {
+                    *ptr2
+                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (0i32) as u8;
+                }
+            };
+            let vec6 = children3;
+            let len6 = vec6.len();
+            let layout6 = _rt::alloc::Layout::from_size_align_unchecked(
+                vec6.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
+            let result6 = if layout6.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout6).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout6);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec6.into_iter().enumerate() {
+                let base = result6.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len5;
+                    *base.add(0).cast::<*mut u8>() = ptr5.cast_mut();
+                }
+            }
+            *ptr2
+                .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len6;
+            *ptr2
+                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = result6;
+            let vec10 = events3;
+            let len10 = vec10.len();
+            let layout10 = _rt::alloc::Layout::from_size_align_unchecked(
+                vec10.len() * (5 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
+            let result10 = if layout10.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout10).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout10);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec10.into_iter().enumerate() {
+                let base = result10.add(i * (5 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let Event {
+                        kind: kind7,
+                        reason: reason7,
+                        message: message7,
+                    } = e;
+                    *base.add(0).cast::<u8>() = (kind7.clone() as i32) as u8;
+                    let vec8 = (reason7.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                    let len8 = vec8.len();
+                    ::core::mem::forget(vec8);
+                    *base
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len8;
+                    *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr8.cast_mut();
+                    let vec9 = (message7.into_bytes()).into_boxed_slice();
+                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                    let len9 = vec9.len();
+                    ::core::mem::forget(vec9);
+                    *base
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len9;
+                    *base
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr9.cast_mut();
+                }
+            }
+            *ptr2
+                .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len10;
+            *ptr2
+                .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = result10;
         }
         Err(e) => {
             *ptr2.add(0).cast::<u8>() = (1i32) as u8;
-            let ReconcileError { code: code5, message: message5 } = e;
-            *ptr2.add(4).cast::<i32>() = _rt::as_i32(code5);
-            let vec6 = (message5.into_bytes()).into_boxed_slice();
-            let ptr6 = vec6.as_ptr().cast::<u8>();
-            let len6 = vec6.len();
-            ::core::mem::forget(vec6);
-            *ptr2.add(12).cast::<usize>() = len6;
-            *ptr2.add(8).cast::<*mut u8>() = ptr6.cast_mut();
+            let ReconcileError {
+                code: code11,
+                message: message11,
+            } = e;
+            *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(code11);
+            let vec12 = (message11.into_bytes()).into_boxed_slice();
+            let ptr12 = vec12.as_ptr().cast::<u8>();
+            let len12 = vec12.len();
+            ::core::mem::forget(vec12);
+            *ptr2
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>() = len12;
+            *ptr2
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>() = ptr12.cast_mut();
         }
     };
     ptr2
@@ -80,294 +254,1469 @@ pub unsafe fn __post_return_reconcile<T: Guest>(arg0: *mut u8) {
     let l0 = i32::from(*arg0.add(0).cast::<u8>());
     match l0 {
         0 => {
-            let l1 = *arg0.add(12).cast::<*mut u8>();
-            let l2 = *arg0.add(16).cast::<usize>();
-            _rt::cabi_dealloc(l1, l2, 1);
+            let l1 = i32::from(
+                *arg0
+                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                    .cast::<u8>(),
+            );
+            match l1 {
+                0 => (),
+                _ => {
+                    let l2 = *arg0
+                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l3 = *arg0
+                        .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l2, l3, 1);
+                }
+            }
+            let l4 = *arg0
+                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l5 = *arg0
+                .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let base8 = l4;
+            let len8 = l5;
+            for i in 0..len8 {
+                let base = base8.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let l6 = *base.add(0).cast::<*mut u8>();
+                    let l7 = *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l6, l7, 1);
+                }
+            }
+            _rt::cabi_dealloc(
+                base8,
+                len8 * (2 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
+            let l9 = *arg0
+                .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l10 = *arg0
+                .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            let base15 = l9;
+            let len15 = l10;
+            for i in 0..len15 {
+                let base = base15.add(i * (5 * ::core::mem::size_of::<*const u8>()));
+                {
+                    let l11 = *base
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l12 = *base
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l11, l12, 1);
+                    let l13 = *base
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l14 = *base
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l13, l14, 1);
+                }
+            }
+            _rt::cabi_dealloc(
+                base15,
+                len15 * (5 * ::core::mem::size_of::<*const u8>()),
+                ::core::mem::size_of::<*const u8>(),
+            );
         }
         _ => {
-            let l3 = *arg0.add(8).cast::<*mut u8>();
-            let l4 = *arg0.add(12).cast::<usize>();
-            _rt::cabi_dealloc(l3, l4, 1);
+            let l16 = *arg0
+                .add(2 * ::core::mem::size_of::<*const u8>())
+                .cast::<*mut u8>();
+            let l17 = *arg0
+                .add(3 * ::core::mem::size_of::<*const u8>())
+                .cast::<usize>();
+            _rt::cabi_dealloc(l16, l17, 1);
         }
     }
 }
 pub trait Guest {
     /// The `reconcile` function is the main entry point for the reconciler.
-    /// It takes a JSON input and returns a JSON output or an error.
+    /// It takes the object to reconcile as JSON and returns its status,
+    /// desired children and events, or an error.
     fn reconcile(object: _rt::String) -> Result<ReconcileResult, ReconcileError>;
 }
 #[doc(hidden)]
-macro_rules! __export_world_reconciler_cabi {
-    ($ty:ident with_types_in $($path_to_types:tt)*) => {
-        const _ : () = { #[export_name = "reconcile"] unsafe extern "C" fn
-        export_reconcile(arg0 : * mut u8, arg1 : usize,) -> * mut u8 {
-        $($path_to_types)*:: _export_reconcile_cabi::<$ty > (arg0, arg1) } #[export_name
-        = "cabi_post_reconcile"] unsafe extern "C" fn _post_return_reconcile(arg0 : * mut
-        u8,) { $($path_to_types)*:: __post_return_reconcile::<$ty > (arg0) } };
-    };
+
+macro_rules! __export_world_reconciler_cabi{
+  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+    #[unsafe(export_name = "reconcile")]
+    unsafe extern "C" fn export_reconcile(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      unsafe { $($path_to_types)*::_export_reconcile_cabi::<$ty>(arg0, arg1) }
+    }
+    #[unsafe(export_name = "cabi_post_reconcile")]
+    unsafe extern "C" fn _post_return_reconcile(arg0: *mut u8,) {
+      unsafe { $($path_to_types)*::__post_return_reconcile::<$ty>(arg0) }
+    }
+  };);
 }
 #[doc(hidden)]
 pub(crate) use __export_world_reconciler_cabi;
-#[repr(align(4))]
-struct _RetArea([::core::mem::MaybeUninit<u8>; 20]);
-static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 20]);
-#[allow(dead_code)]
+
+#[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+#[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+struct _RetArea([::core::mem::MaybeUninit<u8>; 8 + 8 * ::core::mem::size_of::<*const u8>()]);
+static mut _RET_AREA: _RetArea =
+    _RetArea([::core::mem::MaybeUninit::uninit(); 8 + 8 * ::core::mem::size_of::<*const u8>()]);
+#[allow(dead_code, clippy::all)]
 pub mod example {
-    #[allow(dead_code)]
     pub mod reconciler {
-        #[allow(dead_code, clippy::all)]
+
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
         pub mod retrieve {
             #[used]
             #[doc(hidden)]
-            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+
+            use super::super::super::_rt;
+            /// Identifies an object in the host object store.
+            #[derive(Clone)]
+            pub struct ObjectRef {
+                pub api_version: _rt::String,
+                pub kind: _rt::String,
+                /// None for cluster-scoped objects.
+                pub namespace: Option<_rt::String>,
+                pub name: _rt::String,
+            }
+            impl ::core::fmt::Debug for ObjectRef {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("ObjectRef")
+                        .field("api-version", &self.api_version)
+                        .field("kind", &self.kind)
+                        .field("namespace", &self.namespace)
+                        .field("name", &self.name)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
+            pub enum LookupError {
+                /// No object exists for the reference.
+                NotFound,
+                /// The reference or label selector is malformed.
+                Invalid(_rt::String),
+            }
+            impl ::core::fmt::Debug for LookupError {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        LookupError::NotFound => f.debug_tuple("LookupError::NotFound").finish(),
+                        LookupError::Invalid(e) => {
+                            f.debug_tuple("LookupError::Invalid").field(e).finish()
+                        }
+                    }
+                }
+            }
+            impl ::core::fmt::Display for LookupError {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(f, "{:?}", self)
+                }
+            }
+
+            impl std::error::Error for LookupError {}
+            #[allow(unused_unsafe, clippy::all)]
+            /// Look up an object in the host object store.
+            /// Returns the object as JSON.
+            pub fn get(reference: &ObjectRef) -> Result<_rt::String, LookupError> {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<u8>; 4 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit();
+                            4 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let ObjectRef {
+                        api_version: api_version0,
+                        kind: kind0,
+                        namespace: namespace0,
+                        name: name0,
+                    } = reference;
+                    let vec1 = api_version0;
+                    let ptr1 = vec1.as_ptr().cast::<u8>();
+                    let len1 = vec1.len();
+                    let vec2 = kind0;
+                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                    let len2 = vec2.len();
+                    let (result4_0, result4_1, result4_2) = match namespace0 {
+                        Some(e) => {
+                            let vec3 = e;
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+
+                            (1i32, ptr3.cast_mut(), len3)
+                        }
+                        None => (0i32, ::core::ptr::null_mut(), 0usize),
+                    };
+                    let vec5 = name0;
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    let ptr6 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "example:reconciler/retrieve@0.2.0")]
+                    unsafe extern "C" {
+                        #[link_name = "get"]
+                        fn wit_import7(
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                        );
+                    }
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import7(
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                        _: usize,
+                        _: i32,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import7(
+                            ptr1.cast_mut(),
+                            len1,
+                            ptr2.cast_mut(),
+                            len2,
+                            result4_0,
+                            result4_1,
+                            result4_2,
+                            ptr5.cast_mut(),
+                            len5,
+                            ptr6,
+                        )
+                    };
+                    let l8 = i32::from(*ptr6.add(0).cast::<u8>());
+                    let result17 = match l8 {
+                        0 => {
+                            let e = {
+                                let l9 = *ptr6
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l10 = *ptr6
+                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len11 = l10;
+                                let bytes11 = _rt::Vec::from_raw_parts(l9.cast(), len11, len11);
+
+                                _rt::string_lift(bytes11)
+                            };
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l12 = i32::from(
+                                    *ptr6.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                );
+                                let v16 = match l12 {
+                                    0 => LookupError::NotFound,
+                                    n => {
+                                        debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                        let e16 = {
+                                            let l13 = *ptr6
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l14 = *ptr6
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len15 = l14;
+                                            let bytes15 =
+                                                _rt::Vec::from_raw_parts(l13.cast(), len15, len15);
+
+                                            _rt::string_lift(bytes15)
+                                        };
+                                        LookupError::Invalid(e16)
+                                    }
+                                };
+
+                                v16
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result17
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// List the objects of a kind as JSON, sorted by namespace and name.
+            /// Objects of every apiVersion of the kind are listed, tell them apart by
+            /// their `apiVersion`.
+            /// Without a namespace objects of all namespaces are listed.
+            /// The label selector uses the Kubernetes syntax, e.g.
+            /// `topo.kubenet.dev/topology=kubenet,role in (leaf,spine)`,
+            /// an empty selector matches every object.
+            pub fn list_objects(
+                kind: &str,
+                namespace: Option<&str>,
+                label_selector: &str,
+            ) -> Result<_rt::Vec<_rt::String>, LookupError> {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<u8>; 4 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit();
+                            4 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let vec0 = kind;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    let (result2_0, result2_1, result2_2) = match namespace {
+                        Some(e) => {
+                            let vec1 = e;
+                            let ptr1 = vec1.as_ptr().cast::<u8>();
+                            let len1 = vec1.len();
+
+                            (1i32, ptr1.cast_mut(), len1)
+                        }
+                        None => (0i32, ::core::ptr::null_mut(), 0usize),
+                    };
+                    let vec3 = label_selector;
+                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                    let len3 = vec3.len();
+                    let ptr4 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "example:reconciler/retrieve@0.2.0")]
+                    unsafe extern "C" {
+                        #[link_name = "list-objects"]
+                        fn wit_import5(
+                            _: *mut u8,
+                            _: usize,
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                        );
+                    }
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import5(
+                        _: *mut u8,
+                        _: usize,
+                        _: i32,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import5(
+                            ptr0.cast_mut(),
+                            len0,
+                            result2_0,
+                            result2_1,
+                            result2_2,
+                            ptr3.cast_mut(),
+                            len3,
+                            ptr4,
+                        )
+                    };
+                    let l6 = i32::from(*ptr4.add(0).cast::<u8>());
+                    let result18 = match l6 {
+                        0 => {
+                            let e = {
+                                let l7 = *ptr4
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l8 = *ptr4
+                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let base12 = l7;
+                                let len12 = l8;
+                                let mut result12 = _rt::Vec::with_capacity(len12);
+                                for i in 0..len12 {
+                                    let base =
+                                        base12.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                    let e12 = {
+                                        let l9 = *base.add(0).cast::<*mut u8>();
+                                        let l10 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len11 = l10;
+                                        let bytes11 =
+                                            _rt::Vec::from_raw_parts(l9.cast(), len11, len11);
+
+                                        _rt::string_lift(bytes11)
+                                    };
+                                    result12.push(e12);
+                                }
+                                _rt::cabi_dealloc(
+                                    base12,
+                                    len12 * (2 * ::core::mem::size_of::<*const u8>()),
+                                    ::core::mem::size_of::<*const u8>(),
+                                );
+
+                                result12
+                            };
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l13 = i32::from(
+                                    *ptr4.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                );
+                                let v17 = match l13 {
+                                    0 => LookupError::NotFound,
+                                    n => {
+                                        debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                        let e17 = {
+                                            let l14 = *ptr4
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l15 = *ptr4
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len16 = l15;
+                                            let bytes16 =
+                                                _rt::Vec::from_raw_parts(l14.cast(), len16, len16);
+
+                                            _rt::string_lift(bytes16)
+                                        };
+                                        LookupError::Invalid(e17)
+                                    }
+                                };
+
+                                v17
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result18
+                }
+            }
+        }
+
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+        pub mod writer {
+            #[used]
+            #[doc(hidden)]
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+
             use super::super::super::_rt;
+            pub type ObjectRef = super::super::super::example::reconciler::retrieve::ObjectRef;
+            #[derive(Clone)]
+            pub enum WriteError {
+                /// The object to update, patch or delete does not exist.
+                NotFound,
+                /// The object to create already exists.
+                AlreadyExists,
+                /// The resource version of the write does not match the stored object.
+                Conflict(_rt::String),
+                /// The object or patch is malformed.
+                Invalid(_rt::String),
+            }
+            impl ::core::fmt::Debug for WriteError {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        WriteError::NotFound => f.debug_tuple("WriteError::NotFound").finish(),
+                        WriteError::AlreadyExists => {
+                            f.debug_tuple("WriteError::AlreadyExists").finish()
+                        }
+                        WriteError::Conflict(e) => {
+                            f.debug_tuple("WriteError::Conflict").field(e).finish()
+                        }
+                        WriteError::Invalid(e) => {
+                            f.debug_tuple("WriteError::Invalid").field(e).finish()
+                        }
+                    }
+                }
+            }
+            impl ::core::fmt::Display for WriteError {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(f, "{:?}", self)
+                }
+            }
+
+            impl std::error::Error for WriteError {}
             #[allow(unused_unsafe, clippy::all)]
-            pub fn get(name: &str) -> _rt::String {
+            /// Create an object given as JSON, `metadata.resourceVersion` must not be set.
+            /// Returns the stored object with its resource version.
+            pub fn create(object: &str) -> Result<_rt::String, WriteError> {
                 unsafe {
-                    #[repr(align(4))]
-                    struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
-                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
-                    let vec0 = name;
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<u8>; 4 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit();
+                            4 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let vec0 = object;
                     let ptr0 = vec0.as_ptr().cast::<u8>();
                     let len0 = vec0.len();
                     let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
                     #[cfg(target_arch = "wasm32")]
-                    #[link(wasm_import_module = "example:reconciler/retrieve@0.1.0")]
-                    extern "C" {
-                        #[link_name = "get"]
-                        fn wit_import(_: *mut u8, _: usize, _: *mut u8);
+                    #[link(wasm_import_module = "example:reconciler/writer@0.2.0")]
+                    unsafe extern "C" {
+                        #[link_name = "create"]
+                        fn wit_import2(_: *mut u8, _: usize, _: *mut u8);
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import(_: *mut u8, _: usize, _: *mut u8) {
+                    unsafe extern "C" fn wit_import2(_: *mut u8, _: usize, _: *mut u8) {
                         unreachable!()
                     }
-                    wit_import(ptr0.cast_mut(), len0, ptr1);
-                    let l2 = *ptr1.add(0).cast::<*mut u8>();
-                    let l3 = *ptr1.add(4).cast::<usize>();
-                    let len4 = l3;
-                    let bytes4 = _rt::Vec::from_raw_parts(l2.cast(), len4, len4);
-                    _rt::string_lift(bytes4)
+                    unsafe { wit_import2(ptr0.cast_mut(), len0, ptr1) };
+                    let l3 = i32::from(*ptr1.add(0).cast::<u8>());
+                    let result15 = match l3 {
+                        0 => {
+                            let e = {
+                                let l4 = *ptr1
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l5 = *ptr1
+                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len6 = l5;
+                                let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+
+                                _rt::string_lift(bytes6)
+                            };
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l7 = i32::from(
+                                    *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                );
+                                let v14 = match l7 {
+                                    0 => WriteError::NotFound,
+                                    1 => WriteError::AlreadyExists,
+                                    2 => {
+                                        let e14 = {
+                                            let l8 = *ptr1
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l9 = *ptr1
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len10 = l9;
+                                            let bytes10 =
+                                                _rt::Vec::from_raw_parts(l8.cast(), len10, len10);
+
+                                            _rt::string_lift(bytes10)
+                                        };
+                                        WriteError::Conflict(e14)
+                                    }
+                                    n => {
+                                        debug_assert_eq!(n, 3, "invalid enum discriminant");
+                                        let e14 = {
+                                            let l11 = *ptr1
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l12 = *ptr1
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len13 = l12;
+                                            let bytes13 =
+                                                _rt::Vec::from_raw_parts(l11.cast(), len13, len13);
+
+                                            _rt::string_lift(bytes13)
+                                        };
+                                        WriteError::Invalid(e14)
+                                    }
+                                };
+
+                                v14
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result15
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Replace an object given as JSON. When `metadata.resourceVersion` is set
+            /// it must match the stored object.
+            /// Returns the stored object with its new resource version.
+            pub fn update(object: &str) -> Result<_rt::String, WriteError> {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<u8>; 4 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit();
+                            4 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let vec0 = object;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "example:reconciler/writer@0.2.0")]
+                    unsafe extern "C" {
+                        #[link_name = "update"]
+                        fn wit_import2(_: *mut u8, _: usize, _: *mut u8);
+                    }
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import2(_: *mut u8, _: usize, _: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import2(ptr0.cast_mut(), len0, ptr1) };
+                    let l3 = i32::from(*ptr1.add(0).cast::<u8>());
+                    let result15 = match l3 {
+                        0 => {
+                            let e = {
+                                let l4 = *ptr1
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l5 = *ptr1
+                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len6 = l5;
+                                let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+
+                                _rt::string_lift(bytes6)
+                            };
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l7 = i32::from(
+                                    *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                );
+                                let v14 = match l7 {
+                                    0 => WriteError::NotFound,
+                                    1 => WriteError::AlreadyExists,
+                                    2 => {
+                                        let e14 = {
+                                            let l8 = *ptr1
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l9 = *ptr1
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len10 = l9;
+                                            let bytes10 =
+                                                _rt::Vec::from_raw_parts(l8.cast(), len10, len10);
+
+                                            _rt::string_lift(bytes10)
+                                        };
+                                        WriteError::Conflict(e14)
+                                    }
+                                    n => {
+                                        debug_assert_eq!(n, 3, "invalid enum discriminant");
+                                        let e14 = {
+                                            let l11 = *ptr1
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l12 = *ptr1
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len13 = l12;
+                                            let bytes13 =
+                                                _rt::Vec::from_raw_parts(l11.cast(), len13, len13);
+
+                                            _rt::string_lift(bytes13)
+                                        };
+                                        WriteError::Invalid(e14)
+                                    }
+                                };
+
+                                v14
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result15
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Apply a JSON merge patch (RFC 7386) to an object. When the patch sets
+            /// `metadata.resourceVersion` it must match the stored object.
+            /// Returns the stored object with its new resource version.
+            pub fn patch(reference: &ObjectRef, patch: &str) -> Result<_rt::String, WriteError> {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<u8>; 4 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit();
+                            4 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let super::super::super::example::reconciler::retrieve::ObjectRef {
+                        api_version: api_version0,
+                        kind: kind0,
+                        namespace: namespace0,
+                        name: name0,
+                    } = reference;
+                    let vec1 = api_version0;
+                    let ptr1 = vec1.as_ptr().cast::<u8>();
+                    let len1 = vec1.len();
+                    let vec2 = kind0;
+                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                    let len2 = vec2.len();
+                    let (result4_0, result4_1, result4_2) = match namespace0 {
+                        Some(e) => {
+                            let vec3 = e;
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+
+                            (1i32, ptr3.cast_mut(), len3)
+                        }
+                        None => (0i32, ::core::ptr::null_mut(), 0usize),
+                    };
+                    let vec5 = name0;
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    let vec6 = patch;
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    let ptr7 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "example:reconciler/writer@0.2.0")]
+                    unsafe extern "C" {
+                        #[link_name = "patch"]
+                        fn wit_import8(
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                        );
+                    }
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import8(
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                        _: usize,
+                        _: i32,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import8(
+                            ptr1.cast_mut(),
+                            len1,
+                            ptr2.cast_mut(),
+                            len2,
+                            result4_0,
+                            result4_1,
+                            result4_2,
+                            ptr5.cast_mut(),
+                            len5,
+                            ptr6.cast_mut(),
+                            len6,
+                            ptr7,
+                        )
+                    };
+                    let l9 = i32::from(*ptr7.add(0).cast::<u8>());
+                    let result21 = match l9 {
+                        0 => {
+                            let e = {
+                                let l10 = *ptr7
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l11 = *ptr7
+                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len12 = l11;
+                                let bytes12 = _rt::Vec::from_raw_parts(l10.cast(), len12, len12);
+
+                                _rt::string_lift(bytes12)
+                            };
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l13 = i32::from(
+                                    *ptr7.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                );
+                                let v20 = match l13 {
+                                    0 => WriteError::NotFound,
+                                    1 => WriteError::AlreadyExists,
+                                    2 => {
+                                        let e20 = {
+                                            let l14 = *ptr7
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l15 = *ptr7
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len16 = l15;
+                                            let bytes16 =
+                                                _rt::Vec::from_raw_parts(l14.cast(), len16, len16);
+
+                                            _rt::string_lift(bytes16)
+                                        };
+                                        WriteError::Conflict(e20)
+                                    }
+                                    n => {
+                                        debug_assert_eq!(n, 3, "invalid enum discriminant");
+                                        let e20 = {
+                                            let l17 = *ptr7
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l18 = *ptr7
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len19 = l18;
+                                            let bytes19 =
+                                                _rt::Vec::from_raw_parts(l17.cast(), len19, len19);
+
+                                            _rt::string_lift(bytes19)
+                                        };
+                                        WriteError::Invalid(e20)
+                                    }
+                                };
+
+                                v20
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result21
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Delete an object. When a resource version is given it must match the stored object.
+            pub fn delete(
+                reference: &ObjectRef,
+                resource_version: Option<&str>,
+            ) -> Result<(), WriteError> {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<u8>; 4 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit();
+                            4 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let super::super::super::example::reconciler::retrieve::ObjectRef {
+                        api_version: api_version0,
+                        kind: kind0,
+                        namespace: namespace0,
+                        name: name0,
+                    } = reference;
+                    let vec1 = api_version0;
+                    let ptr1 = vec1.as_ptr().cast::<u8>();
+                    let len1 = vec1.len();
+                    let vec2 = kind0;
+                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                    let len2 = vec2.len();
+                    let (result4_0, result4_1, result4_2) = match namespace0 {
+                        Some(e) => {
+                            let vec3 = e;
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+
+                            (1i32, ptr3.cast_mut(), len3)
+                        }
+                        None => (0i32, ::core::ptr::null_mut(), 0usize),
+                    };
+                    let vec5 = name0;
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    let (result7_0, result7_1, result7_2) = match resource_version {
+                        Some(e) => {
+                            let vec6 = e;
+                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                            let len6 = vec6.len();
+
+                            (1i32, ptr6.cast_mut(), len6)
+                        }
+                        None => (0i32, ::core::ptr::null_mut(), 0usize),
+                    };
+                    let ptr8 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "example:reconciler/writer@0.2.0")]
+                    unsafe extern "C" {
+                        #[link_name = "delete"]
+                        fn wit_import9(
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                        );
+                    }
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import9(
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                        _: usize,
+                        _: i32,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                        _: usize,
+                        _: i32,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import9(
+                            ptr1.cast_mut(),
+                            len1,
+                            ptr2.cast_mut(),
+                            len2,
+                            result4_0,
+                            result4_1,
+                            result4_2,
+                            ptr5.cast_mut(),
+                            len5,
+                            result7_0,
+                            result7_1,
+                            result7_2,
+                            ptr8,
+                        )
+                    };
+                    let l10 = i32::from(*ptr8.add(0).cast::<u8>());
+                    let result19 = match l10 {
+                        0 => {
+                            let e = ();
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l11 = i32::from(
+                                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                );
+                                let v18 = match l11 {
+                                    0 => WriteError::NotFound,
+                                    1 => WriteError::AlreadyExists,
+                                    2 => {
+                                        let e18 = {
+                                            let l12 = *ptr8
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l13 = *ptr8
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len14 = l13;
+                                            let bytes14 =
+                                                _rt::Vec::from_raw_parts(l12.cast(), len14, len14);
+
+                                            _rt::string_lift(bytes14)
+                                        };
+                                        WriteError::Conflict(e18)
+                                    }
+                                    n => {
+                                        debug_assert_eq!(n, 3, "invalid enum discriminant");
+                                        let e18 = {
+                                            let l15 = *ptr8
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l16 = *ptr8
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len17 = l16;
+                                            let bytes17 =
+                                                _rt::Vec::from_raw_parts(l15.cast(), len17, len17);
+
+                                            _rt::string_lift(bytes17)
+                                        };
+                                        WriteError::Invalid(e18)
+                                    }
+                                };
+
+                                v18
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result19
+                }
+            }
+        }
+
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+        pub mod logging {
+            #[used]
+            #[doc(hidden)]
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+
+            use super::super::super::_rt;
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum Level {
+                Trace,
+                Debug,
+                Info,
+                Warn,
+                Error,
+            }
+            impl ::core::fmt::Debug for Level {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        Level::Trace => f.debug_tuple("Level::Trace").finish(),
+                        Level::Debug => f.debug_tuple("Level::Debug").finish(),
+                        Level::Info => f.debug_tuple("Level::Info").finish(),
+                        Level::Warn => f.debug_tuple("Level::Warn").finish(),
+                        Level::Error => f.debug_tuple("Level::Error").finish(),
+                    }
+                }
+            }
+
+            impl Level {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> Level {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+
+                    match val {
+                        0 => Level::Trace,
+                        1 => Level::Debug,
+                        2 => Level::Info,
+                        3 => Level::Warn,
+                        4 => Level::Error,
+
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+
+            /// A structured key/value pair attached to a log record.
+            #[derive(Clone)]
+            pub struct Field {
+                pub key: _rt::String,
+                pub value: _rt::String,
+            }
+            impl ::core::fmt::Debug for Field {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("Field")
+                        .field("key", &self.key)
+                        .field("value", &self.value)
+                        .finish()
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Emit a record into the host log. The host attaches the component
+            /// and the key of the reconciled object, so records of a guest can be
+            /// filtered like the logs of the host.
+            pub fn log(level: Level, message: &str, fields: &[Field]) -> () {
+                unsafe {
+                    let vec0 = message;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    let vec4 = fields;
+                    let len4 = vec4.len();
+                    let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec4.len() * (4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result4 = if layout4.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout4);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec4.into_iter().enumerate() {
+                        let base = result4.add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let Field {
+                                key: key1,
+                                value: value1,
+                            } = e;
+                            let vec2 = key1;
+                            let ptr2 = vec2.as_ptr().cast::<u8>();
+                            let len2 = vec2.len();
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len2;
+                            *base.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                            let vec3 = value1;
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len3;
+                            *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr3.cast_mut();
+                        }
+                    }
+
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "example:reconciler/logging@0.2.0")]
+                    unsafe extern "C" {
+                        #[link_name = "log"]
+                        fn wit_import5(_: i32, _: *mut u8, _: usize, _: *mut u8, _: usize);
+                    }
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import5(
+                        _: i32,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                        _: usize,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import5(level.clone() as i32, ptr0.cast_mut(), len0, result4, len4)
+                    };
+                    if layout4.size() != 0 {
+                        _rt::alloc::dealloc(result4.cast(), layout4);
+                    }
                 }
             }
         }
     }
 }
-#[allow(dead_code)]
+#[allow(dead_code, clippy::all)]
 pub mod wasi {
-    #[allow(dead_code)]
     pub mod cli {
-        #[allow(dead_code, clippy::all)]
+
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
         pub mod stdout {
             #[used]
             #[doc(hidden)]
-            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+
             pub type OutputStream = super::super::super::wasi::io::streams::OutputStream;
             #[allow(unused_unsafe, clippy::all)]
             pub fn get_stdout() -> OutputStream {
                 unsafe {
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "wasi:cli/stdout@0.2.0")]
-                    extern "C" {
+                    unsafe extern "C" {
                         #[link_name = "get-stdout"]
-                        fn wit_import() -> i32;
+                        fn wit_import0() -> i32;
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import() -> i32 {
+                    unsafe extern "C" fn wit_import0() -> i32 {
                         unreachable!()
                     }
-                    let ret = wit_import();
-                    super::super::super::wasi::io::streams::OutputStream::from_handle(
-                        ret as u32,
-                    )
+                    let ret = unsafe { wit_import0() };
+                    unsafe {
+                        super::super::super::wasi::io::streams::OutputStream::from_handle(
+                            ret as u32,
+                        )
+                    }
                 }
             }
         }
-        #[allow(dead_code, clippy::all)]
+
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
         pub mod environment {
             #[used]
             #[doc(hidden)]
-            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+
             use super::super::super::_rt;
             #[allow(unused_unsafe, clippy::all)]
             pub fn get_environment() -> _rt::Vec<(_rt::String, _rt::String)> {
                 unsafe {
-                    #[repr(align(4))]
-                    struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
-                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<u8>; 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit();
+                            2 * ::core::mem::size_of::<*const u8>()],
+                    );
                     let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "wasi:cli/environment@0.2.0")]
-                    extern "C" {
+                    unsafe extern "C" {
                         #[link_name = "get-environment"]
-                        fn wit_import(_: *mut u8);
+                        fn wit_import1(_: *mut u8);
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import(_: *mut u8) {
+                    unsafe extern "C" fn wit_import1(_: *mut u8) {
                         unreachable!()
                     }
-                    wit_import(ptr0);
-                    let l1 = *ptr0.add(0).cast::<*mut u8>();
-                    let l2 = *ptr0.add(4).cast::<usize>();
-                    let base9 = l1;
-                    let len9 = l2;
-                    let mut result9 = _rt::Vec::with_capacity(len9);
-                    for i in 0..len9 {
-                        let base = base9.add(i * 16);
-                        let e9 = {
-                            let l3 = *base.add(0).cast::<*mut u8>();
-                            let l4 = *base.add(4).cast::<usize>();
-                            let len5 = l4;
-                            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
-                            let l6 = *base.add(8).cast::<*mut u8>();
-                            let l7 = *base.add(12).cast::<usize>();
-                            let len8 = l7;
-                            let bytes8 = _rt::Vec::from_raw_parts(l6.cast(), len8, len8);
-                            (_rt::string_lift(bytes5), _rt::string_lift(bytes8))
+                    unsafe { wit_import1(ptr0) };
+                    let l2 = *ptr0.add(0).cast::<*mut u8>();
+                    let l3 = *ptr0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base10 = l2;
+                    let len10 = l3;
+                    let mut result10 = _rt::Vec::with_capacity(len10);
+                    for i in 0..len10 {
+                        let base = base10.add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                        let e10 = {
+                            let l4 = *base.add(0).cast::<*mut u8>();
+                            let l5 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len6 = l5;
+                            let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+                            let l7 = *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l8 = *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len9 = l8;
+                            let bytes9 = _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+
+                            (_rt::string_lift(bytes6), _rt::string_lift(bytes9))
                         };
-                        result9.push(e9);
+                        result10.push(e10);
                     }
-                    _rt::cabi_dealloc(base9, len9 * 16, 4);
-                    result9
+                    _rt::cabi_dealloc(
+                        base10,
+                        len10 * (4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result11 = result10;
+                    result11
                 }
             }
             #[allow(unused_unsafe, clippy::all)]
             pub fn get_arguments() -> _rt::Vec<_rt::String> {
                 unsafe {
-                    #[repr(align(4))]
-                    struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
-                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<u8>; 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit();
+                            2 * ::core::mem::size_of::<*const u8>()],
+                    );
                     let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "wasi:cli/environment@0.2.0")]
-                    extern "C" {
+                    unsafe extern "C" {
                         #[link_name = "get-arguments"]
-                        fn wit_import(_: *mut u8);
+                        fn wit_import1(_: *mut u8);
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import(_: *mut u8) {
+                    unsafe extern "C" fn wit_import1(_: *mut u8) {
                         unreachable!()
                     }
-                    wit_import(ptr0);
-                    let l1 = *ptr0.add(0).cast::<*mut u8>();
-                    let l2 = *ptr0.add(4).cast::<usize>();
-                    let base6 = l1;
-                    let len6 = l2;
-                    let mut result6 = _rt::Vec::with_capacity(len6);
-                    for i in 0..len6 {
-                        let base = base6.add(i * 8);
-                        let e6 = {
-                            let l3 = *base.add(0).cast::<*mut u8>();
-                            let l4 = *base.add(4).cast::<usize>();
-                            let len5 = l4;
-                            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
-                            _rt::string_lift(bytes5)
+                    unsafe { wit_import1(ptr0) };
+                    let l2 = *ptr0.add(0).cast::<*mut u8>();
+                    let l3 = *ptr0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base7 = l2;
+                    let len7 = l3;
+                    let mut result7 = _rt::Vec::with_capacity(len7);
+                    for i in 0..len7 {
+                        let base = base7.add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        let e7 = {
+                            let l4 = *base.add(0).cast::<*mut u8>();
+                            let l5 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len6 = l5;
+                            let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+
+                            _rt::string_lift(bytes6)
                         };
-                        result6.push(e6);
+                        result7.push(e7);
                     }
-                    _rt::cabi_dealloc(base6, len6 * 8, 4);
-                    result6
+                    _rt::cabi_dealloc(
+                        base7,
+                        len7 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result8 = result7;
+                    result8
                 }
             }
             #[allow(unused_unsafe, clippy::all)]
             pub fn initial_cwd() -> Option<_rt::String> {
                 unsafe {
-                    #[repr(align(4))]
-                    struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
-                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<u8>; 3 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit();
+                            3 * ::core::mem::size_of::<*const u8>()],
+                    );
                     let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "wasi:cli/environment@0.2.0")]
-                    extern "C" {
+                    unsafe extern "C" {
                         #[link_name = "initial-cwd"]
-                        fn wit_import(_: *mut u8);
+                        fn wit_import1(_: *mut u8);
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import(_: *mut u8) {
+                    unsafe extern "C" fn wit_import1(_: *mut u8) {
                         unreachable!()
                     }
-                    wit_import(ptr0);
-                    let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                    match l1 {
+                    unsafe { wit_import1(ptr0) };
+                    let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                    let result6 = match l2 {
                         0 => None,
                         1 => {
                             let e = {
-                                let l2 = *ptr0.add(4).cast::<*mut u8>();
-                                let l3 = *ptr0.add(8).cast::<usize>();
-                                let len4 = l3;
-                                let bytes4 = _rt::Vec::from_raw_parts(
-                                    l2.cast(),
-                                    len4,
-                                    len4,
-                                );
-                                _rt::string_lift(bytes4)
+                                let l3 = *ptr0
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l4 = *ptr0
+                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len5 = l4;
+                                let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+
+                                _rt::string_lift(bytes5)
                             };
                             Some(e)
                         }
                         _ => _rt::invalid_enum_discriminant(),
-                    }
+                    };
+                    result6
                 }
             }
         }
-        #[allow(dead_code, clippy::all)]
+
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
         pub mod stderr {
             #[used]
             #[doc(hidden)]
-            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+
             pub type OutputStream = super::super::super::wasi::io::streams::OutputStream;
             #[allow(unused_unsafe, clippy::all)]
             pub fn get_stderr() -> OutputStream {
                 unsafe {
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "wasi:cli/stderr@0.2.0")]
-                    extern "C" {
+                    unsafe extern "C" {
                         #[link_name = "get-stderr"]
-                        fn wit_import() -> i32;
+                        fn wit_import0() -> i32;
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import() -> i32 {
+                    unsafe extern "C" fn wit_import0() -> i32 {
                         unreachable!()
                     }
-                    let ret = wit_import();
-                    super::super::super::wasi::io::streams::OutputStream::from_handle(
-                        ret as u32,
-                    )
+                    let ret = unsafe { wit_import0() };
+                    unsafe {
+                        super::super::super::wasi::io::streams::OutputStream::from_handle(
+                            ret as u32,
+                        )
+                    }
                 }
             }
         }
-        #[allow(dead_code, clippy::all)]
+
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
         pub mod stdin {
             #[used]
             #[doc(hidden)]
-            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+
             pub type InputStream = super::super::super::wasi::io::streams::InputStream;
             #[allow(unused_unsafe, clippy::all)]
             pub fn get_stdin() -> InputStream {
                 unsafe {
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "wasi:cli/stdin@0.2.0")]
-                    extern "C" {
+                    unsafe extern "C" {
                         #[link_name = "get-stdin"]
-                        fn wit_import() -> i32;
+                        fn wit_import0() -> i32;
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import() -> i32 {
+                    unsafe extern "C" fn wit_import0() -> i32 {
                         unreachable!()
                     }
-                    let ret = wit_import();
-                    super::super::super::wasi::io::streams::InputStream::from_handle(
-                        ret as u32,
-                    )
+                    let ret = unsafe { wit_import0() };
+                    unsafe {
+                        super::super::super::wasi::io::streams::InputStream::from_handle(ret as u32)
+                    }
                 }
             }
         }
     }
-    #[allow(dead_code)]
     pub mod clocks {
-        #[allow(dead_code, clippy::all)]
+
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
         pub mod monotonic_clock {
             #[used]
             #[doc(hidden)]
-            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+
             use super::super::super::_rt;
             pub type Pollable = super::super::super::wasi::io::poll::Pollable;
             pub type Instant = u64;
@@ -377,15 +1726,16 @@ pub mod wasi {
                 unsafe {
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "wasi:clocks/monotonic-clock@0.2.0")]
-                    extern "C" {
+                    unsafe extern "C" {
                         #[link_name = "now"]
-                        fn wit_import() -> i64;
+                        fn wit_import0() -> i64;
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import() -> i64 {
+                    unsafe extern "C" fn wit_import0() -> i64 {
                         unreachable!()
                     }
-                    let ret = wit_import();
+                    let ret = unsafe { wit_import0() };
                     ret as u64
                 }
             }
@@ -394,15 +1744,16 @@ pub mod wasi {
                 unsafe {
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "wasi:clocks/monotonic-clock@0.2.0")]
-                    extern "C" {
+                    unsafe extern "C" {
                         #[link_name = "resolution"]
-                        fn wit_import() -> i64;
+                        fn wit_import0() -> i64;
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import() -> i64 {
+                    unsafe extern "C" fn wit_import0() -> i64 {
                         unreachable!()
                     }
-                    let ret = wit_import();
+                    let ret = unsafe { wit_import0() };
                     ret as u64
                 }
             }
@@ -411,18 +1762,19 @@ pub mod wasi {
                 unsafe {
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "wasi:clocks/monotonic-clock@0.2.0")]
-                    extern "C" {
+                    unsafe extern "C" {
                         #[link_name = "subscribe-instant"]
-                        fn wit_import(_: i64) -> i32;
+                        fn wit_import0(_: i64) -> i32;
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import(_: i64) -> i32 {
+                    unsafe extern "C" fn wit_import0(_: i64) -> i32 {
                         unreachable!()
                     }
-                    let ret = wit_import(_rt::as_i64(when));
-                    super::super::super::wasi::io::poll::Pollable::from_handle(
-                        ret as u32,
-                    )
+                    let ret = unsafe { wit_import0(_rt::as_i64(when)) };
+                    unsafe {
+                        super::super::super::wasi::io::poll::Pollable::from_handle(ret as u32)
+                    }
                 }
             }
             #[allow(unused_unsafe, clippy::all)]
@@ -430,26 +1782,30 @@ pub mod wasi {
                 unsafe {
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "wasi:clocks/monotonic-clock@0.2.0")]
-                    extern "C" {
+                    unsafe extern "C" {
                         #[link_name = "subscribe-duration"]
-                        fn wit_import(_: i64) -> i32;
+                        fn wit_import0(_: i64) -> i32;
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import(_: i64) -> i32 {
+                    unsafe extern "C" fn wit_import0(_: i64) -> i32 {
                         unreachable!()
                     }
-                    let ret = wit_import(_rt::as_i64(when));
-                    super::super::super::wasi::io::poll::Pollable::from_handle(
-                        ret as u32,
-                    )
+                    let ret = unsafe { wit_import0(_rt::as_i64(when)) };
+                    unsafe {
+                        super::super::super::wasi::io::poll::Pollable::from_handle(ret as u32)
+                    }
                 }
             }
         }
-        #[allow(dead_code, clippy::all)]
+
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
         pub mod wall_clock {
             #[used]
             #[doc(hidden)]
-            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+
             #[repr(C)]
             #[derive(Clone, Copy)]
             pub struct Datetime {
@@ -457,10 +1813,7 @@ pub mod wasi {
                 pub nanoseconds: u32,
             }
             impl ::core::fmt::Debug for Datetime {
-                fn fmt(
-                    &self,
-                    f: &mut ::core::fmt::Formatter<'_>,
-                ) -> ::core::fmt::Result {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     f.debug_struct("Datetime")
                         .field("seconds", &self.seconds)
                         .field("nanoseconds", &self.nanoseconds)
@@ -476,21 +1829,23 @@ pub mod wasi {
                     let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "wasi:clocks/wall-clock@0.2.0")]
-                    extern "C" {
+                    unsafe extern "C" {
                         #[link_name = "now"]
-                        fn wit_import(_: *mut u8);
+                        fn wit_import1(_: *mut u8);
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import(_: *mut u8) {
+                    unsafe extern "C" fn wit_import1(_: *mut u8) {
                         unreachable!()
                     }
-                    wit_import(ptr0);
-                    let l1 = *ptr0.add(0).cast::<i64>();
-                    let l2 = *ptr0.add(8).cast::<i32>();
-                    Datetime {
-                        seconds: l1 as u64,
-                        nanoseconds: l2 as u32,
-                    }
+                    unsafe { wit_import1(ptr0) };
+                    let l2 = *ptr0.add(0).cast::<i64>();
+                    let l3 = *ptr0.add(8).cast::<i32>();
+                    let result4 = Datetime {
+                        seconds: l2 as u64,
+                        nanoseconds: l3 as u32,
+                    };
+                    result4
                 }
             }
             #[allow(unused_unsafe, clippy::all)]
@@ -502,32 +1857,36 @@ pub mod wasi {
                     let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "wasi:clocks/wall-clock@0.2.0")]
-                    extern "C" {
+                    unsafe extern "C" {
                         #[link_name = "resolution"]
-                        fn wit_import(_: *mut u8);
+                        fn wit_import1(_: *mut u8);
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import(_: *mut u8) {
+                    unsafe extern "C" fn wit_import1(_: *mut u8) {
                         unreachable!()
                     }
-                    wit_import(ptr0);
-                    let l1 = *ptr0.add(0).cast::<i64>();
-                    let l2 = *ptr0.add(8).cast::<i32>();
-                    Datetime {
-                        seconds: l1 as u64,
-                        nanoseconds: l2 as u32,
-                    }
+                    unsafe { wit_import1(ptr0) };
+                    let l2 = *ptr0.add(0).cast::<i64>();
+                    let l3 = *ptr0.add(8).cast::<i32>();
+                    let result4 = Datetime {
+                        seconds: l2 as u64,
+                        nanoseconds: l3 as u32,
+                    };
+                    result4
                 }
             }
         }
     }
-    #[allow(dead_code)]
     pub mod filesystem {
-        #[allow(dead_code, clippy::all)]
+
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
         pub mod types {
             #[used]
             #[doc(hidden)]
-            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+
             use super::super::super::_rt;
             pub type InputStream = super::super::super::wasi::io::streams::InputStream;
             pub type OutputStream = super::super::super::wasi::io::streams::OutputStream;
@@ -547,10 +1906,7 @@ pub mod wasi {
                 Socket,
             }
             impl ::core::fmt::Debug for DescriptorType {
-                fn fmt(
-                    &self,
-                    f: &mut ::core::fmt::Formatter<'_>,
-                ) -> ::core::fmt::Result {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     match self {
                         DescriptorType::Unknown => {
                             f.debug_tuple("DescriptorType::Unknown").finish()
@@ -564,27 +1920,25 @@ pub mod wasi {
                         DescriptorType::Directory => {
                             f.debug_tuple("DescriptorType::Directory").finish()
                         }
-                        DescriptorType::Fifo => {
-                            f.debug_tuple("DescriptorType::Fifo").finish()
-                        }
+                        DescriptorType::Fifo => f.debug_tuple("DescriptorType::Fifo").finish(),
                         DescriptorType::SymbolicLink => {
                             f.debug_tuple("DescriptorType::SymbolicLink").finish()
                         }
                         DescriptorType::RegularFile => {
                             f.debug_tuple("DescriptorType::RegularFile").finish()
                         }
-                        DescriptorType::Socket => {
-                            f.debug_tuple("DescriptorType::Socket").finish()
-                        }
+                        DescriptorType::Socket => f.debug_tuple("DescriptorType::Socket").finish(),
                     }
                 }
             }
+
             impl DescriptorType {
                 #[doc(hidden)]
                 pub unsafe fn _lift(val: u8) -> DescriptorType {
                     if !cfg!(debug_assertions) {
                         return ::core::mem::transmute(val);
                     }
+
                     match val {
                         0 => DescriptorType::Unknown,
                         1 => DescriptorType::BlockDevice,
@@ -594,24 +1948,37 @@ pub mod wasi {
                         5 => DescriptorType::SymbolicLink,
                         6 => DescriptorType::RegularFile,
                         7 => DescriptorType::Socket,
+
                         _ => panic!("invalid enum discriminant"),
                     }
                 }
             }
+
             wit_bindgen_rt::bitflags::bitflags! {
-                #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)] pub
-                struct DescriptorFlags : u8 { const READ = 1 << 0; const WRITE = 1 << 1;
-                const FILE_INTEGRITY_SYNC = 1 << 2; const DATA_INTEGRITY_SYNC = 1 << 3;
-                const REQUESTED_WRITE_SYNC = 1 << 4; const MUTATE_DIRECTORY = 1 << 5; }
+              #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+              pub struct DescriptorFlags: u8 {
+                const READ = 1 << 0;
+                const WRITE = 1 << 1;
+                const FILE_INTEGRITY_SYNC = 1 << 2;
+                const DATA_INTEGRITY_SYNC = 1 << 3;
+                const REQUESTED_WRITE_SYNC = 1 << 4;
+                const MUTATE_DIRECTORY = 1 << 5;
+              }
             }
             wit_bindgen_rt::bitflags::bitflags! {
-                #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)] pub
-                struct PathFlags : u8 { const SYMLINK_FOLLOW = 1 << 0; }
+              #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+              pub struct PathFlags: u8 {
+                const SYMLINK_FOLLOW = 1 << 0;
+              }
             }
             wit_bindgen_rt::bitflags::bitflags! {
-                #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)] pub
-                struct OpenFlags : u8 { const CREATE = 1 << 0; const DIRECTORY = 1 << 1;
-                const EXCLUSIVE = 1 << 2; const TRUNCATE = 1 << 3; }
+              #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+              pub struct OpenFlags: u8 {
+                const CREATE = 1 << 0;
+                const DIRECTORY = 1 << 1;
+                const EXCLUSIVE = 1 << 2;
+                const TRUNCATE = 1 << 3;
+              }
             }
             pub type LinkCount = u64;
             #[repr(C)]
@@ -625,10 +1992,7 @@ pub mod wasi {
                 pub status_change_timestamp: Option<Datetime>,
             }
             impl ::core::fmt::Debug for DescriptorStat {
-                fn fmt(
-                    &self,
-                    f: &mut ::core::fmt::Formatter<'_>,
-                ) -> ::core::fmt::Result {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     f.debug_struct("DescriptorStat")
                         .field("type", &self.type_)
                         .field("link-count", &self.link_count)
@@ -649,14 +2013,9 @@ pub mod wasi {
                 Timestamp(Datetime),
             }
             impl ::core::fmt::Debug for NewTimestamp {
-                fn fmt(
-                    &self,
-                    f: &mut ::core::fmt::Formatter<'_>,
-                ) -> ::core::fmt::Result {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     match self {
-                        NewTimestamp::NoChange => {
-                            f.debug_tuple("NewTimestamp::NoChange").finish()
-                        }
+                        NewTimestamp::NoChange => f.debug_tuple("NewTimestamp::NoChange").finish(),
                         NewTimestamp::Now => f.debug_tuple("NewTimestamp::Now").finish(),
                         NewTimestamp::Timestamp(e) => {
                             f.debug_tuple("NewTimestamp::Timestamp").field(e).finish()
@@ -670,10 +2029,7 @@ pub mod wasi {
                 pub name: _rt::String,
             }
             impl ::core::fmt::Debug for DirectoryEntry {
-                fn fmt(
-                    &self,
-                    f: &mut ::core::fmt::Formatter<'_>,
-                ) -> ::core::fmt::Result {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     f.debug_struct("DirectoryEntry")
                         .field("type", &self.type_)
                         .field("name", &self.name)
@@ -806,10 +2162,7 @@ pub mod wasi {
                 }
             }
             impl ::core::fmt::Debug for ErrorCode {
-                fn fmt(
-                    &self,
-                    f: &mut ::core::fmt::Formatter<'_>,
-                ) -> ::core::fmt::Result {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     f.debug_struct("ErrorCode")
                         .field("code", &(*self as i32))
                         .field("name", &self.name())
@@ -818,20 +2171,20 @@ pub mod wasi {
                 }
             }
             impl ::core::fmt::Display for ErrorCode {
-                fn fmt(
-                    &self,
-                    f: &mut ::core::fmt::Formatter<'_>,
-                ) -> ::core::fmt::Result {
-                    write!(f, "{} (error {})", self.name(), * self as i32)
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(f, "{} (error {})", self.name(), *self as i32)
                 }
             }
+
             impl std::error::Error for ErrorCode {}
+
             impl ErrorCode {
                 #[doc(hidden)]
                 pub unsafe fn _lift(val: u8) -> ErrorCode {
                     if !cfg!(debug_assertions) {
                         return ::core::mem::transmute(val);
                     }
+
                     match val {
                         0 => ErrorCode::Access,
                         1 => ErrorCode::WouldBlock,
@@ -870,10 +2223,12 @@ pub mod wasi {
                         34 => ErrorCode::InvalidSeek,
                         35 => ErrorCode::TextFileBusy,
                         36 => ErrorCode::CrossDevice,
+
                         _ => panic!("invalid enum discriminant"),
                     }
                 }
             }
+
             #[repr(u8)]
             #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
             pub enum Advice {
@@ -885,15 +2240,10 @@ pub mod wasi {
                 NoReuse,
             }
             impl ::core::fmt::Debug for Advice {
-                fn fmt(
-                    &self,
-                    f: &mut ::core::fmt::Formatter<'_>,
-                ) -> ::core::fmt::Result {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     match self {
                         Advice::Normal => f.debug_tuple("Advice::Normal").finish(),
-                        Advice::Sequential => {
-                            f.debug_tuple("Advice::Sequential").finish()
-                        }
+                        Advice::Sequential => f.debug_tuple("Advice::Sequential").finish(),
                         Advice::Random => f.debug_tuple("Advice::Random").finish(),
                         Advice::WillNeed => f.debug_tuple("Advice::WillNeed").finish(),
                         Advice::DontNeed => f.debug_tuple("Advice::DontNeed").finish(),
@@ -901,12 +2251,14 @@ pub mod wasi {
                     }
                 }
             }
+
             impl Advice {
                 #[doc(hidden)]
                 pub unsafe fn _lift(val: u8) -> Advice {
                     if !cfg!(debug_assertions) {
                         return ::core::mem::transmute(val);
                     }
+
                     match val {
                         0 => Advice::Normal,
                         1 => Advice::Sequential,
@@ -914,10 +2266,12 @@ pub mod wasi {
                         3 => Advice::WillNeed,
                         4 => Advice::DontNeed,
                         5 => Advice::NoReuse,
+
                         _ => panic!("invalid enum discriminant"),
                     }
                 }
             }
+
             #[repr(C)]
             #[derive(Clone, Copy)]
             pub struct MetadataHashValue {
@@ -925,134 +2279,145 @@ pub mod wasi {
                 pub upper: u64,
             }
             impl ::core::fmt::Debug for MetadataHashValue {
-                fn fmt(
-                    &self,
-                    f: &mut ::core::fmt::Formatter<'_>,
-                ) -> ::core::fmt::Result {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     f.debug_struct("MetadataHashValue")
                         .field("lower", &self.lower)
                         .field("upper", &self.upper)
                         .finish()
                 }
             }
+
             #[derive(Debug)]
             #[repr(transparent)]
             pub struct Descriptor {
                 handle: _rt::Resource<Descriptor>,
             }
+
             impl Descriptor {
                 #[doc(hidden)]
                 pub unsafe fn from_handle(handle: u32) -> Self {
                     Self {
-                        handle: _rt::Resource::from_handle(handle),
+                        handle: unsafe { _rt::Resource::from_handle(handle) },
                     }
                 }
+
                 #[doc(hidden)]
                 pub fn take_handle(&self) -> u32 {
                     _rt::Resource::take_handle(&self.handle)
                 }
+
                 #[doc(hidden)]
                 pub fn handle(&self) -> u32 {
                     _rt::Resource::handle(&self.handle)
                 }
             }
+
             unsafe impl _rt::WasmResource for Descriptor {
                 #[inline]
                 unsafe fn drop(_handle: u32) {
                     #[cfg(not(target_arch = "wasm32"))]
                     unreachable!();
+
                     #[cfg(target_arch = "wasm32")]
                     {
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[resource-drop]descriptor"]
                             fn drop(_: u32);
                         }
-                        drop(_handle);
+
+                        unsafe { drop(_handle) };
                     }
                 }
             }
+
             #[derive(Debug)]
             #[repr(transparent)]
             pub struct DirectoryEntryStream {
                 handle: _rt::Resource<DirectoryEntryStream>,
             }
+
             impl DirectoryEntryStream {
                 #[doc(hidden)]
                 pub unsafe fn from_handle(handle: u32) -> Self {
                     Self {
-                        handle: _rt::Resource::from_handle(handle),
+                        handle: unsafe { _rt::Resource::from_handle(handle) },
                     }
                 }
+
                 #[doc(hidden)]
                 pub fn take_handle(&self) -> u32 {
                     _rt::Resource::take_handle(&self.handle)
                 }
+
                 #[doc(hidden)]
                 pub fn handle(&self) -> u32 {
                     _rt::Resource::handle(&self.handle)
                 }
             }
+
             unsafe impl _rt::WasmResource for DirectoryEntryStream {
                 #[inline]
                 unsafe fn drop(_handle: u32) {
                     #[cfg(not(target_arch = "wasm32"))]
                     unreachable!();
+
                     #[cfg(target_arch = "wasm32")]
                     {
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[resource-drop]directory-entry-stream"]
                             fn drop(_: u32);
                         }
-                        drop(_handle);
+
+                        unsafe { drop(_handle) };
                     }
                 }
             }
+
             impl Descriptor {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn read_via_stream(
-                    &self,
-                    offset: Filesize,
-                ) -> Result<InputStream, ErrorCode> {
+                pub fn read_via_stream(&self, offset: Filesize) -> Result<InputStream, ErrorCode> {
                     unsafe {
                         #[repr(align(4))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 8],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.read-via-stream"]
-                            fn wit_import(_: i32, _: i64, _: *mut u8);
+                            fn wit_import1(_: i32, _: i64, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i64, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: i64, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, _rt::as_i64(offset), ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, _rt::as_i64(offset), ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result5 = match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = *ptr0.add(4).cast::<i32>();
-                                    super::super::super::wasi::io::streams::InputStream::from_handle(
-                                        l2 as u32,
-                                    )
+                                    let l3 = *ptr0.add(4).cast::<i32>();
+
+                                    unsafe {
+                                        super::super::super::wasi::io::streams::InputStream::from_handle(l3 as u32)
+                                    }
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l3 = i32::from(*ptr0.add(4).cast::<u8>());
-                                    ErrorCode::_lift(l3 as u8)
+                                    let l4 = i32::from(*ptr0.add(4).cast::<u8>());
+
+                                    ErrorCode::_lift(l4 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result5
                     }
                 }
             }
@@ -1065,41 +2430,43 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(4))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 8],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.write-via-stream"]
-                            fn wit_import(_: i32, _: i64, _: *mut u8);
+                            fn wit_import1(_: i32, _: i64, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i64, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: i64, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, _rt::as_i64(offset), ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, _rt::as_i64(offset), ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result5 = match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = *ptr0.add(4).cast::<i32>();
-                                    super::super::super::wasi::io::streams::OutputStream::from_handle(
-                                        l2 as u32,
-                                    )
+                                    let l3 = *ptr0.add(4).cast::<i32>();
+
+                                    unsafe {
+                                        super::super::super::wasi::io::streams::OutputStream::from_handle(l3 as u32)
+                                    }
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l3 = i32::from(*ptr0.add(4).cast::<u8>());
-                                    ErrorCode::_lift(l3 as u8)
+                                    let l4 = i32::from(*ptr0.add(4).cast::<u8>());
+
+                                    ErrorCode::_lift(l4 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result5
                     }
                 }
             }
@@ -1109,41 +2476,43 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(4))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 8],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.append-via-stream"]
-                            fn wit_import(_: i32, _: *mut u8);
+                            fn wit_import1(_: i32, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result5 = match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = *ptr0.add(4).cast::<i32>();
-                                    super::super::super::wasi::io::streams::OutputStream::from_handle(
-                                        l2 as u32,
-                                    )
+                                    let l3 = *ptr0.add(4).cast::<i32>();
+
+                                    unsafe {
+                                        super::super::super::wasi::io::streams::OutputStream::from_handle(l3 as u32)
+                                    }
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l3 = i32::from(*ptr0.add(4).cast::<u8>());
-                                    ErrorCode::_lift(l3 as u8)
+                                    let l4 = i32::from(*ptr0.add(4).cast::<u8>());
+
+                                    ErrorCode::_lift(l4 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result5
                     }
                 }
             }
@@ -1158,42 +2527,51 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(1))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 2],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.advise"]
-                            fn wit_import(_: i32, _: i64, _: i64, _: i32, _: *mut u8);
+                            fn wit_import1(_: i32, _: i64, _: i64, _: i32, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i64, _: i64, _: i32, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(
+                            _: i32,
+                            _: i64,
+                            _: i64,
+                            _: i32,
+                            _: *mut u8,
+                        ) {
                             unreachable!()
                         }
-                        wit_import(
-                            (self).handle() as i32,
-                            _rt::as_i64(offset),
-                            _rt::as_i64(length),
-                            advice.clone() as i32,
-                            ptr0,
-                        );
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe {
+                            wit_import1(
+                                (self).handle() as i32,
+                                _rt::as_i64(offset),
+                                _rt::as_i64(length),
+                                advice.clone() as i32,
+                                ptr0,
+                            )
+                        };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result4 = match l2 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l2 = i32::from(*ptr0.add(1).cast::<u8>());
-                                    ErrorCode::_lift(l2 as u8)
+                                    let l3 = i32::from(*ptr0.add(1).cast::<u8>());
+
+                                    ErrorCode::_lift(l3 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result4
                     }
                 }
             }
@@ -1203,36 +2581,37 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(1))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 2],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.sync-data"]
-                            fn wit_import(_: i32, _: *mut u8);
+                            fn wit_import1(_: i32, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result4 = match l2 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l2 = i32::from(*ptr0.add(1).cast::<u8>());
-                                    ErrorCode::_lift(l2 as u8)
+                                    let l3 = i32::from(*ptr0.add(1).cast::<u8>());
+
+                                    ErrorCode::_lift(l3 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result4
                     }
                 }
             }
@@ -1242,40 +2621,42 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(1))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 2],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.get-flags"]
-                            fn wit_import(_: i32, _: *mut u8);
+                            fn wit_import1(_: i32, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result5 = match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = i32::from(*ptr0.add(1).cast::<u8>());
+                                    let l3 = i32::from(*ptr0.add(1).cast::<u8>());
+
                                     DescriptorFlags::empty()
-                                        | DescriptorFlags::from_bits_retain(((l2 as u8) << 0) as _)
+                                        | DescriptorFlags::from_bits_retain(((l3 as u8) << 0) as _)
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l3 = i32::from(*ptr0.add(1).cast::<u8>());
-                                    ErrorCode::_lift(l3 as u8)
+                                    let l4 = i32::from(*ptr0.add(1).cast::<u8>());
+
+                                    ErrorCode::_lift(l4 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result5
                     }
                 }
             }
@@ -1285,39 +2666,41 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(1))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 2],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.get-type"]
-                            fn wit_import(_: i32, _: *mut u8);
+                            fn wit_import1(_: i32, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result5 = match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = i32::from(*ptr0.add(1).cast::<u8>());
-                                    DescriptorType::_lift(l2 as u8)
+                                    let l3 = i32::from(*ptr0.add(1).cast::<u8>());
+
+                                    DescriptorType::_lift(l3 as u8)
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l3 = i32::from(*ptr0.add(1).cast::<u8>());
-                                    ErrorCode::_lift(l3 as u8)
+                                    let l4 = i32::from(*ptr0.add(1).cast::<u8>());
+
+                                    ErrorCode::_lift(l4 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result5
                     }
                 }
             }
@@ -1327,36 +2710,37 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(1))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 2],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.set-size"]
-                            fn wit_import(_: i32, _: i64, _: *mut u8);
+                            fn wit_import1(_: i32, _: i64, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i64, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: i64, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, _rt::as_i64(size), ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, _rt::as_i64(size), ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result4 = match l2 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l2 = i32::from(*ptr0.add(1).cast::<u8>());
-                                    ErrorCode::_lift(l2 as u8)
+                                    let l3 = i32::from(*ptr0.add(1).cast::<u8>());
+
+                                    ErrorCode::_lift(l3 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result4
                     }
                 }
             }
@@ -1370,9 +2754,7 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(1))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 2],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
                         let (result1_0, result1_1, result1_2) = match data_access_timestamp {
                             NewTimestamp::NoChange => (0i32, 0i64, 0i32),
                             NewTimestamp::Now => (1i32, 0i64, 0i32),
@@ -1381,6 +2763,7 @@ pub mod wasi {
                                     seconds: seconds0,
                                     nanoseconds: nanoseconds0,
                                 } = e;
+
                                 (2i32, _rt::as_i64(seconds0), _rt::as_i32(nanoseconds0))
                             }
                         };
@@ -1392,15 +2775,16 @@ pub mod wasi {
                                     seconds: seconds2,
                                     nanoseconds: nanoseconds2,
                                 } = e;
+
                                 (2i32, _rt::as_i64(seconds2), _rt::as_i32(nanoseconds2))
                             }
                         };
                         let ptr4 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.set-times"]
-                            fn wit_import(
+                            fn wit_import5(
                                 _: i32,
                                 _: i32,
                                 _: i64,
@@ -1411,8 +2795,9 @@ pub mod wasi {
                                 _: *mut u8,
                             );
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(
+                        unsafe extern "C" fn wit_import5(
                             _: i32,
                             _: i32,
                             _: i64,
@@ -1424,31 +2809,35 @@ pub mod wasi {
                         ) {
                             unreachable!()
                         }
-                        wit_import(
-                            (self).handle() as i32,
-                            result1_0,
-                            result1_1,
-                            result1_2,
-                            result3_0,
-                            result3_1,
-                            result3_2,
-                            ptr4,
-                        );
-                        let l5 = i32::from(*ptr4.add(0).cast::<u8>());
-                        match l5 {
+                        unsafe {
+                            wit_import5(
+                                (self).handle() as i32,
+                                result1_0,
+                                result1_1,
+                                result1_2,
+                                result3_0,
+                                result3_1,
+                                result3_2,
+                                ptr4,
+                            )
+                        };
+                        let l6 = i32::from(*ptr4.add(0).cast::<u8>());
+                        let result8 = match l6 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l6 = i32::from(*ptr4.add(1).cast::<u8>());
-                                    ErrorCode::_lift(l6 as u8)
+                                    let l7 = i32::from(*ptr4.add(1).cast::<u8>());
+
+                                    ErrorCode::_lift(l7 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result8
                     }
                 }
             }
@@ -1460,52 +2849,72 @@ pub mod wasi {
                     offset: Filesize,
                 ) -> Result<(_rt::Vec<u8>, bool), ErrorCode> {
                     unsafe {
-                        #[repr(align(4))]
-                        struct RetArea([::core::mem::MaybeUninit<u8>; 16]);
+                        #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                        #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                        struct RetArea(
+                            [::core::mem::MaybeUninit<u8>; 4 * ::core::mem::size_of::<*const u8>()],
+                        );
                         let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 16],
+                            [::core::mem::MaybeUninit::uninit();
+                                4 * ::core::mem::size_of::<*const u8>()],
                         );
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.read"]
-                            fn wit_import(_: i32, _: i64, _: i64, _: *mut u8);
+                            fn wit_import1(_: i32, _: i64, _: i64, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i64, _: i64, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: i64, _: i64, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import(
-                            (self).handle() as i32,
-                            _rt::as_i64(length),
-                            _rt::as_i64(offset),
-                            ptr0,
-                        );
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe {
+                            wit_import1(
+                                (self).handle() as i32,
+                                _rt::as_i64(length),
+                                _rt::as_i64(offset),
+                                ptr0,
+                            )
+                        };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result8 = match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = *ptr0.add(4).cast::<*mut u8>();
-                                    let l3 = *ptr0.add(8).cast::<usize>();
-                                    let len4 = l3;
-                                    let l5 = i32::from(*ptr0.add(12).cast::<u8>());
+                                    let l3 = *ptr0
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l4 = *ptr0
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let len5 = l4;
+                                    let l6 = i32::from(
+                                        *ptr0
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+
                                     (
-                                        _rt::Vec::from_raw_parts(l2.cast(), len4, len4),
-                                        _rt::bool_lift(l5 as u8),
+                                        _rt::Vec::from_raw_parts(l3.cast(), len5, len5),
+                                        _rt::bool_lift(l6 as u8),
                                     )
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l6 = i32::from(*ptr0.add(4).cast::<u8>());
-                                    ErrorCode::_lift(l6 as u8)
+                                    let l7 = i32::from(
+                                        *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                    );
+
+                                    ErrorCode::_lift(l7 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result8
                     }
                 }
             }
@@ -1519,54 +2928,58 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(8))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 16]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 16],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 16]);
                         let vec0 = buffer;
                         let ptr0 = vec0.as_ptr().cast::<u8>();
                         let len0 = vec0.len();
                         let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.write"]
-                            fn wit_import(
-                                _: i32,
-                                _: *mut u8,
-                                _: usize,
-                                _: i64,
-                                _: *mut u8,
-                            );
+                            fn wit_import2(_: i32, _: *mut u8, _: usize, _: i64, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8, _: usize, _: i64, _: *mut u8) {
+                        unsafe extern "C" fn wit_import2(
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: i64,
+                            _: *mut u8,
+                        ) {
                             unreachable!()
                         }
-                        wit_import(
-                            (self).handle() as i32,
-                            ptr0.cast_mut(),
-                            len0,
-                            _rt::as_i64(offset),
-                            ptr1,
-                        );
-                        let l2 = i32::from(*ptr1.add(0).cast::<u8>());
-                        match l2 {
+                        unsafe {
+                            wit_import2(
+                                (self).handle() as i32,
+                                ptr0.cast_mut(),
+                                len0,
+                                _rt::as_i64(offset),
+                                ptr1,
+                            )
+                        };
+                        let l3 = i32::from(*ptr1.add(0).cast::<u8>());
+                        let result6 = match l3 {
                             0 => {
                                 let e = {
-                                    let l3 = *ptr1.add(8).cast::<i64>();
-                                    l3 as u64
+                                    let l4 = *ptr1.add(8).cast::<i64>();
+
+                                    l4 as u64
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l4 = i32::from(*ptr1.add(8).cast::<u8>());
-                                    ErrorCode::_lift(l4 as u8)
+                                    let l5 = i32::from(*ptr1.add(8).cast::<u8>());
+
+                                    ErrorCode::_lift(l5 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result6
                     }
                 }
             }
@@ -1576,39 +2989,41 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(4))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 8],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.read-directory"]
-                            fn wit_import(_: i32, _: *mut u8);
+                            fn wit_import1(_: i32, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result5 = match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = *ptr0.add(4).cast::<i32>();
-                                    DirectoryEntryStream::from_handle(l2 as u32)
+                                    let l3 = *ptr0.add(4).cast::<i32>();
+
+                                    unsafe { DirectoryEntryStream::from_handle(l3 as u32) }
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l3 = i32::from(*ptr0.add(4).cast::<u8>());
-                                    ErrorCode::_lift(l3 as u8)
+                                    let l4 = i32::from(*ptr0.add(4).cast::<u8>());
+
+                                    ErrorCode::_lift(l4 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result5
                     }
                 }
             }
@@ -1618,36 +3033,37 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(1))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 2],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.sync"]
-                            fn wit_import(_: i32, _: *mut u8);
+                            fn wit_import1(_: i32, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result4 = match l2 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l2 = i32::from(*ptr0.add(1).cast::<u8>());
-                                    ErrorCode::_lift(l2 as u8)
+                                    let l3 = i32::from(*ptr0.add(1).cast::<u8>());
+
+                                    ErrorCode::_lift(l3 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result4
                     }
                 }
             }
@@ -1657,39 +3073,40 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(1))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 2],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
                         let vec0 = path;
                         let ptr0 = vec0.as_ptr().cast::<u8>();
                         let len0 = vec0.len();
                         let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.create-directory-at"]
-                            fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8);
+                            fn wit_import2(_: i32, _: *mut u8, _: usize, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8) {
+                        unsafe extern "C" fn wit_import2(_: i32, _: *mut u8, _: usize, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0.cast_mut(), len0, ptr1);
-                        let l2 = i32::from(*ptr1.add(0).cast::<u8>());
-                        match l2 {
+                        unsafe { wit_import2((self).handle() as i32, ptr0.cast_mut(), len0, ptr1) };
+                        let l3 = i32::from(*ptr1.add(0).cast::<u8>());
+                        let result5 = match l3 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l3 = i32::from(*ptr1.add(1).cast::<u8>());
-                                    ErrorCode::_lift(l3 as u8)
+                                    let l4 = i32::from(*ptr1.add(1).cast::<u8>());
+
+                                    ErrorCode::_lift(l4 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result5
                     }
                 }
             }
@@ -1699,75 +3116,78 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(8))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 104]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 104],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 104]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.stat"]
-                            fn wit_import(_: i32, _: *mut u8);
+                            fn wit_import1(_: i32, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result16 = match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = i32::from(*ptr0.add(8).cast::<u8>());
-                                    let l3 = *ptr0.add(16).cast::<i64>();
-                                    let l4 = *ptr0.add(24).cast::<i64>();
-                                    let l5 = i32::from(*ptr0.add(32).cast::<u8>());
-                                    let l8 = i32::from(*ptr0.add(56).cast::<u8>());
-                                    let l11 = i32::from(*ptr0.add(80).cast::<u8>());
+                                    let l3 = i32::from(*ptr0.add(8).cast::<u8>());
+                                    let l4 = *ptr0.add(16).cast::<i64>();
+                                    let l5 = *ptr0.add(24).cast::<i64>();
+                                    let l6 = i32::from(*ptr0.add(32).cast::<u8>());
+                                    let l9 = i32::from(*ptr0.add(56).cast::<u8>());
+                                    let l12 = i32::from(*ptr0.add(80).cast::<u8>());
+
                                     DescriptorStat {
-                                        type_: DescriptorType::_lift(l2 as u8),
-                                        link_count: l3 as u64,
-                                        size: l4 as u64,
-                                        data_access_timestamp: match l5 {
+                                        type_: DescriptorType::_lift(l3 as u8),
+                                        link_count: l4 as u64,
+                                        size: l5 as u64,
+                                        data_access_timestamp: match l6 {
                                             0 => None,
                                             1 => {
                                                 let e = {
-                                                    let l6 = *ptr0.add(40).cast::<i64>();
-                                                    let l7 = *ptr0.add(48).cast::<i32>();
-                                                    super::super::super::wasi::clocks::wall_clock::Datetime {
-                                                        seconds: l6 as u64,
-                                                        nanoseconds: l7 as u32,
-                                                    }
+                                                    let l7 = *ptr0.add(40).cast::<i64>();
+                                                    let l8 = *ptr0.add(48).cast::<i32>();
+
+                                                    super::super::super::wasi::clocks::wall_clock::Datetime{
+                            seconds: l7 as u64,
+                            nanoseconds: l8 as u32,
+                          }
                                                 };
                                                 Some(e)
                                             }
                                             _ => _rt::invalid_enum_discriminant(),
                                         },
-                                        data_modification_timestamp: match l8 {
+                                        data_modification_timestamp: match l9 {
                                             0 => None,
                                             1 => {
                                                 let e = {
-                                                    let l9 = *ptr0.add(64).cast::<i64>();
-                                                    let l10 = *ptr0.add(72).cast::<i32>();
-                                                    super::super::super::wasi::clocks::wall_clock::Datetime {
-                                                        seconds: l9 as u64,
-                                                        nanoseconds: l10 as u32,
-                                                    }
+                                                    let l10 = *ptr0.add(64).cast::<i64>();
+                                                    let l11 = *ptr0.add(72).cast::<i32>();
+
+                                                    super::super::super::wasi::clocks::wall_clock::Datetime{
+                            seconds: l10 as u64,
+                            nanoseconds: l11 as u32,
+                          }
                                                 };
                                                 Some(e)
                                             }
                                             _ => _rt::invalid_enum_discriminant(),
                                         },
-                                        status_change_timestamp: match l11 {
+                                        status_change_timestamp: match l12 {
                                             0 => None,
                                             1 => {
                                                 let e = {
-                                                    let l12 = *ptr0.add(88).cast::<i64>();
-                                                    let l13 = *ptr0.add(96).cast::<i32>();
-                                                    super::super::super::wasi::clocks::wall_clock::Datetime {
-                                                        seconds: l12 as u64,
-                                                        nanoseconds: l13 as u32,
-                                                    }
+                                                    let l13 = *ptr0.add(88).cast::<i64>();
+                                                    let l14 = *ptr0.add(96).cast::<i32>();
+
+                                                    super::super::super::wasi::clocks::wall_clock::Datetime{
+                            seconds: l13 as u64,
+                            nanoseconds: l14 as u32,
+                          }
                                                 };
                                                 Some(e)
                                             }
@@ -1779,13 +3199,15 @@ pub mod wasi {
                             }
                             1 => {
                                 let e = {
-                                    let l14 = i32::from(*ptr0.add(8).cast::<u8>());
-                                    ErrorCode::_lift(l14 as u8)
+                                    let l15 = i32::from(*ptr0.add(8).cast::<u8>());
+
+                                    ErrorCode::_lift(l15 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result16
                     }
                 }
             }
@@ -1799,9 +3221,7 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(8))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 104]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 104],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 104]);
                         let flags0 = path_flags;
                         let vec1 = path;
                         let ptr1 = vec1.as_ptr().cast::<u8>();
@@ -1809,81 +3229,88 @@ pub mod wasi {
                         let ptr2 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.stat-at"]
-                            fn wit_import(
-                                _: i32,
-                                _: i32,
-                                _: *mut u8,
-                                _: usize,
-                                _: *mut u8,
-                            );
+                            fn wit_import3(_: i32, _: i32, _: *mut u8, _: usize, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i32, _: *mut u8, _: usize, _: *mut u8) {
+                        unsafe extern "C" fn wit_import3(
+                            _: i32,
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                        ) {
                             unreachable!()
                         }
-                        wit_import(
-                            (self).handle() as i32,
-                            (flags0.bits() >> 0) as i32,
-                            ptr1.cast_mut(),
-                            len1,
-                            ptr2,
-                        );
-                        let l3 = i32::from(*ptr2.add(0).cast::<u8>());
-                        match l3 {
+                        unsafe {
+                            wit_import3(
+                                (self).handle() as i32,
+                                (flags0.bits() >> 0) as i32,
+                                ptr1.cast_mut(),
+                                len1,
+                                ptr2,
+                            )
+                        };
+                        let l4 = i32::from(*ptr2.add(0).cast::<u8>());
+                        let result18 = match l4 {
                             0 => {
                                 let e = {
-                                    let l4 = i32::from(*ptr2.add(8).cast::<u8>());
-                                    let l5 = *ptr2.add(16).cast::<i64>();
-                                    let l6 = *ptr2.add(24).cast::<i64>();
-                                    let l7 = i32::from(*ptr2.add(32).cast::<u8>());
-                                    let l10 = i32::from(*ptr2.add(56).cast::<u8>());
-                                    let l13 = i32::from(*ptr2.add(80).cast::<u8>());
+                                    let l5 = i32::from(*ptr2.add(8).cast::<u8>());
+                                    let l6 = *ptr2.add(16).cast::<i64>();
+                                    let l7 = *ptr2.add(24).cast::<i64>();
+                                    let l8 = i32::from(*ptr2.add(32).cast::<u8>());
+                                    let l11 = i32::from(*ptr2.add(56).cast::<u8>());
+                                    let l14 = i32::from(*ptr2.add(80).cast::<u8>());
+
                                     DescriptorStat {
-                                        type_: DescriptorType::_lift(l4 as u8),
-                                        link_count: l5 as u64,
-                                        size: l6 as u64,
-                                        data_access_timestamp: match l7 {
+                                        type_: DescriptorType::_lift(l5 as u8),
+                                        link_count: l6 as u64,
+                                        size: l7 as u64,
+                                        data_access_timestamp: match l8 {
                                             0 => None,
                                             1 => {
                                                 let e = {
-                                                    let l8 = *ptr2.add(40).cast::<i64>();
-                                                    let l9 = *ptr2.add(48).cast::<i32>();
-                                                    super::super::super::wasi::clocks::wall_clock::Datetime {
-                                                        seconds: l8 as u64,
-                                                        nanoseconds: l9 as u32,
-                                                    }
+                                                    let l9 = *ptr2.add(40).cast::<i64>();
+                                                    let l10 = *ptr2.add(48).cast::<i32>();
+
+                                                    super::super::super::wasi::clocks::wall_clock::Datetime{
+                            seconds: l9 as u64,
+                            nanoseconds: l10 as u32,
+                          }
                                                 };
                                                 Some(e)
                                             }
                                             _ => _rt::invalid_enum_discriminant(),
                                         },
-                                        data_modification_timestamp: match l10 {
+                                        data_modification_timestamp: match l11 {
                                             0 => None,
                                             1 => {
                                                 let e = {
-                                                    let l11 = *ptr2.add(64).cast::<i64>();
-                                                    let l12 = *ptr2.add(72).cast::<i32>();
-                                                    super::super::super::wasi::clocks::wall_clock::Datetime {
-                                                        seconds: l11 as u64,
-                                                        nanoseconds: l12 as u32,
-                                                    }
+                                                    let l12 = *ptr2.add(64).cast::<i64>();
+                                                    let l13 = *ptr2.add(72).cast::<i32>();
+
+                                                    super::super::super::wasi::clocks::wall_clock::Datetime{
+                            seconds: l12 as u64,
+                            nanoseconds: l13 as u32,
+                          }
                                                 };
                                                 Some(e)
                                             }
                                             _ => _rt::invalid_enum_discriminant(),
                                         },
-                                        status_change_timestamp: match l13 {
+                                        status_change_timestamp: match l14 {
                                             0 => None,
                                             1 => {
                                                 let e = {
-                                                    let l14 = *ptr2.add(88).cast::<i64>();
-                                                    let l15 = *ptr2.add(96).cast::<i32>();
-                                                    super::super::super::wasi::clocks::wall_clock::Datetime {
-                                                        seconds: l14 as u64,
-                                                        nanoseconds: l15 as u32,
-                                                    }
+                                                    let l15 = *ptr2.add(88).cast::<i64>();
+                                                    let l16 = *ptr2.add(96).cast::<i32>();
+
+                                                    super::super::super::wasi::clocks::wall_clock::Datetime{
+                            seconds: l15 as u64,
+                            nanoseconds: l16 as u32,
+                          }
                                                 };
                                                 Some(e)
                                             }
@@ -1895,13 +3322,15 @@ pub mod wasi {
                             }
                             1 => {
                                 let e = {
-                                    let l16 = i32::from(*ptr2.add(8).cast::<u8>());
-                                    ErrorCode::_lift(l16 as u8)
+                                    let l17 = i32::from(*ptr2.add(8).cast::<u8>());
+
+                                    ErrorCode::_lift(l17 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result18
                     }
                 }
             }
@@ -1917,9 +3346,7 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(1))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 2],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
                         let flags0 = path_flags;
                         let vec1 = path;
                         let ptr1 = vec1.as_ptr().cast::<u8>();
@@ -1932,6 +3359,7 @@ pub mod wasi {
                                     seconds: seconds2,
                                     nanoseconds: nanoseconds2,
                                 } = e;
+
                                 (2i32, _rt::as_i64(seconds2), _rt::as_i32(nanoseconds2))
                             }
                         };
@@ -1943,15 +3371,16 @@ pub mod wasi {
                                     seconds: seconds4,
                                     nanoseconds: nanoseconds4,
                                 } = e;
+
                                 (2i32, _rt::as_i64(seconds4), _rt::as_i32(nanoseconds4))
                             }
                         };
                         let ptr6 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.set-times-at"]
-                            fn wit_import(
+                            fn wit_import7(
                                 _: i32,
                                 _: i32,
                                 _: *mut u8,
@@ -1965,8 +3394,9 @@ pub mod wasi {
                                 _: *mut u8,
                             );
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(
+                        unsafe extern "C" fn wit_import7(
                             _: i32,
                             _: i32,
                             _: *mut u8,
@@ -1981,34 +3411,38 @@ pub mod wasi {
                         ) {
                             unreachable!()
                         }
-                        wit_import(
-                            (self).handle() as i32,
-                            (flags0.bits() >> 0) as i32,
-                            ptr1.cast_mut(),
-                            len1,
-                            result3_0,
-                            result3_1,
-                            result3_2,
-                            result5_0,
-                            result5_1,
-                            result5_2,
-                            ptr6,
-                        );
-                        let l7 = i32::from(*ptr6.add(0).cast::<u8>());
-                        match l7 {
+                        unsafe {
+                            wit_import7(
+                                (self).handle() as i32,
+                                (flags0.bits() >> 0) as i32,
+                                ptr1.cast_mut(),
+                                len1,
+                                result3_0,
+                                result3_1,
+                                result3_2,
+                                result5_0,
+                                result5_1,
+                                result5_2,
+                                ptr6,
+                            )
+                        };
+                        let l8 = i32::from(*ptr6.add(0).cast::<u8>());
+                        let result10 = match l8 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l8 = i32::from(*ptr6.add(1).cast::<u8>());
-                                    ErrorCode::_lift(l8 as u8)
+                                    let l9 = i32::from(*ptr6.add(1).cast::<u8>());
+
+                                    ErrorCode::_lift(l9 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result10
                     }
                 }
             }
@@ -2024,9 +3458,7 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(1))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 2],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
                         let flags0 = old_path_flags;
                         let vec1 = old_path;
                         let ptr1 = vec1.as_ptr().cast::<u8>();
@@ -2037,9 +3469,9 @@ pub mod wasi {
                         let ptr3 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.link-at"]
-                            fn wit_import(
+                            fn wit_import4(
                                 _: i32,
                                 _: i32,
                                 _: *mut u8,
@@ -2050,8 +3482,9 @@ pub mod wasi {
                                 _: *mut u8,
                             );
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(
+                        unsafe extern "C" fn wit_import4(
                             _: i32,
                             _: i32,
                             _: *mut u8,
@@ -2063,31 +3496,35 @@ pub mod wasi {
                         ) {
                             unreachable!()
                         }
-                        wit_import(
-                            (self).handle() as i32,
-                            (flags0.bits() >> 0) as i32,
-                            ptr1.cast_mut(),
-                            len1,
-                            (new_descriptor).handle() as i32,
-                            ptr2.cast_mut(),
-                            len2,
-                            ptr3,
-                        );
-                        let l4 = i32::from(*ptr3.add(0).cast::<u8>());
-                        match l4 {
+                        unsafe {
+                            wit_import4(
+                                (self).handle() as i32,
+                                (flags0.bits() >> 0) as i32,
+                                ptr1.cast_mut(),
+                                len1,
+                                (new_descriptor).handle() as i32,
+                                ptr2.cast_mut(),
+                                len2,
+                                ptr3,
+                            )
+                        };
+                        let l5 = i32::from(*ptr3.add(0).cast::<u8>());
+                        let result7 = match l5 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l5 = i32::from(*ptr3.add(1).cast::<u8>());
-                                    ErrorCode::_lift(l5 as u8)
+                                    let l6 = i32::from(*ptr3.add(1).cast::<u8>());
+
+                                    ErrorCode::_lift(l6 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result7
                     }
                 }
             }
@@ -2103,9 +3540,7 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(4))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 8],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
                         let flags0 = path_flags;
                         let vec1 = path;
                         let ptr1 = vec1.as_ptr().cast::<u8>();
@@ -2115,9 +3550,9 @@ pub mod wasi {
                         let ptr4 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.open-at"]
-                            fn wit_import(
+                            fn wit_import5(
                                 _: i32,
                                 _: i32,
                                 _: *mut u8,
@@ -2127,8 +3562,9 @@ pub mod wasi {
                                 _: *mut u8,
                             );
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(
+                        unsafe extern "C" fn wit_import5(
                             _: i32,
                             _: i32,
                             _: *mut u8,
@@ -2139,33 +3575,38 @@ pub mod wasi {
                         ) {
                             unreachable!()
                         }
-                        wit_import(
-                            (self).handle() as i32,
-                            (flags0.bits() >> 0) as i32,
-                            ptr1.cast_mut(),
-                            len1,
-                            (flags2.bits() >> 0) as i32,
-                            (flags3.bits() >> 0) as i32,
-                            ptr4,
-                        );
-                        let l5 = i32::from(*ptr4.add(0).cast::<u8>());
-                        match l5 {
+                        unsafe {
+                            wit_import5(
+                                (self).handle() as i32,
+                                (flags0.bits() >> 0) as i32,
+                                ptr1.cast_mut(),
+                                len1,
+                                (flags2.bits() >> 0) as i32,
+                                (flags3.bits() >> 0) as i32,
+                                ptr4,
+                            )
+                        };
+                        let l6 = i32::from(*ptr4.add(0).cast::<u8>());
+                        let result9 = match l6 {
                             0 => {
                                 let e = {
-                                    let l6 = *ptr4.add(4).cast::<i32>();
-                                    Descriptor::from_handle(l6 as u32)
+                                    let l7 = *ptr4.add(4).cast::<i32>();
+
+                                    unsafe { Descriptor::from_handle(l7 as u32) }
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l7 = i32::from(*ptr4.add(4).cast::<u8>());
-                                    ErrorCode::_lift(l7 as u8)
+                                    let l8 = i32::from(*ptr4.add(4).cast::<u8>());
+
+                                    ErrorCode::_lift(l8 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result9
                     }
                 }
             }
@@ -2173,10 +3614,14 @@ pub mod wasi {
                 #[allow(unused_unsafe, clippy::all)]
                 pub fn readlink_at(&self, path: &str) -> Result<_rt::String, ErrorCode> {
                     unsafe {
-                        #[repr(align(4))]
-                        struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
+                        #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                        #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                        struct RetArea(
+                            [::core::mem::MaybeUninit<u8>; 3 * ::core::mem::size_of::<*const u8>()],
+                        );
                         let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 12],
+                            [::core::mem::MaybeUninit::uninit();
+                                3 * ::core::mem::size_of::<*const u8>()],
                         );
                         let vec0 = path;
                         let ptr0 = vec0.as_ptr().cast::<u8>();
@@ -2184,40 +3629,46 @@ pub mod wasi {
                         let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.readlink-at"]
-                            fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8);
+                            fn wit_import2(_: i32, _: *mut u8, _: usize, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8) {
+                        unsafe extern "C" fn wit_import2(_: i32, _: *mut u8, _: usize, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0.cast_mut(), len0, ptr1);
-                        let l2 = i32::from(*ptr1.add(0).cast::<u8>());
-                        match l2 {
+                        unsafe { wit_import2((self).handle() as i32, ptr0.cast_mut(), len0, ptr1) };
+                        let l3 = i32::from(*ptr1.add(0).cast::<u8>());
+                        let result8 = match l3 {
                             0 => {
                                 let e = {
-                                    let l3 = *ptr1.add(4).cast::<*mut u8>();
-                                    let l4 = *ptr1.add(8).cast::<usize>();
-                                    let len5 = l4;
-                                    let bytes5 = _rt::Vec::from_raw_parts(
-                                        l3.cast(),
-                                        len5,
-                                        len5,
-                                    );
-                                    _rt::string_lift(bytes5)
+                                    let l4 = *ptr1
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l5 = *ptr1
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let len6 = l5;
+                                    let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+
+                                    _rt::string_lift(bytes6)
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l6 = i32::from(*ptr1.add(4).cast::<u8>());
-                                    ErrorCode::_lift(l6 as u8)
+                                    let l7 = i32::from(
+                                        *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                    );
+
+                                    ErrorCode::_lift(l7 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result8
                     }
                 }
             }
@@ -2227,39 +3678,40 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(1))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 2],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
                         let vec0 = path;
                         let ptr0 = vec0.as_ptr().cast::<u8>();
                         let len0 = vec0.len();
                         let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.remove-directory-at"]
-                            fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8);
+                            fn wit_import2(_: i32, _: *mut u8, _: usize, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8) {
+                        unsafe extern "C" fn wit_import2(_: i32, _: *mut u8, _: usize, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0.cast_mut(), len0, ptr1);
-                        let l2 = i32::from(*ptr1.add(0).cast::<u8>());
-                        match l2 {
+                        unsafe { wit_import2((self).handle() as i32, ptr0.cast_mut(), len0, ptr1) };
+                        let l3 = i32::from(*ptr1.add(0).cast::<u8>());
+                        let result5 = match l3 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l3 = i32::from(*ptr1.add(1).cast::<u8>());
-                                    ErrorCode::_lift(l3 as u8)
+                                    let l4 = i32::from(*ptr1.add(1).cast::<u8>());
+
+                                    ErrorCode::_lift(l4 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result5
                     }
                 }
             }
@@ -2274,9 +3726,7 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(1))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 2],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
                         let vec0 = old_path;
                         let ptr0 = vec0.as_ptr().cast::<u8>();
                         let len0 = vec0.len();
@@ -2286,9 +3736,9 @@ pub mod wasi {
                         let ptr2 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.rename-at"]
-                            fn wit_import(
+                            fn wit_import3(
                                 _: i32,
                                 _: *mut u8,
                                 _: usize,
@@ -2298,8 +3748,9 @@ pub mod wasi {
                                 _: *mut u8,
                             );
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(
+                        unsafe extern "C" fn wit_import3(
                             _: i32,
                             _: *mut u8,
                             _: usize,
@@ -2307,49 +3758,47 @@ pub mod wasi {
                             _: *mut u8,
                             _: usize,
                             _: *mut u8,
-                        ) {
-                            unreachable!()
-                        }
-                        wit_import(
-                            (self).handle() as i32,
-                            ptr0.cast_mut(),
-                            len0,
-                            (new_descriptor).handle() as i32,
-                            ptr1.cast_mut(),
-                            len1,
-                            ptr2,
-                        );
-                        let l3 = i32::from(*ptr2.add(0).cast::<u8>());
-                        match l3 {
+                        ) {
+                            unreachable!()
+                        }
+                        unsafe {
+                            wit_import3(
+                                (self).handle() as i32,
+                                ptr0.cast_mut(),
+                                len0,
+                                (new_descriptor).handle() as i32,
+                                ptr1.cast_mut(),
+                                len1,
+                                ptr2,
+                            )
+                        };
+                        let l4 = i32::from(*ptr2.add(0).cast::<u8>());
+                        let result6 = match l4 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l4 = i32::from(*ptr2.add(1).cast::<u8>());
-                                    ErrorCode::_lift(l4 as u8)
+                                    let l5 = i32::from(*ptr2.add(1).cast::<u8>());
+
+                                    ErrorCode::_lift(l5 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result6
                     }
                 }
             }
             impl Descriptor {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn symlink_at(
-                    &self,
-                    old_path: &str,
-                    new_path: &str,
-                ) -> Result<(), ErrorCode> {
+                pub fn symlink_at(&self, old_path: &str, new_path: &str) -> Result<(), ErrorCode> {
                     unsafe {
                         #[repr(align(1))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 2],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
                         let vec0 = old_path;
                         let ptr0 = vec0.as_ptr().cast::<u8>();
                         let len0 = vec0.len();
@@ -2359,9 +3808,9 @@ pub mod wasi {
                         let ptr2 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.symlink-at"]
-                            fn wit_import(
+                            fn wit_import3(
                                 _: i32,
                                 _: *mut u8,
                                 _: usize,
@@ -2370,8 +3819,9 @@ pub mod wasi {
                                 _: *mut u8,
                             );
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(
+                        unsafe extern "C" fn wit_import3(
                             _: i32,
                             _: *mut u8,
                             _: usize,
@@ -2381,29 +3831,33 @@ pub mod wasi {
                         ) {
                             unreachable!()
                         }
-                        wit_import(
-                            (self).handle() as i32,
-                            ptr0.cast_mut(),
-                            len0,
-                            ptr1.cast_mut(),
-                            len1,
-                            ptr2,
-                        );
-                        let l3 = i32::from(*ptr2.add(0).cast::<u8>());
-                        match l3 {
+                        unsafe {
+                            wit_import3(
+                                (self).handle() as i32,
+                                ptr0.cast_mut(),
+                                len0,
+                                ptr1.cast_mut(),
+                                len1,
+                                ptr2,
+                            )
+                        };
+                        let l4 = i32::from(*ptr2.add(0).cast::<u8>());
+                        let result6 = match l4 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l4 = i32::from(*ptr2.add(1).cast::<u8>());
-                                    ErrorCode::_lift(l4 as u8)
+                                    let l5 = i32::from(*ptr2.add(1).cast::<u8>());
+
+                                    ErrorCode::_lift(l5 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result6
                     }
                 }
             }
@@ -2413,39 +3867,40 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(1))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 2],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
                         let vec0 = path;
                         let ptr0 = vec0.as_ptr().cast::<u8>();
                         let len0 = vec0.len();
                         let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.unlink-file-at"]
-                            fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8);
+                            fn wit_import2(_: i32, _: *mut u8, _: usize, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8) {
+                        unsafe extern "C" fn wit_import2(_: i32, _: *mut u8, _: usize, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0.cast_mut(), len0, ptr1);
-                        let l2 = i32::from(*ptr1.add(0).cast::<u8>());
-                        match l2 {
+                        unsafe { wit_import2((self).handle() as i32, ptr0.cast_mut(), len0, ptr1) };
+                        let l3 = i32::from(*ptr1.add(0).cast::<u8>());
+                        let result5 = match l3 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l3 = i32::from(*ptr1.add(1).cast::<u8>());
-                                    ErrorCode::_lift(l3 as u8)
+                                    let l4 = i32::from(*ptr1.add(1).cast::<u8>());
+
+                                    ErrorCode::_lift(l4 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result5
                     }
                 }
             }
@@ -2455,18 +3910,17 @@ pub mod wasi {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.is-same-object"]
-                            fn wit_import(_: i32, _: i32) -> i32;
+                            fn wit_import0(_: i32, _: i32) -> i32;
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i32) -> i32 {
+                        unsafe extern "C" fn wit_import0(_: i32, _: i32) -> i32 {
                             unreachable!()
                         }
-                        let ret = wit_import(
-                            (self).handle() as i32,
-                            (other).handle() as i32,
-                        );
+                        let ret =
+                            unsafe { wit_import0((self).handle() as i32, (other).handle() as i32) };
                         _rt::bool_lift(ret as u8)
                     }
                 }
@@ -2477,43 +3931,45 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(8))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 24]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 24],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 24]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.metadata-hash"]
-                            fn wit_import(_: i32, _: *mut u8);
+                            fn wit_import1(_: i32, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result6 = match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = *ptr0.add(8).cast::<i64>();
-                                    let l3 = *ptr0.add(16).cast::<i64>();
+                                    let l3 = *ptr0.add(8).cast::<i64>();
+                                    let l4 = *ptr0.add(16).cast::<i64>();
+
                                     MetadataHashValue {
-                                        lower: l2 as u64,
-                                        upper: l3 as u64,
+                                        lower: l3 as u64,
+                                        upper: l4 as u64,
                                     }
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l4 = i32::from(*ptr0.add(8).cast::<u8>());
-                                    ErrorCode::_lift(l4 as u8)
+                                    let l5 = i32::from(*ptr0.add(8).cast::<u8>());
+
+                                    ErrorCode::_lift(l5 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result6
                     }
                 }
             }
@@ -2527,9 +3983,7 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(8))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 24]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 24],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 24]);
                         let flags0 = path_flags;
                         let vec1 = path;
                         let ptr1 = vec1.as_ptr().cast::<u8>();
@@ -2537,96 +3991,116 @@ pub mod wasi {
                         let ptr2 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]descriptor.metadata-hash-at"]
-                            fn wit_import(
-                                _: i32,
-                                _: i32,
-                                _: *mut u8,
-                                _: usize,
-                                _: *mut u8,
-                            );
+                            fn wit_import3(_: i32, _: i32, _: *mut u8, _: usize, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i32, _: *mut u8, _: usize, _: *mut u8) {
+                        unsafe extern "C" fn wit_import3(
+                            _: i32,
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                        ) {
                             unreachable!()
                         }
-                        wit_import(
-                            (self).handle() as i32,
-                            (flags0.bits() >> 0) as i32,
-                            ptr1.cast_mut(),
-                            len1,
-                            ptr2,
-                        );
-                        let l3 = i32::from(*ptr2.add(0).cast::<u8>());
-                        match l3 {
+                        unsafe {
+                            wit_import3(
+                                (self).handle() as i32,
+                                (flags0.bits() >> 0) as i32,
+                                ptr1.cast_mut(),
+                                len1,
+                                ptr2,
+                            )
+                        };
+                        let l4 = i32::from(*ptr2.add(0).cast::<u8>());
+                        let result8 = match l4 {
                             0 => {
                                 let e = {
-                                    let l4 = *ptr2.add(8).cast::<i64>();
-                                    let l5 = *ptr2.add(16).cast::<i64>();
+                                    let l5 = *ptr2.add(8).cast::<i64>();
+                                    let l6 = *ptr2.add(16).cast::<i64>();
+
                                     MetadataHashValue {
-                                        lower: l4 as u64,
-                                        upper: l5 as u64,
+                                        lower: l5 as u64,
+                                        upper: l6 as u64,
                                     }
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l6 = i32::from(*ptr2.add(8).cast::<u8>());
-                                    ErrorCode::_lift(l6 as u8)
+                                    let l7 = i32::from(*ptr2.add(8).cast::<u8>());
+
+                                    ErrorCode::_lift(l7 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result8
                     }
                 }
             }
             impl DirectoryEntryStream {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn read_directory_entry(
-                    &self,
-                ) -> Result<Option<DirectoryEntry>, ErrorCode> {
+                pub fn read_directory_entry(&self) -> Result<Option<DirectoryEntry>, ErrorCode> {
                     unsafe {
-                        #[repr(align(4))]
-                        struct RetArea([::core::mem::MaybeUninit<u8>; 20]);
+                        #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                        #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                        struct RetArea(
+                            [::core::mem::MaybeUninit<u8>; 5 * ::core::mem::size_of::<*const u8>()],
+                        );
                         let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 20],
+                            [::core::mem::MaybeUninit::uninit();
+                                5 * ::core::mem::size_of::<*const u8>()],
                         );
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]directory-entry-stream.read-directory-entry"]
-                            fn wit_import(_: i32, _: *mut u8);
+                            fn wit_import1(_: i32, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result9 = match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = i32::from(*ptr0.add(4).cast::<u8>());
-                                    match l2 {
+                                    let l3 = i32::from(
+                                        *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                    );
+
+                                    match l3 {
                                         0 => None,
                                         1 => {
                                             let e = {
-                                                let l3 = i32::from(*ptr0.add(8).cast::<u8>());
-                                                let l4 = *ptr0.add(12).cast::<*mut u8>();
-                                                let l5 = *ptr0.add(16).cast::<usize>();
-                                                let len6 = l5;
-                                                let bytes6 = _rt::Vec::from_raw_parts(
-                                                    l4.cast(),
-                                                    len6,
-                                                    len6,
+                                                let l4 = i32::from(
+                                                    *ptr0
+                                                        .add(
+                                                            2 * ::core::mem::size_of::<*const u8>(),
+                                                        )
+                                                        .cast::<u8>(),
                                                 );
+                                                let l5 = *ptr0
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>();
+                                                let l6 = *ptr0
+                                                    .add(4 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let len7 = l6;
+                                                let bytes7 =
+                                                    _rt::Vec::from_raw_parts(l5.cast(), len7, len7);
+
                                                 DirectoryEntry {
-                                                    type_: DescriptorType::_lift(l3 as u8),
-                                                    name: _rt::string_lift(bytes6),
+                                                    type_: DescriptorType::_lift(l4 as u8),
+                                                    name: _rt::string_lift(bytes7),
                                                 }
                                             };
                                             Some(e)
@@ -2638,13 +4112,17 @@ pub mod wasi {
                             }
                             1 => {
                                 let e = {
-                                    let l7 = i32::from(*ptr0.add(4).cast::<u8>());
-                                    ErrorCode::_lift(l7 as u8)
+                                    let l8 = i32::from(
+                                        *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                    );
+
+                                    ErrorCode::_lift(l8 as u8)
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result9
                     }
                 }
             }
@@ -2657,250 +4135,309 @@ pub mod wasi {
                     let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "wasi:filesystem/types@0.2.0")]
-                    extern "C" {
+                    unsafe extern "C" {
                         #[link_name = "filesystem-error-code"]
-                        fn wit_import(_: i32, _: *mut u8);
+                        fn wit_import1(_: i32, _: *mut u8);
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import(_: i32, _: *mut u8) {
+                    unsafe extern "C" fn wit_import1(_: i32, _: *mut u8) {
                         unreachable!()
                     }
-                    wit_import((err).handle() as i32, ptr0);
-                    let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                    match l1 {
+                    unsafe { wit_import1((err).handle() as i32, ptr0) };
+                    let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                    let result4 = match l2 {
                         0 => None,
                         1 => {
                             let e = {
-                                let l2 = i32::from(*ptr0.add(1).cast::<u8>());
-                                ErrorCode::_lift(l2 as u8)
+                                let l3 = i32::from(*ptr0.add(1).cast::<u8>());
+
+                                ErrorCode::_lift(l3 as u8)
                             };
                             Some(e)
                         }
                         _ => _rt::invalid_enum_discriminant(),
-                    }
+                    };
+                    result4
                 }
             }
         }
-        #[allow(dead_code, clippy::all)]
+
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
         pub mod preopens {
             #[used]
             #[doc(hidden)]
-            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+
             use super::super::super::_rt;
             pub type Descriptor = super::super::super::wasi::filesystem::types::Descriptor;
             #[allow(unused_unsafe, clippy::all)]
             pub fn get_directories() -> _rt::Vec<(Descriptor, _rt::String)> {
                 unsafe {
-                    #[repr(align(4))]
-                    struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
-                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<u8>; 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit();
+                            2 * ::core::mem::size_of::<*const u8>()],
+                    );
                     let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "wasi:filesystem/preopens@0.2.0")]
-                    extern "C" {
+                    unsafe extern "C" {
                         #[link_name = "get-directories"]
-                        fn wit_import(_: *mut u8);
+                        fn wit_import1(_: *mut u8);
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import(_: *mut u8) {
+                    unsafe extern "C" fn wit_import1(_: *mut u8) {
                         unreachable!()
                     }
-                    wit_import(ptr0);
-                    let l1 = *ptr0.add(0).cast::<*mut u8>();
-                    let l2 = *ptr0.add(4).cast::<usize>();
-                    let base7 = l1;
-                    let len7 = l2;
-                    let mut result7 = _rt::Vec::with_capacity(len7);
-                    for i in 0..len7 {
-                        let base = base7.add(i * 12);
-                        let e7 = {
-                            let l3 = *base.add(0).cast::<i32>();
-                            let l4 = *base.add(4).cast::<*mut u8>();
-                            let l5 = *base.add(8).cast::<usize>();
-                            let len6 = l5;
-                            let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+                    unsafe { wit_import1(ptr0) };
+                    let l2 = *ptr0.add(0).cast::<*mut u8>();
+                    let l3 = *ptr0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base8 = l2;
+                    let len8 = l3;
+                    let mut result8 = _rt::Vec::with_capacity(len8);
+                    for i in 0..len8 {
+                        let base = base8.add(i * (3 * ::core::mem::size_of::<*const u8>()));
+                        let e8 = {
+                            let l4 = *base.add(0).cast::<i32>();
+                            let l5 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l6 = *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len7 = l6;
+                            let bytes7 = _rt::Vec::from_raw_parts(l5.cast(), len7, len7);
+
                             (
-                                super::super::super::wasi::filesystem::types::Descriptor::from_handle(
-                                    l3 as u32,
-                                ),
-                                _rt::string_lift(bytes6),
+                                unsafe {
+                                    super::super::super::wasi::filesystem::types::Descriptor::from_handle(l4 as u32)
+                                },
+                                _rt::string_lift(bytes7),
                             )
                         };
-                        result7.push(e7);
+                        result8.push(e8);
                     }
-                    _rt::cabi_dealloc(base7, len7 * 12, 4);
-                    result7
+                    _rt::cabi_dealloc(
+                        base8,
+                        len8 * (3 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result9 = result8;
+                    result9
                 }
             }
         }
     }
-    #[allow(dead_code)]
     pub mod io {
-        #[allow(dead_code, clippy::all)]
+
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
         pub mod error {
             #[used]
             #[doc(hidden)]
-            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+
             use super::super::super::_rt;
+
             #[derive(Debug)]
             #[repr(transparent)]
             pub struct Error {
                 handle: _rt::Resource<Error>,
             }
+
             impl Error {
                 #[doc(hidden)]
                 pub unsafe fn from_handle(handle: u32) -> Self {
                     Self {
-                        handle: _rt::Resource::from_handle(handle),
+                        handle: unsafe { _rt::Resource::from_handle(handle) },
                     }
                 }
+
                 #[doc(hidden)]
                 pub fn take_handle(&self) -> u32 {
                     _rt::Resource::take_handle(&self.handle)
                 }
+
                 #[doc(hidden)]
                 pub fn handle(&self) -> u32 {
                     _rt::Resource::handle(&self.handle)
                 }
             }
+
             unsafe impl _rt::WasmResource for Error {
                 #[inline]
                 unsafe fn drop(_handle: u32) {
                     #[cfg(not(target_arch = "wasm32"))]
                     unreachable!();
+
                     #[cfg(target_arch = "wasm32")]
                     {
                         #[link(wasm_import_module = "wasi:io/error@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[resource-drop]error"]
                             fn drop(_: u32);
                         }
-                        drop(_handle);
+
+                        unsafe { drop(_handle) };
                     }
                 }
             }
+
             impl Error {
                 #[allow(unused_unsafe, clippy::all)]
                 pub fn to_debug_string(&self) -> _rt::String {
                     unsafe {
-                        #[repr(align(4))]
-                        struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                        #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                        #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                        struct RetArea(
+                            [::core::mem::MaybeUninit<u8>; 2 * ::core::mem::size_of::<*const u8>()],
+                        );
                         let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 8],
+                            [::core::mem::MaybeUninit::uninit();
+                                2 * ::core::mem::size_of::<*const u8>()],
                         );
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/error@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]error.to-debug-string"]
-                            fn wit_import(_: i32, _: *mut u8);
+                            fn wit_import1(_: i32, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = *ptr0.add(0).cast::<*mut u8>();
-                        let l2 = *ptr0.add(4).cast::<usize>();
-                        let len3 = l2;
-                        let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
-                        _rt::string_lift(bytes3)
+                        unsafe { wit_import1((self).handle() as i32, ptr0) };
+                        let l2 = *ptr0.add(0).cast::<*mut u8>();
+                        let l3 = *ptr0
+                            .add(::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let len4 = l3;
+                        let bytes4 = _rt::Vec::from_raw_parts(l2.cast(), len4, len4);
+                        let result5 = _rt::string_lift(bytes4);
+                        result5
                     }
                 }
             }
         }
-        #[allow(dead_code, clippy::all)]
+
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
         pub mod poll {
             #[used]
             #[doc(hidden)]
-            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+
             use super::super::super::_rt;
+
             #[derive(Debug)]
             #[repr(transparent)]
             pub struct Pollable {
                 handle: _rt::Resource<Pollable>,
             }
+
             impl Pollable {
                 #[doc(hidden)]
                 pub unsafe fn from_handle(handle: u32) -> Self {
                     Self {
-                        handle: _rt::Resource::from_handle(handle),
+                        handle: unsafe { _rt::Resource::from_handle(handle) },
                     }
                 }
+
                 #[doc(hidden)]
                 pub fn take_handle(&self) -> u32 {
                     _rt::Resource::take_handle(&self.handle)
                 }
+
                 #[doc(hidden)]
                 pub fn handle(&self) -> u32 {
                     _rt::Resource::handle(&self.handle)
                 }
             }
+
             unsafe impl _rt::WasmResource for Pollable {
                 #[inline]
                 unsafe fn drop(_handle: u32) {
                     #[cfg(not(target_arch = "wasm32"))]
                     unreachable!();
+
                     #[cfg(target_arch = "wasm32")]
                     {
                         #[link(wasm_import_module = "wasi:io/poll@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[resource-drop]pollable"]
                             fn drop(_: u32);
                         }
-                        drop(_handle);
+
+                        unsafe { drop(_handle) };
                     }
                 }
             }
+
             impl Pollable {
                 #[allow(unused_unsafe, clippy::all)]
                 pub fn ready(&self) -> bool {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/poll@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]pollable.ready"]
-                            fn wit_import(_: i32) -> i32;
+                            fn wit_import0(_: i32) -> i32;
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32) -> i32 {
+                        unsafe extern "C" fn wit_import0(_: i32) -> i32 {
                             unreachable!()
                         }
-                        let ret = wit_import((self).handle() as i32);
+                        let ret = unsafe { wit_import0((self).handle() as i32) };
                         _rt::bool_lift(ret as u8)
                     }
                 }
             }
             impl Pollable {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn block(&self) {
+                pub fn block(&self) -> () {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/poll@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]pollable.block"]
-                            fn wit_import(_: i32);
+                            fn wit_import0(_: i32);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32) {
+                        unsafe extern "C" fn wit_import0(_: i32) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32);
+                        unsafe { wit_import0((self).handle() as i32) };
                     }
                 }
             }
             #[allow(unused_unsafe, clippy::all)]
             pub fn poll(in_: &[&Pollable]) -> _rt::Vec<u32> {
                 unsafe {
-                    #[repr(align(4))]
-                    struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
-                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<u8>; 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit();
+                            2 * ::core::mem::size_of::<*const u8>()],
+                    );
                     let vec0 = in_;
                     let len0 = vec0.len();
-                    let layout0 = _rt::alloc::Layout::from_size_align_unchecked(
-                        vec0.len() * 4,
-                        4,
-                    );
+                    let layout0 = _rt::alloc::Layout::from_size_align_unchecked(vec0.len() * 4, 4);
                     let result0 = if layout0.size() != 0 {
                         let ptr = _rt::alloc::alloc(layout0).cast::<u8>();
                         if ptr.is_null() {
@@ -2919,30 +4456,37 @@ pub mod wasi {
                     let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "wasi:io/poll@0.2.0")]
-                    extern "C" {
+                    unsafe extern "C" {
                         #[link_name = "poll"]
-                        fn wit_import(_: *mut u8, _: usize, _: *mut u8);
+                        fn wit_import2(_: *mut u8, _: usize, _: *mut u8);
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import(_: *mut u8, _: usize, _: *mut u8) {
+                    unsafe extern "C" fn wit_import2(_: *mut u8, _: usize, _: *mut u8) {
                         unreachable!()
                     }
-                    wit_import(result0, len0, ptr1);
-                    let l2 = *ptr1.add(0).cast::<*mut u8>();
-                    let l3 = *ptr1.add(4).cast::<usize>();
-                    let len4 = l3;
+                    unsafe { wit_import2(result0, len0, ptr1) };
+                    let l3 = *ptr1.add(0).cast::<*mut u8>();
+                    let l4 = *ptr1
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len5 = l4;
+                    let result6 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
                     if layout0.size() != 0 {
                         _rt::alloc::dealloc(result0.cast(), layout0);
                     }
-                    _rt::Vec::from_raw_parts(l2.cast(), len4, len4)
+                    result6
                 }
             }
         }
-        #[allow(dead_code, clippy::all)]
+
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
         pub mod streams {
             #[used]
             #[doc(hidden)]
-            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+
             use super::super::super::_rt;
             pub type Error = super::super::super::wasi::io::error::Error;
             pub type Pollable = super::super::super::wasi::io::poll::Pollable;
@@ -2951,223 +4495,265 @@ pub mod wasi {
                 Closed,
             }
             impl ::core::fmt::Debug for StreamError {
-                fn fmt(
-                    &self,
-                    f: &mut ::core::fmt::Formatter<'_>,
-                ) -> ::core::fmt::Result {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     match self {
-                        StreamError::LastOperationFailed(e) => {
-                            f.debug_tuple("StreamError::LastOperationFailed")
-                                .field(e)
-                                .finish()
-                        }
-                        StreamError::Closed => {
-                            f.debug_tuple("StreamError::Closed").finish()
-                        }
+                        StreamError::LastOperationFailed(e) => f
+                            .debug_tuple("StreamError::LastOperationFailed")
+                            .field(e)
+                            .finish(),
+                        StreamError::Closed => f.debug_tuple("StreamError::Closed").finish(),
                     }
                 }
             }
             impl ::core::fmt::Display for StreamError {
-                fn fmt(
-                    &self,
-                    f: &mut ::core::fmt::Formatter<'_>,
-                ) -> ::core::fmt::Result {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     write!(f, "{:?}", self)
                 }
             }
+
             impl std::error::Error for StreamError {}
+
             #[derive(Debug)]
             #[repr(transparent)]
             pub struct InputStream {
                 handle: _rt::Resource<InputStream>,
             }
+
             impl InputStream {
                 #[doc(hidden)]
                 pub unsafe fn from_handle(handle: u32) -> Self {
                     Self {
-                        handle: _rt::Resource::from_handle(handle),
+                        handle: unsafe { _rt::Resource::from_handle(handle) },
                     }
                 }
+
                 #[doc(hidden)]
                 pub fn take_handle(&self) -> u32 {
                     _rt::Resource::take_handle(&self.handle)
                 }
+
                 #[doc(hidden)]
                 pub fn handle(&self) -> u32 {
                     _rt::Resource::handle(&self.handle)
                 }
             }
+
             unsafe impl _rt::WasmResource for InputStream {
                 #[inline]
                 unsafe fn drop(_handle: u32) {
                     #[cfg(not(target_arch = "wasm32"))]
                     unreachable!();
+
                     #[cfg(target_arch = "wasm32")]
                     {
                         #[link(wasm_import_module = "wasi:io/streams@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[resource-drop]input-stream"]
                             fn drop(_: u32);
                         }
-                        drop(_handle);
+
+                        unsafe { drop(_handle) };
                     }
                 }
             }
+
             #[derive(Debug)]
             #[repr(transparent)]
             pub struct OutputStream {
                 handle: _rt::Resource<OutputStream>,
             }
+
             impl OutputStream {
                 #[doc(hidden)]
                 pub unsafe fn from_handle(handle: u32) -> Self {
                     Self {
-                        handle: _rt::Resource::from_handle(handle),
+                        handle: unsafe { _rt::Resource::from_handle(handle) },
                     }
                 }
+
                 #[doc(hidden)]
                 pub fn take_handle(&self) -> u32 {
                     _rt::Resource::take_handle(&self.handle)
                 }
+
                 #[doc(hidden)]
                 pub fn handle(&self) -> u32 {
                     _rt::Resource::handle(&self.handle)
                 }
             }
+
             unsafe impl _rt::WasmResource for OutputStream {
                 #[inline]
                 unsafe fn drop(_handle: u32) {
                     #[cfg(not(target_arch = "wasm32"))]
                     unreachable!();
+
                     #[cfg(target_arch = "wasm32")]
                     {
                         #[link(wasm_import_module = "wasi:io/streams@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[resource-drop]output-stream"]
                             fn drop(_: u32);
                         }
-                        drop(_handle);
+
+                        unsafe { drop(_handle) };
                     }
                 }
             }
+
             impl InputStream {
                 #[allow(unused_unsafe, clippy::all)]
                 pub fn read(&self, len: u64) -> Result<_rt::Vec<u8>, StreamError> {
                     unsafe {
-                        #[repr(align(4))]
-                        struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
+                        #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                        #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                        struct RetArea(
+                            [::core::mem::MaybeUninit<u8>; 3 * ::core::mem::size_of::<*const u8>()],
+                        );
                         let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 12],
+                            [::core::mem::MaybeUninit::uninit();
+                                3 * ::core::mem::size_of::<*const u8>()],
                         );
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/streams@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]input-stream.read"]
-                            fn wit_import(_: i32, _: i64, _: *mut u8);
+                            fn wit_import1(_: i32, _: i64, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i64, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: i64, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, _rt::as_i64(&len), ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, _rt::as_i64(&len), ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result9 = match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = *ptr0.add(4).cast::<*mut u8>();
-                                    let l3 = *ptr0.add(8).cast::<usize>();
-                                    let len4 = l3;
-                                    _rt::Vec::from_raw_parts(l2.cast(), len4, len4)
+                                    let l3 = *ptr0
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l4 = *ptr0
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let len5 = l4;
+
+                                    _rt::Vec::from_raw_parts(l3.cast(), len5, len5)
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l5 = i32::from(*ptr0.add(4).cast::<u8>());
-                                    let v7 = match l5 {
+                                    let l6 = i32::from(
+                                        *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                    );
+                                    let v8 = match l6 {
                                         0 => {
-                                            let e7 = {
-                                                let l6 = *ptr0.add(8).cast::<i32>();
-                                                super::super::super::wasi::io::error::Error::from_handle(
-                                                    l6 as u32,
-                                                )
+                                            let e8 = {
+                                                let l7 = *ptr0
+                                                    .add(
+                                                        4 + 1 * ::core::mem::size_of::<*const u8>(),
+                                                    )
+                                                    .cast::<i32>();
+
+                                                unsafe {
+                                                    super::super::super::wasi::io::error::Error::from_handle(l7 as u32)
+                                                }
                                             };
-                                            StreamError::LastOperationFailed(e7)
+                                            StreamError::LastOperationFailed(e8)
                                         }
                                         n => {
                                             debug_assert_eq!(n, 1, "invalid enum discriminant");
                                             StreamError::Closed
                                         }
                                     };
-                                    v7
+
+                                    v8
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result9
                     }
                 }
             }
             impl InputStream {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn blocking_read(
-                    &self,
-                    len: u64,
-                ) -> Result<_rt::Vec<u8>, StreamError> {
+                pub fn blocking_read(&self, len: u64) -> Result<_rt::Vec<u8>, StreamError> {
                     unsafe {
-                        #[repr(align(4))]
-                        struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
+                        #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                        #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                        struct RetArea(
+                            [::core::mem::MaybeUninit<u8>; 3 * ::core::mem::size_of::<*const u8>()],
+                        );
                         let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 12],
+                            [::core::mem::MaybeUninit::uninit();
+                                3 * ::core::mem::size_of::<*const u8>()],
                         );
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/streams@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]input-stream.blocking-read"]
-                            fn wit_import(_: i32, _: i64, _: *mut u8);
+                            fn wit_import1(_: i32, _: i64, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i64, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: i64, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, _rt::as_i64(&len), ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, _rt::as_i64(&len), ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result9 = match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = *ptr0.add(4).cast::<*mut u8>();
-                                    let l3 = *ptr0.add(8).cast::<usize>();
-                                    let len4 = l3;
-                                    _rt::Vec::from_raw_parts(l2.cast(), len4, len4)
+                                    let l3 = *ptr0
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l4 = *ptr0
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let len5 = l4;
+
+                                    _rt::Vec::from_raw_parts(l3.cast(), len5, len5)
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l5 = i32::from(*ptr0.add(4).cast::<u8>());
-                                    let v7 = match l5 {
+                                    let l6 = i32::from(
+                                        *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                    );
+                                    let v8 = match l6 {
                                         0 => {
-                                            let e7 = {
-                                                let l6 = *ptr0.add(8).cast::<i32>();
-                                                super::super::super::wasi::io::error::Error::from_handle(
-                                                    l6 as u32,
-                                                )
+                                            let e8 = {
+                                                let l7 = *ptr0
+                                                    .add(
+                                                        4 + 1 * ::core::mem::size_of::<*const u8>(),
+                                                    )
+                                                    .cast::<i32>();
+
+                                                unsafe {
+                                                    super::super::super::wasi::io::error::Error::from_handle(l7 as u32)
+                                                }
                                             };
-                                            StreamError::LastOperationFailed(e7)
+                                            StreamError::LastOperationFailed(e8)
                                         }
                                         n => {
                                             debug_assert_eq!(n, 1, "invalid enum discriminant");
                                             StreamError::Closed
                                         }
                                     };
-                                    v7
+
+                                    v8
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result9
                     }
                 }
             }
@@ -3177,54 +4763,57 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(8))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 16]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 16],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 16]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/streams@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]input-stream.skip"]
-                            fn wit_import(_: i32, _: i64, _: *mut u8);
+                            fn wit_import1(_: i32, _: i64, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i64, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: i64, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, _rt::as_i64(&len), ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, _rt::as_i64(&len), ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result7 = match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = *ptr0.add(8).cast::<i64>();
-                                    l2 as u64
+                                    let l3 = *ptr0.add(8).cast::<i64>();
+
+                                    l3 as u64
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l3 = i32::from(*ptr0.add(8).cast::<u8>());
-                                    let v5 = match l3 {
+                                    let l4 = i32::from(*ptr0.add(8).cast::<u8>());
+                                    let v6 = match l4 {
                                         0 => {
-                                            let e5 = {
-                                                let l4 = *ptr0.add(12).cast::<i32>();
-                                                super::super::super::wasi::io::error::Error::from_handle(
-                                                    l4 as u32,
-                                                )
+                                            let e6 = {
+                                                let l5 = *ptr0.add(12).cast::<i32>();
+
+                                                unsafe {
+                                                    super::super::super::wasi::io::error::Error::from_handle(l5 as u32)
+                                                }
                                             };
-                                            StreamError::LastOperationFailed(e5)
+                                            StreamError::LastOperationFailed(e6)
                                         }
                                         n => {
                                             debug_assert_eq!(n, 1, "invalid enum discriminant");
                                             StreamError::Closed
                                         }
                                     };
-                                    v5
+
+                                    v6
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result7
                     }
                 }
             }
@@ -3234,54 +4823,57 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(8))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 16]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 16],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 16]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/streams@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]input-stream.blocking-skip"]
-                            fn wit_import(_: i32, _: i64, _: *mut u8);
+                            fn wit_import1(_: i32, _: i64, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i64, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: i64, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, _rt::as_i64(&len), ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, _rt::as_i64(&len), ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result7 = match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = *ptr0.add(8).cast::<i64>();
-                                    l2 as u64
+                                    let l3 = *ptr0.add(8).cast::<i64>();
+
+                                    l3 as u64
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l3 = i32::from(*ptr0.add(8).cast::<u8>());
-                                    let v5 = match l3 {
+                                    let l4 = i32::from(*ptr0.add(8).cast::<u8>());
+                                    let v6 = match l4 {
                                         0 => {
-                                            let e5 = {
-                                                let l4 = *ptr0.add(12).cast::<i32>();
-                                                super::super::super::wasi::io::error::Error::from_handle(
-                                                    l4 as u32,
-                                                )
+                                            let e6 = {
+                                                let l5 = *ptr0.add(12).cast::<i32>();
+
+                                                unsafe {
+                                                    super::super::super::wasi::io::error::Error::from_handle(l5 as u32)
+                                                }
                                             };
-                                            StreamError::LastOperationFailed(e5)
+                                            StreamError::LastOperationFailed(e6)
                                         }
                                         n => {
                                             debug_assert_eq!(n, 1, "invalid enum discriminant");
                                             StreamError::Closed
                                         }
                                     };
-                                    v5
+
+                                    v6
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result7
                     }
                 }
             }
@@ -3291,18 +4883,19 @@ pub mod wasi {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/streams@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]input-stream.subscribe"]
-                            fn wit_import(_: i32) -> i32;
+                            fn wit_import0(_: i32) -> i32;
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32) -> i32 {
+                        unsafe extern "C" fn wit_import0(_: i32) -> i32 {
                             unreachable!()
                         }
-                        let ret = wit_import((self).handle() as i32);
-                        super::super::super::wasi::io::poll::Pollable::from_handle(
-                            ret as u32,
-                        )
+                        let ret = unsafe { wit_import0((self).handle() as i32) };
+                        unsafe {
+                            super::super::super::wasi::io::poll::Pollable::from_handle(ret as u32)
+                        }
                     }
                 }
             }
@@ -3312,54 +4905,57 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(8))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 16]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 16],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 16]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/streams@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]output-stream.check-write"]
-                            fn wit_import(_: i32, _: *mut u8);
+                            fn wit_import1(_: i32, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result7 = match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = *ptr0.add(8).cast::<i64>();
-                                    l2 as u64
+                                    let l3 = *ptr0.add(8).cast::<i64>();
+
+                                    l3 as u64
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l3 = i32::from(*ptr0.add(8).cast::<u8>());
-                                    let v5 = match l3 {
+                                    let l4 = i32::from(*ptr0.add(8).cast::<u8>());
+                                    let v6 = match l4 {
                                         0 => {
-                                            let e5 = {
-                                                let l4 = *ptr0.add(12).cast::<i32>();
-                                                super::super::super::wasi::io::error::Error::from_handle(
-                                                    l4 as u32,
-                                                )
+                                            let e6 = {
+                                                let l5 = *ptr0.add(12).cast::<i32>();
+
+                                                unsafe {
+                                                    super::super::super::wasi::io::error::Error::from_handle(l5 as u32)
+                                                }
                                             };
-                                            StreamError::LastOperationFailed(e5)
+                                            StreamError::LastOperationFailed(e6)
                                         }
                                         n => {
                                             debug_assert_eq!(n, 1, "invalid enum discriminant");
                                             StreamError::Closed
                                         }
                                     };
-                                    v5
+
+                                    v6
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result7
                     }
                 }
             }
@@ -3369,114 +4965,115 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(4))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 12],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
                         let vec0 = contents;
                         let ptr0 = vec0.as_ptr().cast::<u8>();
                         let len0 = vec0.len();
                         let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/streams@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]output-stream.write"]
-                            fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8);
+                            fn wit_import2(_: i32, _: *mut u8, _: usize, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8) {
+                        unsafe extern "C" fn wit_import2(_: i32, _: *mut u8, _: usize, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0.cast_mut(), len0, ptr1);
-                        let l2 = i32::from(*ptr1.add(0).cast::<u8>());
-                        match l2 {
+                        unsafe { wit_import2((self).handle() as i32, ptr0.cast_mut(), len0, ptr1) };
+                        let l3 = i32::from(*ptr1.add(0).cast::<u8>());
+                        let result7 = match l3 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l3 = i32::from(*ptr1.add(4).cast::<u8>());
-                                    let v5 = match l3 {
+                                    let l4 = i32::from(*ptr1.add(4).cast::<u8>());
+                                    let v6 = match l4 {
                                         0 => {
-                                            let e5 = {
-                                                let l4 = *ptr1.add(8).cast::<i32>();
-                                                super::super::super::wasi::io::error::Error::from_handle(
-                                                    l4 as u32,
-                                                )
+                                            let e6 = {
+                                                let l5 = *ptr1.add(8).cast::<i32>();
+
+                                                unsafe {
+                                                    super::super::super::wasi::io::error::Error::from_handle(l5 as u32)
+                                                }
                                             };
-                                            StreamError::LastOperationFailed(e5)
+                                            StreamError::LastOperationFailed(e6)
                                         }
                                         n => {
                                             debug_assert_eq!(n, 1, "invalid enum discriminant");
                                             StreamError::Closed
                                         }
                                     };
-                                    v5
+
+                                    v6
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result7
                     }
                 }
             }
             impl OutputStream {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn blocking_write_and_flush(
-                    &self,
-                    contents: &[u8],
-                ) -> Result<(), StreamError> {
+                pub fn blocking_write_and_flush(&self, contents: &[u8]) -> Result<(), StreamError> {
                     unsafe {
                         #[repr(align(4))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 12],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
                         let vec0 = contents;
                         let ptr0 = vec0.as_ptr().cast::<u8>();
                         let len0 = vec0.len();
                         let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/streams@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]output-stream.blocking-write-and-flush"]
-                            fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8);
+                            fn wit_import2(_: i32, _: *mut u8, _: usize, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8) {
+                        unsafe extern "C" fn wit_import2(_: i32, _: *mut u8, _: usize, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0.cast_mut(), len0, ptr1);
-                        let l2 = i32::from(*ptr1.add(0).cast::<u8>());
-                        match l2 {
+                        unsafe { wit_import2((self).handle() as i32, ptr0.cast_mut(), len0, ptr1) };
+                        let l3 = i32::from(*ptr1.add(0).cast::<u8>());
+                        let result7 = match l3 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l3 = i32::from(*ptr1.add(4).cast::<u8>());
-                                    let v5 = match l3 {
+                                    let l4 = i32::from(*ptr1.add(4).cast::<u8>());
+                                    let v6 = match l4 {
                                         0 => {
-                                            let e5 = {
-                                                let l4 = *ptr1.add(8).cast::<i32>();
-                                                super::super::super::wasi::io::error::Error::from_handle(
-                                                    l4 as u32,
-                                                )
+                                            let e6 = {
+                                                let l5 = *ptr1.add(8).cast::<i32>();
+
+                                                unsafe {
+                                                    super::super::super::wasi::io::error::Error::from_handle(l5 as u32)
+                                                }
                                             };
-                                            StreamError::LastOperationFailed(e5)
+                                            StreamError::LastOperationFailed(e6)
                                         }
                                         n => {
                                             debug_assert_eq!(n, 1, "invalid enum discriminant");
                                             StreamError::Closed
                                         }
                                     };
-                                    v5
+
+                                    v6
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result7
                     }
                 }
             }
@@ -3486,51 +5083,53 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(4))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 12],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/streams@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]output-stream.flush"]
-                            fn wit_import(_: i32, _: *mut u8);
+                            fn wit_import1(_: i32, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result6 = match l2 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l2 = i32::from(*ptr0.add(4).cast::<u8>());
-                                    let v4 = match l2 {
+                                    let l3 = i32::from(*ptr0.add(4).cast::<u8>());
+                                    let v5 = match l3 {
                                         0 => {
-                                            let e4 = {
-                                                let l3 = *ptr0.add(8).cast::<i32>();
-                                                super::super::super::wasi::io::error::Error::from_handle(
-                                                    l3 as u32,
-                                                )
+                                            let e5 = {
+                                                let l4 = *ptr0.add(8).cast::<i32>();
+
+                                                unsafe {
+                                                    super::super::super::wasi::io::error::Error::from_handle(l4 as u32)
+                                                }
                                             };
-                                            StreamError::LastOperationFailed(e4)
+                                            StreamError::LastOperationFailed(e5)
                                         }
                                         n => {
                                             debug_assert_eq!(n, 1, "invalid enum discriminant");
                                             StreamError::Closed
                                         }
                                     };
-                                    v4
+
+                                    v5
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result6
                     }
                 }
             }
@@ -3540,51 +5139,53 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(4))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 12],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/streams@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]output-stream.blocking-flush"]
-                            fn wit_import(_: i32, _: *mut u8);
+                            fn wit_import1(_: i32, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result6 = match l2 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l2 = i32::from(*ptr0.add(4).cast::<u8>());
-                                    let v4 = match l2 {
+                                    let l3 = i32::from(*ptr0.add(4).cast::<u8>());
+                                    let v5 = match l3 {
                                         0 => {
-                                            let e4 = {
-                                                let l3 = *ptr0.add(8).cast::<i32>();
-                                                super::super::super::wasi::io::error::Error::from_handle(
-                                                    l3 as u32,
-                                                )
+                                            let e5 = {
+                                                let l4 = *ptr0.add(8).cast::<i32>();
+
+                                                unsafe {
+                                                    super::super::super::wasi::io::error::Error::from_handle(l4 as u32)
+                                                }
                                             };
-                                            StreamError::LastOperationFailed(e4)
+                                            StreamError::LastOperationFailed(e5)
                                         }
                                         n => {
                                             debug_assert_eq!(n, 1, "invalid enum discriminant");
                                             StreamError::Closed
                                         }
                                     };
-                                    v4
+
+                                    v5
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result6
                     }
                 }
             }
@@ -3594,18 +5195,19 @@ pub mod wasi {
                     unsafe {
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/streams@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]output-stream.subscribe"]
-                            fn wit_import(_: i32) -> i32;
+                            fn wit_import0(_: i32) -> i32;
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32) -> i32 {
+                        unsafe extern "C" fn wit_import0(_: i32) -> i32 {
                             unreachable!()
                         }
-                        let ret = wit_import((self).handle() as i32);
-                        super::super::super::wasi::io::poll::Pollable::from_handle(
-                            ret as u32,
-                        )
+                        let ret = unsafe { wit_import0((self).handle() as i32) };
+                        unsafe {
+                            super::super::super::wasi::io::poll::Pollable::from_handle(ret as u32)
+                        }
                     }
                 }
             }
@@ -3615,174 +5217,176 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(4))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 12],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/streams@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]output-stream.write-zeroes"]
-                            fn wit_import(_: i32, _: i64, _: *mut u8);
+                            fn wit_import1(_: i32, _: i64, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i64, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: i64, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, _rt::as_i64(&len), ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, _rt::as_i64(&len), ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result6 = match l2 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l2 = i32::from(*ptr0.add(4).cast::<u8>());
-                                    let v4 = match l2 {
+                                    let l3 = i32::from(*ptr0.add(4).cast::<u8>());
+                                    let v5 = match l3 {
                                         0 => {
-                                            let e4 = {
-                                                let l3 = *ptr0.add(8).cast::<i32>();
-                                                super::super::super::wasi::io::error::Error::from_handle(
-                                                    l3 as u32,
-                                                )
+                                            let e5 = {
+                                                let l4 = *ptr0.add(8).cast::<i32>();
+
+                                                unsafe {
+                                                    super::super::super::wasi::io::error::Error::from_handle(l4 as u32)
+                                                }
                                             };
-                                            StreamError::LastOperationFailed(e4)
+                                            StreamError::LastOperationFailed(e5)
                                         }
                                         n => {
                                             debug_assert_eq!(n, 1, "invalid enum discriminant");
                                             StreamError::Closed
                                         }
                                     };
-                                    v4
+
+                                    v5
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result6
                     }
                 }
             }
             impl OutputStream {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn blocking_write_zeroes_and_flush(
-                    &self,
-                    len: u64,
-                ) -> Result<(), StreamError> {
+                pub fn blocking_write_zeroes_and_flush(&self, len: u64) -> Result<(), StreamError> {
                     unsafe {
                         #[repr(align(4))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 12],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/streams@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]output-stream.blocking-write-zeroes-and-flush"]
-                            fn wit_import(_: i32, _: i64, _: *mut u8);
+                            fn wit_import1(_: i32, _: i64, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i64, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: i64, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, _rt::as_i64(&len), ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe { wit_import1((self).handle() as i32, _rt::as_i64(&len), ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result6 = match l2 {
                             0 => {
                                 let e = ();
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l2 = i32::from(*ptr0.add(4).cast::<u8>());
-                                    let v4 = match l2 {
+                                    let l3 = i32::from(*ptr0.add(4).cast::<u8>());
+                                    let v5 = match l3 {
                                         0 => {
-                                            let e4 = {
-                                                let l3 = *ptr0.add(8).cast::<i32>();
-                                                super::super::super::wasi::io::error::Error::from_handle(
-                                                    l3 as u32,
-                                                )
+                                            let e5 = {
+                                                let l4 = *ptr0.add(8).cast::<i32>();
+
+                                                unsafe {
+                                                    super::super::super::wasi::io::error::Error::from_handle(l4 as u32)
+                                                }
                                             };
-                                            StreamError::LastOperationFailed(e4)
+                                            StreamError::LastOperationFailed(e5)
                                         }
                                         n => {
                                             debug_assert_eq!(n, 1, "invalid enum discriminant");
                                             StreamError::Closed
                                         }
                                     };
-                                    v4
+
+                                    v5
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result6
                     }
                 }
             }
             impl OutputStream {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn splice(
-                    &self,
-                    src: &InputStream,
-                    len: u64,
-                ) -> Result<u64, StreamError> {
+                pub fn splice(&self, src: &InputStream, len: u64) -> Result<u64, StreamError> {
                     unsafe {
                         #[repr(align(8))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 16]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 16],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 16]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/streams@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]output-stream.splice"]
-                            fn wit_import(_: i32, _: i32, _: i64, _: *mut u8);
+                            fn wit_import1(_: i32, _: i32, _: i64, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i32, _: i64, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: i32, _: i64, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import(
-                            (self).handle() as i32,
-                            (src).handle() as i32,
-                            _rt::as_i64(&len),
-                            ptr0,
-                        );
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe {
+                            wit_import1(
+                                (self).handle() as i32,
+                                (src).handle() as i32,
+                                _rt::as_i64(&len),
+                                ptr0,
+                            )
+                        };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result7 = match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = *ptr0.add(8).cast::<i64>();
-                                    l2 as u64
+                                    let l3 = *ptr0.add(8).cast::<i64>();
+
+                                    l3 as u64
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l3 = i32::from(*ptr0.add(8).cast::<u8>());
-                                    let v5 = match l3 {
+                                    let l4 = i32::from(*ptr0.add(8).cast::<u8>());
+                                    let v6 = match l4 {
                                         0 => {
-                                            let e5 = {
-                                                let l4 = *ptr0.add(12).cast::<i32>();
-                                                super::super::super::wasi::io::error::Error::from_handle(
-                                                    l4 as u32,
-                                                )
+                                            let e6 = {
+                                                let l5 = *ptr0.add(12).cast::<i32>();
+
+                                                unsafe {
+                                                    super::super::super::wasi::io::error::Error::from_handle(l5 as u32)
+                                                }
                                             };
-                                            StreamError::LastOperationFailed(e5)
+                                            StreamError::LastOperationFailed(e6)
                                         }
                                         n => {
                                             debug_assert_eq!(n, 1, "invalid enum discriminant");
                                             StreamError::Closed
                                         }
                                     };
-                                    v5
+
+                                    v6
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result7
                     }
                 }
             }
@@ -3796,94 +5400,111 @@ pub mod wasi {
                     unsafe {
                         #[repr(align(8))]
                         struct RetArea([::core::mem::MaybeUninit<u8>; 16]);
-                        let mut ret_area = RetArea(
-                            [::core::mem::MaybeUninit::uninit(); 16],
-                        );
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 16]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "wasi:io/streams@0.2.0")]
-                        extern "C" {
+                        unsafe extern "C" {
                             #[link_name = "[method]output-stream.blocking-splice"]
-                            fn wit_import(_: i32, _: i32, _: i64, _: *mut u8);
+                            fn wit_import1(_: i32, _: i32, _: i64, _: *mut u8);
                         }
+
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: i32, _: i64, _: *mut u8) {
+                        unsafe extern "C" fn wit_import1(_: i32, _: i32, _: i64, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import(
-                            (self).handle() as i32,
-                            (src).handle() as i32,
-                            _rt::as_i64(&len),
-                            ptr0,
-                        );
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        unsafe {
+                            wit_import1(
+                                (self).handle() as i32,
+                                (src).handle() as i32,
+                                _rt::as_i64(&len),
+                                ptr0,
+                            )
+                        };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result7 = match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = *ptr0.add(8).cast::<i64>();
-                                    l2 as u64
+                                    let l3 = *ptr0.add(8).cast::<i64>();
+
+                                    l3 as u64
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l3 = i32::from(*ptr0.add(8).cast::<u8>());
-                                    let v5 = match l3 {
+                                    let l4 = i32::from(*ptr0.add(8).cast::<u8>());
+                                    let v6 = match l4 {
                                         0 => {
-                                            let e5 = {
-                                                let l4 = *ptr0.add(12).cast::<i32>();
-                                                super::super::super::wasi::io::error::Error::from_handle(
-                                                    l4 as u32,
-                                                )
+                                            let e6 = {
+                                                let l5 = *ptr0.add(12).cast::<i32>();
+
+                                                unsafe {
+                                                    super::super::super::wasi::io::error::Error::from_handle(l5 as u32)
+                                                }
                                             };
-                                            StreamError::LastOperationFailed(e5)
+                                            StreamError::LastOperationFailed(e6)
                                         }
                                         n => {
                                             debug_assert_eq!(n, 1, "invalid enum discriminant");
                                             StreamError::Closed
                                         }
                                     };
-                                    v5
+
+                                    v6
                                 };
                                 Err(e)
                             }
                             _ => _rt::invalid_enum_discriminant(),
-                        }
+                        };
+                        result7
                     }
                 }
             }
         }
     }
-    #[allow(dead_code)]
     pub mod random {
-        #[allow(dead_code, clippy::all)]
+
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
         pub mod random {
             #[used]
             #[doc(hidden)]
-            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+
             use super::super::super::_rt;
             #[allow(unused_unsafe, clippy::all)]
             pub fn get_random_bytes(len: u64) -> _rt::Vec<u8> {
                 unsafe {
-                    #[repr(align(4))]
-                    struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
-                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<u8>; 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit();
+                            2 * ::core::mem::size_of::<*const u8>()],
+                    );
                     let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "wasi:random/random@0.2.0")]
-                    extern "C" {
+                    unsafe extern "C" {
                         #[link_name = "get-random-bytes"]
-                        fn wit_import(_: i64, _: *mut u8);
+                        fn wit_import1(_: i64, _: *mut u8);
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import(_: i64, _: *mut u8) {
+                    unsafe extern "C" fn wit_import1(_: i64, _: *mut u8) {
                         unreachable!()
                     }
-                    wit_import(_rt::as_i64(&len), ptr0);
-                    let l1 = *ptr0.add(0).cast::<*mut u8>();
-                    let l2 = *ptr0.add(4).cast::<usize>();
-                    let len3 = l2;
-                    _rt::Vec::from_raw_parts(l1.cast(), len3, len3)
+                    unsafe { wit_import1(_rt::as_i64(&len), ptr0) };
+                    let l2 = *ptr0.add(0).cast::<*mut u8>();
+                    let l3 = *ptr0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len4 = l3;
+                    let result5 = _rt::Vec::from_raw_parts(l2.cast(), len4, len4);
+                    result5
                 }
             }
             #[allow(unused_unsafe, clippy::all)]
@@ -3891,15 +5512,16 @@ pub mod wasi {
                 unsafe {
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "wasi:random/random@0.2.0")]
-                    extern "C" {
+                    unsafe extern "C" {
                         #[link_name = "get-random-u64"]
-                        fn wit_import() -> i64;
+                        fn wit_import0() -> i64;
                     }
+
                     #[cfg(not(target_arch = "wasm32"))]
-                    fn wit_import() -> i64 {
+                    unsafe extern "C" fn wit_import0() -> i64 {
                         unreachable!()
                     }
-                    let ret = wit_import();
+                    let ret = unsafe { wit_import0() };
                     ret as u64
                 }
             }
@@ -3907,9 +5529,12 @@ pub mod wasi {
     }
 }
 mod _rt {
+    #![allow(dead_code, clippy::all)]
+
     use core::fmt;
     use core::marker;
     use core::sync::atomic::{AtomicU32, Ordering::Relaxed};
+
     /// A type which represents a component model resource, either imported or
     /// exported into this component.
     ///
@@ -3924,9 +5549,16 @@ mod _rt {
     /// resources.
     #[repr(transparent)]
     pub struct Resource<T: WasmResource> {
+        // NB: This would ideally be `u32` but it is not. The fact that this has
+        // interior mutability is not exposed in the API of this type except for the
+        // `take_handle` method which is supposed to in theory be private.
+        //
+        // This represents, almost all the time, a valid handle value. When it's
+        // invalid it's stored as `u32::MAX`.
         handle: AtomicU32,
         _marker: marker::PhantomData<T>,
     }
+
     /// A trait which all wasm resources implement, namely providing the ability to
     /// drop a resource.
     ///
@@ -3936,6 +5568,7 @@ mod _rt {
         /// Invokes the `[resource-drop]...` intrinsic.
         unsafe fn drop(handle: u32);
     }
+
     impl<T: WasmResource> Resource<T> {
         #[doc(hidden)]
         pub unsafe fn from_handle(handle: u32) -> Self {
@@ -3945,6 +5578,7 @@ mod _rt {
                 _marker: marker::PhantomData,
             }
         }
+
         /// Takes ownership of the handle owned by `resource`.
         ///
         /// Note that this ideally would be `into_handle` taking `Resource<T>` by
@@ -3961,21 +5595,31 @@ mod _rt {
         pub fn take_handle(resource: &Resource<T>) -> u32 {
             resource.handle.swap(u32::MAX, Relaxed)
         }
+
         #[doc(hidden)]
         pub fn handle(resource: &Resource<T>) -> u32 {
             resource.handle.load(Relaxed)
         }
     }
+
     impl<T: WasmResource> fmt::Debug for Resource<T> {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            f.debug_struct("Resource").field("handle", &self.handle).finish()
+            f.debug_struct("Resource")
+                .field("handle", &self.handle)
+                .finish()
         }
     }
+
     impl<T: WasmResource> Drop for Resource<T> {
         fn drop(&mut self) {
             unsafe {
                 match self.handle.load(Relaxed) {
+                    // If this handle was "taken" then don't do anything in the
+                    // destructor.
                     u32::MAX => {}
+
+                    // ... but otherwise do actually destroy it with the imported
+                    // component model intrinsic as defined through `T`.
                     other => T::drop(other),
                 }
             }
@@ -4002,23 +5646,28 @@ mod _rt {
         }
     }
     pub use alloc_crate::alloc;
+
     pub fn as_i64<T: AsI64>(t: T) -> i64 {
         t.as_i64()
     }
+
     pub trait AsI64 {
         fn as_i64(self) -> i64;
     }
+
     impl<'a, T: Copy + AsI64> AsI64 for &'a T {
         fn as_i64(self) -> i64 {
             (*self).as_i64()
         }
     }
+
     impl AsI64 for i64 {
         #[inline]
         fn as_i64(self) -> i64 {
             self as i64
         }
     }
+
     impl AsI64 for u64 {
         #[inline]
         fn as_i64(self) -> i64 {
@@ -4029,7 +5678,7 @@ mod _rt {
         if cfg!(debug_assertions) {
             panic!("invalid enum discriminant")
         } else {
-            core::hint::unreachable_unchecked()
+            unsafe { core::hint::unreachable_unchecked() }
         }
     }
     pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
@@ -4039,73 +5688,86 @@ mod _rt {
         let layout = alloc::Layout::from_size_align_unchecked(size, align);
         alloc::dealloc(ptr, layout);
     }
+
     pub fn as_i32<T: AsI32>(t: T) -> i32 {
         t.as_i32()
     }
+
     pub trait AsI32 {
         fn as_i32(self) -> i32;
     }
+
     impl<'a, T: Copy + AsI32> AsI32 for &'a T {
         fn as_i32(self) -> i32 {
             (*self).as_i32()
         }
     }
+
     impl AsI32 for i32 {
         #[inline]
         fn as_i32(self) -> i32 {
             self as i32
         }
     }
+
     impl AsI32 for u32 {
         #[inline]
         fn as_i32(self) -> i32 {
             self as i32
         }
     }
+
     impl AsI32 for i16 {
         #[inline]
         fn as_i32(self) -> i32 {
             self as i32
         }
     }
+
     impl AsI32 for u16 {
         #[inline]
         fn as_i32(self) -> i32 {
             self as i32
         }
     }
+
     impl AsI32 for i8 {
         #[inline]
         fn as_i32(self) -> i32 {
             self as i32
         }
     }
+
     impl AsI32 for u8 {
         #[inline]
         fn as_i32(self) -> i32 {
             self as i32
         }
     }
+
     impl AsI32 for char {
         #[inline]
         fn as_i32(self) -> i32 {
             self as i32
         }
     }
+
     impl AsI32 for usize {
         #[inline]
         fn as_i32(self) -> i32 {
             self as i32
         }
     }
+
     #[cfg(target_arch = "wasm32")]
     pub fn run_ctors_once() {
         wit_bindgen_rt::run_ctors_once();
     }
     extern crate alloc as alloc_crate;
 }
-/// Generates `#[no_mangle]` functions to export the specified type as the
-/// root implementation of all generated traits.
+
+/// Generates `#[unsafe(no_mangle)]` functions to export the specified type as
+/// the root implementation of all generated traits.
 ///
 /// For more information see the documentation of `wit_bindgen::generate!`.
 ///
@@ -4122,132 +5784,148 @@ mod _rt {
 /// ```
 #[allow(unused_macros)]
 #[doc(hidden)]
+
 macro_rules! __export_reconciler_impl {
-    ($ty:ident) => {
-        self::export!($ty with_types_in self);
-    };
-    ($ty:ident with_types_in $($path_to_types_root:tt)*) => {
-        $($path_to_types_root)*:: __export_world_reconciler_cabi!($ty with_types_in
-        $($path_to_types_root)*);
-    };
+  ($ty:ident) => (self::export!($ty with_types_in self););
+  ($ty:ident with_types_in $($path_to_types_root:tt)*) => (
+  $($path_to_types_root)*::__export_world_reconciler_cabi!($ty with_types_in $($path_to_types_root)*);
+  )
 }
 #[doc(inline)]
 pub(crate) use __export_reconciler_impl as export;
+
 #[cfg(target_arch = "wasm32")]
-#[link_section = "component-type:wit-bindgen:0.35.0:example:reconciler@0.1.0:reconciler:encoded world"]
+#[unsafe(link_section = "component-type:wit-bindgen:0.41.0:example:reconciler@0.2.0:reconciler:encoded world")]
 #[doc(hidden)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 5502] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xfd)\x01A\x02\x01A(\x01\
-r\x03\x07requeue\x7f\x0drequeue-aftery\x06objects\x03\0\x10reconcile-result\x03\0\
-\0\x01r\x02\x04codey\x07messages\x03\0\x0freconcile-error\x03\0\x02\x01B\x04\x04\
-\0\x05error\x03\x01\x01h\0\x01@\x01\x04self\x01\0s\x04\0\x1d[method]error.to-deb\
-ug-string\x01\x02\x03\0\x13wasi:io/error@0.2.0\x05\x04\x01B\x0a\x04\0\x08pollabl\
-e\x03\x01\x01h\0\x01@\x01\x04self\x01\0\x7f\x04\0\x16[method]pollable.ready\x01\x02\
-\x01@\x01\x04self\x01\x01\0\x04\0\x16[method]pollable.block\x01\x03\x01p\x01\x01\
-py\x01@\x01\x02in\x04\0\x05\x04\0\x04poll\x01\x06\x03\0\x12wasi:io/poll@0.2.0\x05\
-\x05\x02\x03\0\0\x05error\x02\x03\0\x01\x08pollable\x01B(\x02\x03\x02\x01\x06\x04\
-\0\x05error\x03\0\0\x02\x03\x02\x01\x07\x04\0\x08pollable\x03\0\x02\x01i\x01\x01\
-q\x02\x15last-operation-failed\x01\x04\0\x06closed\0\0\x04\0\x0cstream-error\x03\
-\0\x05\x04\0\x0cinput-stream\x03\x01\x04\0\x0doutput-stream\x03\x01\x01h\x07\x01\
-p}\x01j\x01\x0a\x01\x06\x01@\x02\x04self\x09\x03lenw\0\x0b\x04\0\x19[method]inpu\
-t-stream.read\x01\x0c\x04\0\"[method]input-stream.blocking-read\x01\x0c\x01j\x01\
-w\x01\x06\x01@\x02\x04self\x09\x03lenw\0\x0d\x04\0\x19[method]input-stream.skip\x01\
-\x0e\x04\0\"[method]input-stream.blocking-skip\x01\x0e\x01i\x03\x01@\x01\x04self\
-\x09\0\x0f\x04\0\x1e[method]input-stream.subscribe\x01\x10\x01h\x08\x01@\x01\x04\
-self\x11\0\x0d\x04\0![method]output-stream.check-write\x01\x12\x01j\0\x01\x06\x01\
-@\x02\x04self\x11\x08contents\x0a\0\x13\x04\0\x1b[method]output-stream.write\x01\
-\x14\x04\0.[method]output-stream.blocking-write-and-flush\x01\x14\x01@\x01\x04se\
-lf\x11\0\x13\x04\0\x1b[method]output-stream.flush\x01\x15\x04\0$[method]output-s\
-tream.blocking-flush\x01\x15\x01@\x01\x04self\x11\0\x0f\x04\0\x1f[method]output-\
-stream.subscribe\x01\x16\x01@\x02\x04self\x11\x03lenw\0\x13\x04\0\"[method]outpu\
-t-stream.write-zeroes\x01\x17\x04\05[method]output-stream.blocking-write-zeroes-\
-and-flush\x01\x17\x01@\x03\x04self\x11\x03src\x09\x03lenw\0\x0d\x04\0\x1c[method\
-]output-stream.splice\x01\x18\x04\0%[method]output-stream.blocking-splice\x01\x18\
-\x03\0\x15wasi:io/streams@0.2.0\x05\x08\x02\x03\0\x02\x0doutput-stream\x01B\x05\x02\
-\x03\x02\x01\x09\x04\0\x0doutput-stream\x03\0\0\x01i\x01\x01@\0\0\x02\x04\0\x0ag\
-et-stdout\x01\x03\x03\0\x15wasi:cli/stdout@0.2.0\x05\x0a\x01B\x05\x01p}\x01@\x01\
-\x03lenw\0\0\x04\0\x10get-random-bytes\x01\x01\x01@\0\0w\x04\0\x0eget-random-u64\
-\x01\x02\x03\0\x18wasi:random/random@0.2.0\x05\x0b\x01B\x0a\x01o\x02ss\x01p\0\x01\
-@\0\0\x01\x04\0\x0fget-environment\x01\x02\x01ps\x01@\0\0\x03\x04\0\x0dget-argum\
-ents\x01\x04\x01ks\x01@\0\0\x05\x04\0\x0binitial-cwd\x01\x06\x03\0\x1awasi:cli/e\
-nvironment@0.2.0\x05\x0c\x01B\x05\x02\x03\x02\x01\x09\x04\0\x0doutput-stream\x03\
-\0\0\x01i\x01\x01@\0\0\x02\x04\0\x0aget-stderr\x01\x03\x03\0\x15wasi:cli/stderr@\
-0.2.0\x05\x0d\x02\x03\0\x02\x0cinput-stream\x01B\x05\x02\x03\x02\x01\x0e\x04\0\x0c\
-input-stream\x03\0\0\x01i\x01\x01@\0\0\x02\x04\0\x09get-stdin\x01\x03\x03\0\x14w\
-asi:cli/stdin@0.2.0\x05\x0f\x01B\x0f\x02\x03\x02\x01\x07\x04\0\x08pollable\x03\0\
-\0\x01w\x04\0\x07instant\x03\0\x02\x01w\x04\0\x08duration\x03\0\x04\x01@\0\0\x03\
-\x04\0\x03now\x01\x06\x01@\0\0\x05\x04\0\x0aresolution\x01\x07\x01i\x01\x01@\x01\
-\x04when\x03\0\x08\x04\0\x11subscribe-instant\x01\x09\x01@\x01\x04when\x05\0\x08\
-\x04\0\x12subscribe-duration\x01\x0a\x03\0!wasi:clocks/monotonic-clock@0.2.0\x05\
-\x10\x01B\x05\x01r\x02\x07secondsw\x0bnanosecondsy\x04\0\x08datetime\x03\0\0\x01\
-@\0\0\x01\x04\0\x03now\x01\x02\x04\0\x0aresolution\x01\x02\x03\0\x1cwasi:clocks/\
-wall-clock@0.2.0\x05\x11\x02\x03\0\x02\x05error\x02\x03\0\x09\x08datetime\x01Br\x02\
-\x03\x02\x01\x0e\x04\0\x0cinput-stream\x03\0\0\x02\x03\x02\x01\x09\x04\0\x0doutp\
-ut-stream\x03\0\x02\x02\x03\x02\x01\x12\x04\0\x05error\x03\0\x04\x02\x03\x02\x01\
-\x13\x04\0\x08datetime\x03\0\x06\x01w\x04\0\x08filesize\x03\0\x08\x01m\x08\x07un\
-known\x0cblock-device\x10character-device\x09directory\x04fifo\x0dsymbolic-link\x0c\
-regular-file\x06socket\x04\0\x0fdescriptor-type\x03\0\x0a\x01n\x06\x04read\x05wr\
-ite\x13file-integrity-sync\x13data-integrity-sync\x14requested-write-sync\x10mut\
-ate-directory\x04\0\x10descriptor-flags\x03\0\x0c\x01n\x01\x0esymlink-follow\x04\
-\0\x0apath-flags\x03\0\x0e\x01n\x04\x06create\x09directory\x09exclusive\x08trunc\
-ate\x04\0\x0aopen-flags\x03\0\x10\x01w\x04\0\x0alink-count\x03\0\x12\x01k\x07\x01\
-r\x06\x04type\x0b\x0alink-count\x13\x04size\x09\x15data-access-timestamp\x14\x1b\
-data-modification-timestamp\x14\x17status-change-timestamp\x14\x04\0\x0fdescript\
-or-stat\x03\0\x15\x01q\x03\x09no-change\0\0\x03now\0\0\x09timestamp\x01\x07\0\x04\
-\0\x0dnew-timestamp\x03\0\x17\x01r\x02\x04type\x0b\x04names\x04\0\x0fdirectory-e\
-ntry\x03\0\x19\x01m%\x06access\x0bwould-block\x07already\x0ebad-descriptor\x04bu\
-sy\x08deadlock\x05quota\x05exist\x0efile-too-large\x15illegal-byte-sequence\x0bi\
-n-progress\x0binterrupted\x07invalid\x02io\x0cis-directory\x04loop\x0etoo-many-l\
-inks\x0cmessage-size\x0dname-too-long\x09no-device\x08no-entry\x07no-lock\x13ins\
-ufficient-memory\x12insufficient-space\x0dnot-directory\x09not-empty\x0fnot-reco\
-verable\x0bunsupported\x06no-tty\x0eno-such-device\x08overflow\x0dnot-permitted\x04\
-pipe\x09read-only\x0cinvalid-seek\x0etext-file-busy\x0ccross-device\x04\0\x0aerr\
-or-code\x03\0\x1b\x01m\x06\x06normal\x0asequential\x06random\x09will-need\x09don\
-t-need\x08no-reuse\x04\0\x06advice\x03\0\x1d\x01r\x02\x05lowerw\x05upperw\x04\0\x13\
-metadata-hash-value\x03\0\x1f\x04\0\x0adescriptor\x03\x01\x04\0\x16directory-ent\
-ry-stream\x03\x01\x01h!\x01i\x01\x01j\x01$\x01\x1c\x01@\x02\x04self#\x06offset\x09\
-\0%\x04\0\"[method]descriptor.read-via-stream\x01&\x01i\x03\x01j\x01'\x01\x1c\x01\
-@\x02\x04self#\x06offset\x09\0(\x04\0#[method]descriptor.write-via-stream\x01)\x01\
-@\x01\x04self#\0(\x04\0$[method]descriptor.append-via-stream\x01*\x01j\0\x01\x1c\
-\x01@\x04\x04self#\x06offset\x09\x06length\x09\x06advice\x1e\0+\x04\0\x19[method\
-]descriptor.advise\x01,\x01@\x01\x04self#\0+\x04\0\x1c[method]descriptor.sync-da\
-ta\x01-\x01j\x01\x0d\x01\x1c\x01@\x01\x04self#\0.\x04\0\x1c[method]descriptor.ge\
-t-flags\x01/\x01j\x01\x0b\x01\x1c\x01@\x01\x04self#\00\x04\0\x1b[method]descript\
-or.get-type\x011\x01@\x02\x04self#\x04size\x09\0+\x04\0\x1b[method]descriptor.se\
-t-size\x012\x01@\x03\x04self#\x15data-access-timestamp\x18\x1bdata-modification-\
-timestamp\x18\0+\x04\0\x1c[method]descriptor.set-times\x013\x01p}\x01o\x024\x7f\x01\
-j\x015\x01\x1c\x01@\x03\x04self#\x06length\x09\x06offset\x09\06\x04\0\x17[method\
-]descriptor.read\x017\x01j\x01\x09\x01\x1c\x01@\x03\x04self#\x06buffer4\x06offse\
-t\x09\08\x04\0\x18[method]descriptor.write\x019\x01i\"\x01j\x01:\x01\x1c\x01@\x01\
-\x04self#\0;\x04\0![method]descriptor.read-directory\x01<\x04\0\x17[method]descr\
-iptor.sync\x01-\x01@\x02\x04self#\x04paths\0+\x04\0&[method]descriptor.create-di\
-rectory-at\x01=\x01j\x01\x16\x01\x1c\x01@\x01\x04self#\0>\x04\0\x17[method]descr\
-iptor.stat\x01?\x01@\x03\x04self#\x0apath-flags\x0f\x04paths\0>\x04\0\x1a[method\
-]descriptor.stat-at\x01@\x01@\x05\x04self#\x0apath-flags\x0f\x04paths\x15data-ac\
-cess-timestamp\x18\x1bdata-modification-timestamp\x18\0+\x04\0\x1f[method]descri\
-ptor.set-times-at\x01A\x01@\x05\x04self#\x0eold-path-flags\x0f\x08old-paths\x0en\
-ew-descriptor#\x08new-paths\0+\x04\0\x1a[method]descriptor.link-at\x01B\x01i!\x01\
-j\x01\xc3\0\x01\x1c\x01@\x05\x04self#\x0apath-flags\x0f\x04paths\x0aopen-flags\x11\
-\x05flags\x0d\0\xc4\0\x04\0\x1a[method]descriptor.open-at\x01E\x01j\x01s\x01\x1c\
-\x01@\x02\x04self#\x04paths\0\xc6\0\x04\0\x1e[method]descriptor.readlink-at\x01G\
-\x04\0&[method]descriptor.remove-directory-at\x01=\x01@\x04\x04self#\x08old-path\
-s\x0enew-descriptor#\x08new-paths\0+\x04\0\x1c[method]descriptor.rename-at\x01H\x01\
-@\x03\x04self#\x08old-paths\x08new-paths\0+\x04\0\x1d[method]descriptor.symlink-\
-at\x01I\x04\0![method]descriptor.unlink-file-at\x01=\x01@\x02\x04self#\x05other#\
-\0\x7f\x04\0![method]descriptor.is-same-object\x01J\x01j\x01\x20\x01\x1c\x01@\x01\
-\x04self#\0\xcb\0\x04\0\x20[method]descriptor.metadata-hash\x01L\x01@\x03\x04sel\
-f#\x0apath-flags\x0f\x04paths\0\xcb\0\x04\0#[method]descriptor.metadata-hash-at\x01\
-M\x01h\"\x01k\x1a\x01j\x01\xcf\0\x01\x1c\x01@\x01\x04self\xce\0\0\xd0\0\x04\03[m\
-ethod]directory-entry-stream.read-directory-entry\x01Q\x01h\x05\x01k\x1c\x01@\x01\
-\x03err\xd2\0\0\xd3\0\x04\0\x15filesystem-error-code\x01T\x03\0\x1bwasi:filesyst\
-em/types@0.2.0\x05\x14\x02\x03\0\x0a\x0adescriptor\x01B\x07\x02\x03\x02\x01\x15\x04\
-\0\x0adescriptor\x03\0\0\x01i\x01\x01o\x02\x02s\x01p\x03\x01@\0\0\x04\x04\0\x0fg\
-et-directories\x01\x05\x03\0\x1ewasi:filesystem/preopens@0.2.0\x05\x16\x01B\x02\x01\
-@\x01\x04names\0s\x04\0\x03get\x01\0\x03\0!example:reconciler/retrieve@0.1.0\x05\
-\x17\x01j\x01\x01\x01\x03\x01@\x01\x06objects\0\x18\x04\0\x09reconcile\x01\x19\x04\
-\0#example:reconciler/reconciler@0.1.0\x04\0\x0b\x10\x01\0\x0areconciler\x03\0\0\
-\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.220.0\x10wit-bind\
-gen-rust\x060.35.0";
+#[allow(clippy::octal_escapes)]
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 6199] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xb6/\x01A\x02\x01A4\x01\
+m\x02\x06normal\x07warning\x03\0\x0aevent-kind\x03\0\0\x01r\x03\x04kind\x01\x06r\
+easons\x07messages\x03\0\x05event\x03\0\x02\x01ks\x01ps\x01p\x03\x01r\x05\x07req\
+ueue\x7f\x0drequeue-aftery\x06status\x04\x08children\x05\x06events\x06\x03\0\x10\
+reconcile-result\x03\0\x07\x01r\x02\x04codey\x07messages\x03\0\x0freconcile-erro\
+r\x03\0\x09\x01B\x04\x04\0\x05error\x03\x01\x01h\0\x01@\x01\x04self\x01\0s\x04\0\
+\x1d[method]error.to-debug-string\x01\x02\x03\0\x13wasi:io/error@0.2.0\x05\x0b\x01\
+B\x0a\x04\0\x08pollable\x03\x01\x01h\0\x01@\x01\x04self\x01\0\x7f\x04\0\x16[meth\
+od]pollable.ready\x01\x02\x01@\x01\x04self\x01\x01\0\x04\0\x16[method]pollable.b\
+lock\x01\x03\x01p\x01\x01py\x01@\x01\x02in\x04\0\x05\x04\0\x04poll\x01\x06\x03\0\
+\x12wasi:io/poll@0.2.0\x05\x0c\x02\x03\0\0\x05error\x02\x03\0\x01\x08pollable\x01\
+B(\x02\x03\x02\x01\x0d\x04\0\x05error\x03\0\0\x02\x03\x02\x01\x0e\x04\0\x08polla\
+ble\x03\0\x02\x01i\x01\x01q\x02\x15last-operation-failed\x01\x04\0\x06closed\0\0\
+\x04\0\x0cstream-error\x03\0\x05\x04\0\x0cinput-stream\x03\x01\x04\0\x0doutput-s\
+tream\x03\x01\x01h\x07\x01p}\x01j\x01\x0a\x01\x06\x01@\x02\x04self\x09\x03lenw\0\
+\x0b\x04\0\x19[method]input-stream.read\x01\x0c\x04\0\"[method]input-stream.bloc\
+king-read\x01\x0c\x01j\x01w\x01\x06\x01@\x02\x04self\x09\x03lenw\0\x0d\x04\0\x19\
+[method]input-stream.skip\x01\x0e\x04\0\"[method]input-stream.blocking-skip\x01\x0e\
+\x01i\x03\x01@\x01\x04self\x09\0\x0f\x04\0\x1e[method]input-stream.subscribe\x01\
+\x10\x01h\x08\x01@\x01\x04self\x11\0\x0d\x04\0![method]output-stream.check-write\
+\x01\x12\x01j\0\x01\x06\x01@\x02\x04self\x11\x08contents\x0a\0\x13\x04\0\x1b[met\
+hod]output-stream.write\x01\x14\x04\0.[method]output-stream.blocking-write-and-f\
+lush\x01\x14\x01@\x01\x04self\x11\0\x13\x04\0\x1b[method]output-stream.flush\x01\
+\x15\x04\0$[method]output-stream.blocking-flush\x01\x15\x01@\x01\x04self\x11\0\x0f\
+\x04\0\x1f[method]output-stream.subscribe\x01\x16\x01@\x02\x04self\x11\x03lenw\0\
+\x13\x04\0\"[method]output-stream.write-zeroes\x01\x17\x04\05[method]output-stre\
+am.blocking-write-zeroes-and-flush\x01\x17\x01@\x03\x04self\x11\x03src\x09\x03le\
+nw\0\x0d\x04\0\x1c[method]output-stream.splice\x01\x18\x04\0%[method]output-stre\
+am.blocking-splice\x01\x18\x03\0\x15wasi:io/streams@0.2.0\x05\x0f\x02\x03\0\x02\x0d\
+output-stream\x01B\x05\x02\x03\x02\x01\x10\x04\0\x0doutput-stream\x03\0\0\x01i\x01\
+\x01@\0\0\x02\x04\0\x0aget-stdout\x01\x03\x03\0\x15wasi:cli/stdout@0.2.0\x05\x11\
+\x01B\x05\x01p}\x01@\x01\x03lenw\0\0\x04\0\x10get-random-bytes\x01\x01\x01@\0\0w\
+\x04\0\x0eget-random-u64\x01\x02\x03\0\x18wasi:random/random@0.2.0\x05\x12\x01B\x0a\
+\x01o\x02ss\x01p\0\x01@\0\0\x01\x04\0\x0fget-environment\x01\x02\x01ps\x01@\0\0\x03\
+\x04\0\x0dget-arguments\x01\x04\x01ks\x01@\0\0\x05\x04\0\x0binitial-cwd\x01\x06\x03\
+\0\x1awasi:cli/environment@0.2.0\x05\x13\x01B\x05\x02\x03\x02\x01\x10\x04\0\x0do\
+utput-stream\x03\0\0\x01i\x01\x01@\0\0\x02\x04\0\x0aget-stderr\x01\x03\x03\0\x15\
+wasi:cli/stderr@0.2.0\x05\x14\x02\x03\0\x02\x0cinput-stream\x01B\x05\x02\x03\x02\
+\x01\x15\x04\0\x0cinput-stream\x03\0\0\x01i\x01\x01@\0\0\x02\x04\0\x09get-stdin\x01\
+\x03\x03\0\x14wasi:cli/stdin@0.2.0\x05\x16\x01B\x0f\x02\x03\x02\x01\x0e\x04\0\x08\
+pollable\x03\0\0\x01w\x04\0\x07instant\x03\0\x02\x01w\x04\0\x08duration\x03\0\x04\
+\x01@\0\0\x03\x04\0\x03now\x01\x06\x01@\0\0\x05\x04\0\x0aresolution\x01\x07\x01i\
+\x01\x01@\x01\x04when\x03\0\x08\x04\0\x11subscribe-instant\x01\x09\x01@\x01\x04w\
+hen\x05\0\x08\x04\0\x12subscribe-duration\x01\x0a\x03\0!wasi:clocks/monotonic-cl\
+ock@0.2.0\x05\x17\x01B\x05\x01r\x02\x07secondsw\x0bnanosecondsy\x04\0\x08datetim\
+e\x03\0\0\x01@\0\0\x01\x04\0\x03now\x01\x02\x04\0\x0aresolution\x01\x02\x03\0\x1c\
+wasi:clocks/wall-clock@0.2.0\x05\x18\x02\x03\0\x02\x05error\x02\x03\0\x09\x08dat\
+etime\x01Br\x02\x03\x02\x01\x15\x04\0\x0cinput-stream\x03\0\0\x02\x03\x02\x01\x10\
+\x04\0\x0doutput-stream\x03\0\x02\x02\x03\x02\x01\x19\x04\0\x05error\x03\0\x04\x02\
+\x03\x02\x01\x1a\x04\0\x08datetime\x03\0\x06\x01w\x04\0\x08filesize\x03\0\x08\x01\
+m\x08\x07unknown\x0cblock-device\x10character-device\x09directory\x04fifo\x0dsym\
+bolic-link\x0cregular-file\x06socket\x04\0\x0fdescriptor-type\x03\0\x0a\x01n\x06\
+\x04read\x05write\x13file-integrity-sync\x13data-integrity-sync\x14requested-wri\
+te-sync\x10mutate-directory\x04\0\x10descriptor-flags\x03\0\x0c\x01n\x01\x0esyml\
+ink-follow\x04\0\x0apath-flags\x03\0\x0e\x01n\x04\x06create\x09directory\x09excl\
+usive\x08truncate\x04\0\x0aopen-flags\x03\0\x10\x01w\x04\0\x0alink-count\x03\0\x12\
+\x01k\x07\x01r\x06\x04type\x0b\x0alink-count\x13\x04size\x09\x15data-access-time\
+stamp\x14\x1bdata-modification-timestamp\x14\x17status-change-timestamp\x14\x04\0\
+\x0fdescriptor-stat\x03\0\x15\x01q\x03\x09no-change\0\0\x03now\0\0\x09timestamp\x01\
+\x07\0\x04\0\x0dnew-timestamp\x03\0\x17\x01r\x02\x04type\x0b\x04names\x04\0\x0fd\
+irectory-entry\x03\0\x19\x01m%\x06access\x0bwould-block\x07already\x0ebad-descri\
+ptor\x04busy\x08deadlock\x05quota\x05exist\x0efile-too-large\x15illegal-byte-seq\
+uence\x0bin-progress\x0binterrupted\x07invalid\x02io\x0cis-directory\x04loop\x0e\
+too-many-links\x0cmessage-size\x0dname-too-long\x09no-device\x08no-entry\x07no-l\
+ock\x13insufficient-memory\x12insufficient-space\x0dnot-directory\x09not-empty\x0f\
+not-recoverable\x0bunsupported\x06no-tty\x0eno-such-device\x08overflow\x0dnot-pe\
+rmitted\x04pipe\x09read-only\x0cinvalid-seek\x0etext-file-busy\x0ccross-device\x04\
+\0\x0aerror-code\x03\0\x1b\x01m\x06\x06normal\x0asequential\x06random\x09will-ne\
+ed\x09dont-need\x08no-reuse\x04\0\x06advice\x03\0\x1d\x01r\x02\x05lowerw\x05uppe\
+rw\x04\0\x13metadata-hash-value\x03\0\x1f\x04\0\x0adescriptor\x03\x01\x04\0\x16d\
+irectory-entry-stream\x03\x01\x01h!\x01i\x01\x01j\x01$\x01\x1c\x01@\x02\x04self#\
+\x06offset\x09\0%\x04\0\"[method]descriptor.read-via-stream\x01&\x01i\x03\x01j\x01\
+'\x01\x1c\x01@\x02\x04self#\x06offset\x09\0(\x04\0#[method]descriptor.write-via-\
+stream\x01)\x01@\x01\x04self#\0(\x04\0$[method]descriptor.append-via-stream\x01*\
+\x01j\0\x01\x1c\x01@\x04\x04self#\x06offset\x09\x06length\x09\x06advice\x1e\0+\x04\
+\0\x19[method]descriptor.advise\x01,\x01@\x01\x04self#\0+\x04\0\x1c[method]descr\
+iptor.sync-data\x01-\x01j\x01\x0d\x01\x1c\x01@\x01\x04self#\0.\x04\0\x1c[method]\
+descriptor.get-flags\x01/\x01j\x01\x0b\x01\x1c\x01@\x01\x04self#\00\x04\0\x1b[me\
+thod]descriptor.get-type\x011\x01@\x02\x04self#\x04size\x09\0+\x04\0\x1b[method]\
+descriptor.set-size\x012\x01@\x03\x04self#\x15data-access-timestamp\x18\x1bdata-\
+modification-timestamp\x18\0+\x04\0\x1c[method]descriptor.set-times\x013\x01p}\x01\
+o\x024\x7f\x01j\x015\x01\x1c\x01@\x03\x04self#\x06length\x09\x06offset\x09\06\x04\
+\0\x17[method]descriptor.read\x017\x01j\x01\x09\x01\x1c\x01@\x03\x04self#\x06buf\
+fer4\x06offset\x09\08\x04\0\x18[method]descriptor.write\x019\x01i\"\x01j\x01:\x01\
+\x1c\x01@\x01\x04self#\0;\x04\0![method]descriptor.read-directory\x01<\x04\0\x17\
+[method]descriptor.sync\x01-\x01@\x02\x04self#\x04paths\0+\x04\0&[method]descrip\
+tor.create-directory-at\x01=\x01j\x01\x16\x01\x1c\x01@\x01\x04self#\0>\x04\0\x17\
+[method]descriptor.stat\x01?\x01@\x03\x04self#\x0apath-flags\x0f\x04paths\0>\x04\
+\0\x1a[method]descriptor.stat-at\x01@\x01@\x05\x04self#\x0apath-flags\x0f\x04pat\
+hs\x15data-access-timestamp\x18\x1bdata-modification-timestamp\x18\0+\x04\0\x1f[\
+method]descriptor.set-times-at\x01A\x01@\x05\x04self#\x0eold-path-flags\x0f\x08o\
+ld-paths\x0enew-descriptor#\x08new-paths\0+\x04\0\x1a[method]descriptor.link-at\x01\
+B\x01i!\x01j\x01\xc3\0\x01\x1c\x01@\x05\x04self#\x0apath-flags\x0f\x04paths\x0ao\
+pen-flags\x11\x05flags\x0d\0\xc4\0\x04\0\x1a[method]descriptor.open-at\x01E\x01j\
+\x01s\x01\x1c\x01@\x02\x04self#\x04paths\0\xc6\0\x04\0\x1e[method]descriptor.rea\
+dlink-at\x01G\x04\0&[method]descriptor.remove-directory-at\x01=\x01@\x04\x04self\
+#\x08old-paths\x0enew-descriptor#\x08new-paths\0+\x04\0\x1c[method]descriptor.re\
+name-at\x01H\x01@\x03\x04self#\x08old-paths\x08new-paths\0+\x04\0\x1d[method]des\
+criptor.symlink-at\x01I\x04\0![method]descriptor.unlink-file-at\x01=\x01@\x02\x04\
+self#\x05other#\0\x7f\x04\0![method]descriptor.is-same-object\x01J\x01j\x01\x20\x01\
+\x1c\x01@\x01\x04self#\0\xcb\0\x04\0\x20[method]descriptor.metadata-hash\x01L\x01\
+@\x03\x04self#\x0apath-flags\x0f\x04paths\0\xcb\0\x04\0#[method]descriptor.metad\
+ata-hash-at\x01M\x01h\"\x01k\x1a\x01j\x01\xcf\0\x01\x1c\x01@\x01\x04self\xce\0\0\
+\xd0\0\x04\03[method]directory-entry-stream.read-directory-entry\x01Q\x01h\x05\x01\
+k\x1c\x01@\x01\x03err\xd2\0\0\xd3\0\x04\0\x15filesystem-error-code\x01T\x03\0\x1b\
+wasi:filesystem/types@0.2.0\x05\x1b\x02\x03\0\x0a\x0adescriptor\x01B\x07\x02\x03\
+\x02\x01\x1c\x04\0\x0adescriptor\x03\0\0\x01i\x01\x01o\x02\x02s\x01p\x03\x01@\0\0\
+\x04\x04\0\x0fget-directories\x01\x05\x03\0\x1ewasi:filesystem/preopens@0.2.0\x05\
+\x1d\x01B\x0c\x01ks\x01r\x04\x0bapi-versions\x04kinds\x09namespace\0\x04names\x04\
+\0\x0aobject-ref\x03\0\x01\x01q\x02\x09not-found\0\0\x07invalid\x01s\0\x04\0\x0c\
+lookup-error\x03\0\x03\x01j\x01s\x01\x04\x01@\x01\x09reference\x02\0\x05\x04\0\x03\
+get\x01\x06\x01ps\x01j\x01\x07\x01\x04\x01@\x03\x04kinds\x09namespace\0\x0elabel\
+-selectors\0\x08\x04\0\x0clist-objects\x01\x09\x03\0!example:reconciler/retrieve\
+@0.2.0\x05\x1e\x02\x03\0\x0c\x0aobject-ref\x01B\x0e\x02\x03\x02\x01\x1f\x04\0\x0a\
+object-ref\x03\0\0\x01q\x04\x09not-found\0\0\x0ealready-exists\0\0\x08conflict\x01\
+s\0\x07invalid\x01s\0\x04\0\x0bwrite-error\x03\0\x02\x01j\x01s\x01\x03\x01@\x01\x06\
+objects\0\x04\x04\0\x06create\x01\x05\x04\0\x06update\x01\x05\x01@\x02\x09refere\
+nce\x01\x05patchs\0\x04\x04\0\x05patch\x01\x06\x01ks\x01j\0\x01\x03\x01@\x02\x09\
+reference\x01\x10resource-version\x07\0\x08\x04\0\x06delete\x01\x09\x03\0\x1fexa\
+mple:reconciler/writer@0.2.0\x05\x20\x01B\x07\x01m\x05\x05trace\x05debug\x04info\
+\x04warn\x05error\x04\0\x05level\x03\0\0\x01r\x02\x03keys\x05values\x04\0\x05fie\
+ld\x03\0\x02\x01p\x03\x01@\x03\x05level\x01\x07messages\x06fields\x04\x01\0\x04\0\
+\x03log\x01\x05\x03\0\x20example:reconciler/logging@0.2.0\x05!\x01j\x01\x08\x01\x0a\
+\x01@\x01\x06objects\0\"\x04\0\x09reconcile\x01#\x04\0#example:reconciler/reconc\
+iler@0.2.0\x04\0\x0b\x10\x01\0\x0areconciler\x03\0\0\0G\x09producers\x01\x0cproc\
+essed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
+
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
@@ -1,20 +1,48 @@
 #[allow(warnings)]
 pub mod bindings;
 
-use bindings::{Guest, ReconcileResult, ReconcileError};
-use bindings::example::reconciler::retrieve::get;
+use bindings::{Event, EventKind, Guest, ReconcileResult, ReconcileError};
+use bindings::example::reconciler::logging::{log, Field, Level};
+use bindings::example::reconciler::retrieve::{get, list_objects, LookupError, ObjectRef};
 
 struct Component;
 
 impl Guest for Component {
     fn reconcile(_obj: String) -> Result<ReconcileResult, ReconcileError> {
         
-        let response = get("Wim");
-        println!("get response {}", response);
+        let node1 = ObjectRef {
+            api_version: "infra.kuid.dev/v1alpha1".to_string(),
+            kind: "Node".to_string(),
+            namespace: Some("default".to_string()),
+            name: "kubenet.node1".to_string(),
+        };
+        let node_field = || Field { key: "node".to_string(), value: node1.name.clone() };
+        match get(&node1) {
+            Ok(_) => log(Level::Debug, "node exists", &[node_field()]),
+            Err(LookupError::NotFound) => log(Level::Info, "node not found", &[node_field()]),
+            Err(LookupError::Invalid(msg)) => log(Level::Error, &format!("invalid reference: {}", msg), &[node_field()]),
+        }
 
-        let obj = "{\"wim\": \"mieke\"}";
+        match list_objects("Node", Some("default"), "topo.kubenet.dev/topology=kubenet") {
+            Ok(nodes) => log(Level::Debug, "listed nodes", &[Field { key: "count".to_string(), value: nodes.len().to_string() }]),
+            Err(e) => log(Level::Error, &format!("cannot list nodes: {:?}", e), &[]),
+        }
 
-        Ok(ReconcileResult{requeue: false, requeue_after: 0, object: obj.to_string()})
+        // the host creates or updates the children, the namespace defaults to the one of the topology
+        let node = r#"{"apiVersion":"infra.kuid.dev/v1alpha1","kind":"Node","metadata":{"name":"kubenet.node1","labels":{"topo.kubenet.dev/topology":"kubenet"}}}"#;
+        let status = r#"{"nodes": 1}"#;
+
+        Ok(ReconcileResult{
+            requeue: false,
+            requeue_after: 0,
+            status: Some(status.to_string()),
+            children: vec![node.to_string()],
+            events: vec![Event{
+                kind: EventKind::Normal,
+                reason: "Reconciled".to_string(),
+                message: "desired 1 node".to_string(),
+            }],
+        })
     }
 }
 
@@ -0,0 +1,215 @@
+//! Retry handling for failed reconciles
+//!
+//! A failed object is retried after a per-object exponential backoff, which is
+//! additionally bounded by a token bucket shared by all objects, so that a guest
+//! that fails on every object cannot hot-loop the host.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+/// decides which reconcile error codes are retried
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// error codes that are never retried
+    pub no_retry_codes: Vec<RangeInclusive<u32>>,
+    /// number of retries after which an object is dropped
+    pub max_retries: u32,
+}
+
+impl RetryPolicy {
+    pub fn is_retryable(&self, code: u32) -> bool {
+        !self
+            .no_retry_codes
+            .iter()
+            .any(|range| range.contains(&code))
+    }
+}
+
+/// combines the per-object backoff with the global token bucket,
+/// the delay for a retry is the larger of both
+#[derive(Debug)]
+pub struct RateLimiter<K> {
+    backoff: ExponentialBackoff<K>,
+    bucket: TokenBucket,
+}
+
+impl<K: Clone + Eq + Hash> RateLimiter<K> {
+    pub fn new(backoff: ExponentialBackoff<K>, bucket: TokenBucket) -> Self {
+        Self { backoff, bucket }
+    }
+
+    /// the delay before the next retry of `key`, counting this retry
+    pub fn when(&mut self, key: &K) -> Duration {
+        self.backoff.when(key).max(self.bucket.reserve())
+    }
+
+    /// number of retries of `key` since it last succeeded
+    pub fn retries(&self, key: &K) -> u32 {
+        self.backoff.retries(key)
+    }
+
+    /// reset the backoff of `key`, e.g. after it reconciled successfully
+    pub fn forget(&mut self, key: &K) {
+        self.backoff.forget(key)
+    }
+}
+
+/// a delay of `base * 2^retries` per key, capped at `max`
+#[derive(Debug)]
+pub struct ExponentialBackoff<K> {
+    base: Duration,
+    max: Duration,
+    failures: HashMap<K, u32>,
+}
+
+impl<K: Clone + Eq + Hash> ExponentialBackoff<K> {
+    pub fn new(base: Duration, max: Duration) -> Self {
+        Self {
+            base,
+            max,
+            failures: HashMap::new(),
+        }
+    }
+
+    pub fn when(&mut self, key: &K) -> Duration {
+        let failures = self.failures.entry(key.clone()).or_insert(0);
+        let exp = *failures;
+        *failures += 1;
+
+        // saturate instead of overflowing for long failing objects
+        let factor = 2u32.checked_pow(exp).unwrap_or(u32::MAX);
+        self.base.saturating_mul(factor).min(self.max)
+    }
+
+    pub fn retries(&self, key: &K) -> u32 {
+        self.failures.get(key).copied().unwrap_or(0)
+    }
+
+    pub fn forget(&mut self, key: &K) {
+        self.failures.remove(key);
+    }
+}
+
+/// a token bucket refilled at `rate` tokens per second holding at most `burst` tokens
+///
+/// reserving a token never fails, it returns how long the caller must wait
+/// until the reserved token becomes available
+#[derive(Debug)]
+pub struct TokenBucket {
+    rate: f64,
+    burst: f64,
+    tokens: f64,
+    last: Instant,
+}
+
+impl TokenBucket {
+    pub fn new(rate: f64, burst: u32) -> Self {
+        Self {
+            rate,
+            burst: burst.into(),
+            tokens: burst.into(),
+            last: Instant::now(),
+        }
+    }
+
+    pub fn reserve(&mut self) -> Duration {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last).as_secs_f64();
+        self.last = now;
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.burst) - 1.0;
+
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MS: Duration = Duration::from_millis(1);
+
+    #[test]
+    fn backoff_doubles_per_retry_up_to_the_cap() {
+        let mut backoff = ExponentialBackoff::new(10 * MS, 100 * MS);
+        let delays: Vec<Duration> = (0..6).map(|_| backoff.when(&"a")).collect();
+        assert_eq!(
+            delays,
+            [10 * MS, 20 * MS, 40 * MS, 80 * MS, 100 * MS, 100 * MS]
+        );
+        assert_eq!(backoff.retries(&"a"), 6);
+    }
+
+    #[test]
+    fn backoff_saturates_for_long_failing_keys() {
+        let mut backoff = ExponentialBackoff::new(Duration::from_secs(1), Duration::from_secs(60));
+        for _ in 0..100 {
+            backoff.when(&"a");
+        }
+        assert_eq!(backoff.when(&"a"), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn backoff_is_per_key_and_reset_by_forget() {
+        let mut backoff = ExponentialBackoff::new(10 * MS, Duration::from_secs(1));
+        backoff.when(&"a");
+        backoff.when(&"a");
+        assert_eq!(backoff.when(&"b"), 10 * MS);
+
+        backoff.forget(&"a");
+        assert_eq!(backoff.retries(&"a"), 0);
+        assert_eq!(backoff.when(&"a"), 10 * MS);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn token_bucket_allows_a_burst_then_paces_at_the_rate() {
+        let mut bucket = TokenBucket::new(10.0, 2);
+        assert_eq!(bucket.reserve(), Duration::ZERO);
+        assert_eq!(bucket.reserve(), Duration::ZERO);
+        assert_eq!(bucket.reserve(), 100 * MS);
+        assert_eq!(bucket.reserve(), 200 * MS);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn token_bucket_refills_up_to_the_burst() {
+        let mut bucket = TokenBucket::new(10.0, 2);
+        bucket.reserve();
+        bucket.reserve();
+        bucket.reserve();
+
+        tokio::time::advance(Duration::from_secs(10)).await;
+        assert_eq!(bucket.reserve(), Duration::ZERO);
+        assert_eq!(bucket.reserve(), Duration::ZERO);
+        assert_eq!(bucket.reserve(), 100 * MS);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn rate_limiter_waits_for_the_longer_of_backoff_and_bucket() {
+        let mut limiter = RateLimiter::new(
+            ExponentialBackoff::new(10 * MS, Duration::from_secs(1)),
+            TokenBucket::new(1.0, 1),
+        );
+        assert_eq!(limiter.when(&"a"), 10 * MS);
+        assert_eq!(limiter.when(&"a"), Duration::from_secs(1));
+        assert_eq!(limiter.retries(&"a"), 2);
+    }
+
+    #[test]
+    fn retry_policy_skips_listed_codes() {
+        let policy = RetryPolicy {
+            no_retry_codes: vec![400..=499, 501..=501],
+            max_retries: 3,
+        };
+        assert!(!policy.is_retryable(404));
+        assert!(!policy.is_retryable(501));
+        assert!(policy.is_retryable(500));
+        assert!(policy.is_retryable(504));
+    }
+}
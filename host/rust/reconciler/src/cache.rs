@@ -0,0 +1,97 @@
+//! On-disk cache of compiled components
+//!
+//! Compiling a component takes far longer than loading compiled code, for the
+//! Python guest it dominates the start of the host. Compiled components are
+//! stored under the SHA-256 of the component bytes and of the precompile
+//! compatibility hash of the engine. That hash covers the wasmtime version and
+//! every engine setting that affects the compiled code, so upgrading wasmtime or
+//! e.g. enabling fuel misses the cache instead of loading incompatible code.
+
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+use wasmtime::component::Component;
+use wasmtime::Engine;
+
+#[derive(Debug, Clone)]
+pub struct ComponentCache {
+    dir: PathBuf,
+}
+
+impl ComponentCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// `$XDG_CACHE_HOME/reconciler`, falling back to `~/.cache/reconciler`
+    pub fn default_dir() -> Option<PathBuf> {
+        let cache_home = std::env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))?;
+        Some(cache_home.join("reconciler"))
+    }
+
+    /// load the compiled component from the cache, compiling and storing it on a miss
+    ///
+    /// failing to write the cache is not an error, the component is still returned
+    pub fn load(&self, engine: &Engine, bytes: &[u8]) -> Result<Component> {
+        let path = self.dir.join(format!("{}.cwasm", cache_key(engine, bytes)));
+
+        if path.exists() {
+            // SAFETY: the file was written by `store` from the output of `Component::serialize`
+            // for an engine with the same compatibility hash, the cache directory is trusted
+            match unsafe { Component::deserialize_file(engine, &path) } {
+                Ok(component) => {
+                    tracing::debug!(path = %path.display(), "loaded compiled component from cache");
+                    return Ok(component);
+                }
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), "ignoring unusable cache entry: {e:#}")
+                }
+            }
+        }
+
+        let component = Component::new(engine, bytes).context("cannot compile component")?;
+        if let Err(e) = self.store(&path, &component) {
+            tracing::warn!(path = %path.display(), "cannot cache compiled component: {e:#}");
+        }
+        Ok(component)
+    }
+
+    /// write through a temporary file, so that a concurrent load never sees a partial entry
+    fn store(&self, path: &Path, component: &Component) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)
+            .with_context(|| format!("cannot create {}", self.dir.display()))?;
+        let tmp = path.with_extension(format!("tmp{}", std::process::id()));
+        std::fs::write(&tmp, component.serialize()?)
+            .with_context(|| format!("cannot write {}", tmp.display()))?;
+        std::fs::rename(&tmp, path)
+            .with_context(|| format!("cannot rename {} to {}", tmp.display(), path.display()))?;
+        tracing::debug!(path = %path.display(), "cached compiled component");
+        Ok(())
+    }
+}
+
+/// hex SHA-256 of the engine compatibility hash and the component bytes
+fn cache_key(engine: &Engine, bytes: &[u8]) -> String {
+    let mut hasher = Sha256Hasher(Sha256::new());
+    engine.precompile_compatibility_hash().hash(&mut hasher);
+    hasher.0.update(bytes);
+    format!("{:x}", hasher.0.finalize())
+}
+
+/// feeds `Hash` implementations into a SHA-256 digest,
+/// unlike `DefaultHasher` the result is stable across builds
+struct Sha256Hasher(Sha256);
+
+impl Hasher for Sha256Hasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        unreachable!("the digest is read with `finalize`")
+    }
+}
@@ -0,0 +1,450 @@
+use std::net::SocketAddr;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand};
+use tracing::Level;
+
+use crate::cache::ComponentCache;
+use crate::instance::InstanceConfig;
+use crate::limits::Limits;
+use crate::policy::{Source, WasiPolicy};
+
+/// Host for WebAssembly reconciler components
+#[derive(Debug, Parser)]
+#[command(name = "reconciler", version, about)]
+pub struct Cli {
+    /// Path to the reconciler WebAssembly component
+    #[arg(short, long, global = true, env = "GUEST_WASM_PATH")]
+    pub wasm: Option<PathBuf>,
+
+    /// Serve Prometheus metrics of the reconciles on `http://<addr>/metrics`
+    #[arg(long, global = true, env = "RECONCILER_METRICS_ADDR")]
+    pub metrics_addr: Option<SocketAddr>,
+
+    /// Log the duration of the load, link, instantiate and reconcile spans when they close
+    #[arg(long, global = true)]
+    pub log_spans: bool,
+
+    #[command(flatten)]
+    pub runtime: RuntimeArgs,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Reconcile objects read from files, directories or stdin
+    Run(RunArgs),
+    /// Check that a component satisfies the `reconciler` world
+    Validate,
+    /// Reconcile an object repeatedly and report timing statistics
+    Bench(BenchArgs),
+    /// Print the imports and exports of a component
+    Inspect,
+    /// Reconcile objects in a loop, requeueing them as the reconciler requests
+    Controller(ControllerArgs),
+    /// Compile a component ahead of time into a `.cwasm` file the host loads without compiling
+    Precompile(PrecompileArgs),
+    /// Call a component with recorded reconciles and report where it diverges from the recording
+    Replay(ReplayArgs),
+    /// Run a directory of conformance cases against guests and print which cases each passes
+    Conformance(ConformanceArgs),
+    /// Compare the result of reconciling each input with its golden file
+    Test(TestArgs),
+}
+
+/// bounds on the guest applied to every reconcile call
+#[derive(Debug, Clone, Args)]
+pub struct RuntimeArgs {
+    /// Deadline of a single reconcile call, a guest that runs longer is
+    /// interrupted and its instance is recreated
+    #[arg(long, global = true, default_value = "10s", value_parser = humantime::parse_duration)]
+    pub timeout: Duration,
+
+    /// Fuel budget of a single reconcile call, enables fuel metering.
+    /// A guest that uses up its fuel is interrupted and its instance is recreated
+    #[arg(long, global = true)]
+    pub fuel: Option<u64>,
+
+    /// Maximum size of a guest linear memory, e.g. `256MiB`
+    #[arg(long, global = true, value_parser = parse_size)]
+    pub max_memory: Option<usize>,
+
+    /// Maximum number of elements of a guest table
+    #[arg(long, global = true)]
+    pub max_table_elements: Option<usize>,
+
+    /// Maximum number of core module and component instances of a guest
+    #[arg(long, global = true)]
+    pub max_instances: Option<usize>,
+
+    /// Instantiate a fresh guest instance for every reconcile, so no guest state
+    /// carries over between reconciles. Uses the pooling instance allocator
+    #[arg(long, global = true)]
+    pub fresh_instance: bool,
+
+    /// Forward what guests write to stdout and stderr to the host log at this level,
+    /// e.g. `info`. The output is always captured per reconcile
+    #[arg(long, global = true)]
+    pub guest_output_level: Option<Level>,
+
+    /// YAML file with the WASI capabilities of the guest: preopened directories,
+    /// environment variables and real or virtual clocks and random.
+    /// Without a policy the guest gets no directories and no environment variables,
+    /// but the real wall and monotonic clocks and OS randomness,
+    /// `--deterministic` switches to virtual clocks and seeded random, see `--seed`
+    #[arg(long, global = true, env = "RECONCILER_WASI_POLICY")]
+    pub wasi_policy: Option<PathBuf>,
+
+    /// Give every guest virtual clocks and seeded random whatever its WASI policy says,
+    /// so identical inputs give identical results
+    #[arg(long, global = true)]
+    pub deterministic: bool,
+
+    /// Seed of the virtual random, replaces the seed of the WASI policies
+    #[arg(long, global = true)]
+    pub seed: Option<u64>,
+
+    /// Directory to write a trace of every reconcile call to, for `replay`
+    #[arg(long, global = true, env = "RECONCILER_RECORD")]
+    pub record: Option<PathBuf>,
+
+    /// Directory of the compiled component cache,
+    /// defaults to `$XDG_CACHE_HOME/reconciler` or `~/.cache/reconciler`
+    #[arg(long, global = true, env = "RECONCILER_CACHE_DIR")]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Compile the component on every start instead of using the compiled component cache
+    #[arg(long, global = true)]
+    pub no_cache: bool,
+}
+
+impl RuntimeArgs {
+    pub fn instance_config(&self) -> anyhow::Result<InstanceConfig> {
+        let policy = match &self.wasi_policy {
+            Some(path) => WasiPolicy::load(path)?,
+            None => WasiPolicy::default(),
+        };
+        Ok(InstanceConfig {
+            timeout: self.timeout,
+            fuel: self.fuel,
+            limits: Limits {
+                memory: self.max_memory,
+                table_elements: self.max_table_elements,
+                instances: self.max_instances,
+            },
+            fresh: self.fresh_instance,
+            output_level: self.guest_output_level,
+            policy: Arc::new(self.policy(policy)),
+            record: self.record.clone(),
+        })
+    }
+
+    /// apply `--deterministic` and `--seed` to a WASI policy
+    pub fn policy(&self, mut policy: WasiPolicy) -> WasiPolicy {
+        if self.deterministic {
+            policy.clocks = Source::Virtual;
+            policy.random = Source::Virtual;
+        }
+        if let Some(seed) = self.seed {
+            policy.seed = seed;
+        }
+        policy
+    }
+
+    /// the compiled component cache, none when disabled or without a cache directory
+    pub fn cache(&self) -> Option<ComponentCache> {
+        if self.no_cache {
+            return None;
+        }
+        self.cache_dir
+            .clone()
+            .or_else(ComponentCache::default_dir)
+            .map(ComponentCache::new)
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct RunArgs {
+    /// JSON or YAML files or directories with objects to reconcile, `-` reads from stdin
+    #[arg(short, long, default_value = "-", num_args = 1..)]
+    pub input: Vec<PathBuf>,
+
+    /// JSON or YAML files or directories with related objects that are added
+    /// to the object store, but not reconciled
+    #[arg(long, num_args = 1..)]
+    pub objects: Vec<PathBuf>,
+
+    /// Print the writes of each reconcile as a unified diff per object
+    /// instead of applying them
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Reconcile each object twice with a fresh instance and fail when the
+    /// results, the object store or the guest output differ
+    #[arg(long)]
+    pub check_drift: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct BenchArgs {
+    /// JSON or YAML file with the single object to reconcile, `-` reads from stdin
+    #[arg(short, long, default_value = "-")]
+    pub input: PathBuf,
+
+    /// Number of measured reconcile iterations
+    #[arg(short = 'n', long, default_value_t = 10)]
+    pub iterations: u32,
+
+    /// Number of unmeasured iterations run before measuring
+    #[arg(long, default_value_t = 0)]
+    pub warmup: u32,
+
+    /// JSON or YAML files or directories with related objects that are added
+    /// to the object store, but not reconciled
+    #[arg(long, num_args = 1..)]
+    pub objects: Vec<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct ControllerArgs {
+    /// JSON or YAML files or directories with objects to reconcile, `-` reads from stdin
+    #[arg(short, long, default_value = "-", num_args = 1..)]
+    pub input: Vec<PathBuf>,
+
+    /// JSON or YAML files or directories with related objects that are added
+    /// to the object store, but not reconciled
+    #[arg(long, num_args = 1..)]
+    pub objects: Vec<PathBuf>,
+
+    /// Stop once no object is queued or waiting for a requeue
+    #[arg(long)]
+    pub until_idle: bool,
+
+    /// YAML registry config that maps apiVersion and kind to the component
+    /// reconciling them, used instead of `--wasm`
+    #[arg(long, env = "RECONCILER_REGISTRY")]
+    pub registry: Option<PathBuf>,
+
+    /// Reload a component when its file changes, a new version that fails
+    /// to compile or link is logged and the current one is kept
+    #[arg(long)]
+    pub watch: bool,
+
+    #[command(flatten)]
+    pub retry: RetryArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct PrecompileArgs {
+    /// Path of the compiled component, defaults to the component path with a `.cwasm` extension
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct ReplayArgs {
+    /// Trace files or directories of trace files written with `--record`
+    #[arg(required = true, num_args = 1..)]
+    pub traces: Vec<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct ConformanceArgs {
+    /// Directory with a YAML or JSON file per case
+    #[arg(default_value = "conformance")]
+    pub cases: PathBuf,
+
+    /// Guest to run the cases against as `<name>=<path>` or `<path>`, repeatable,
+    /// defaults to the `--wasm` component
+    #[arg(long = "guest", value_parser = parse_guest)]
+    pub guests: Vec<(String, PathBuf)>,
+}
+
+#[derive(Debug, Args)]
+pub struct TestArgs {
+    /// Input object files or directories of them, the golden file of
+    /// `<name>.yaml` is `<name>.golden.json` next to it
+    #[arg(required = true, num_args = 1..)]
+    pub inputs: Vec<PathBuf>,
+
+    /// JSON or YAML files or directories with related objects that are added
+    /// to the object store of every test
+    #[arg(long, num_args = 1..)]
+    pub objects: Vec<PathBuf>,
+
+    /// Rewrite the golden files with the current results instead of comparing
+    #[arg(long)]
+    pub update: bool,
+}
+
+/// retry behavior for failed reconciles
+#[derive(Debug, Args)]
+pub struct RetryArgs {
+    /// Delay before the first retry of a failed object, doubled on every retry
+    #[arg(long, default_value = "100ms", value_parser = humantime::parse_duration)]
+    pub backoff_base: Duration,
+
+    /// Upper bound of the per-object retry delay
+    #[arg(long, default_value = "5m", value_parser = humantime::parse_duration)]
+    pub backoff_max: Duration,
+
+    /// Number of retries after which a failing object is dropped
+    #[arg(long, default_value_t = 10)]
+    pub max_retries: u32,
+
+    /// Retries per second allowed across all objects
+    #[arg(long, default_value_t = 10.0, value_parser = parse_rate)]
+    pub retry_rate: f64,
+
+    /// Number of retries allowed in a burst above the retry rate
+    #[arg(long, default_value_t = 100)]
+    pub retry_burst: u32,
+
+    /// Error codes or code ranges that are never retried, e.g. `400-499,0`
+    #[arg(long, default_value = "400-499", value_delimiter = ',', value_parser = parse_code_range)]
+    pub no_retry_codes: Vec<RangeInclusive<u32>>,
+}
+
+/// parse a size in bytes with an optional `KiB`, `MiB` or `GiB` suffix
+fn parse_size(s: &str) -> Result<usize, String> {
+    let s = s.trim();
+    let (number, unit) = s
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or((s, ""), |i| s.split_at(i));
+    let factor: usize = match unit.trim() {
+        "" | "B" => 1,
+        "KiB" => 1 << 10,
+        "MiB" => 1 << 20,
+        "GiB" => 1 << 30,
+        unit => {
+            return Err(format!(
+                "unknown size unit `{unit}`, expected KiB, MiB or GiB"
+            ))
+        }
+    };
+    number
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| n.checked_mul(factor))
+        .ok_or_else(|| format!("`{s}` is not a valid size"))
+}
+
+/// parse `<name>=<path>`, a bare path is named after its file name
+fn parse_guest(s: &str) -> Result<(String, PathBuf), String> {
+    match s.split_once('=') {
+        Some((name, path)) if !name.is_empty() => Ok((name.to_string(), PathBuf::from(path))),
+        Some(_) => Err(format!("`{s}` has an empty guest name")),
+        None => {
+            let path = PathBuf::from(s);
+            let name = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .ok_or_else(|| format!("`{s}` is not a component path"))?;
+            Ok((name, path))
+        }
+    }
+}
+
+fn parse_rate(s: &str) -> Result<f64, String> {
+    match s.parse::<f64>() {
+        Ok(rate) if rate > 0.0 => Ok(rate),
+        _ => Err(format!("`{s}` is not a positive number")),
+    }
+}
+
+/// parse a single error code (`500`) or an inclusive range of codes (`400-499`)
+fn parse_code_range(s: &str) -> Result<RangeInclusive<u32>, String> {
+    let parse = |v: &str| {
+        v.trim()
+            .parse::<u32>()
+            .map_err(|_| format!("`{v}` is not a valid error code"))
+    };
+    match s.split_once('-') {
+        Some((start, end)) => {
+            let (start, end) = (parse(start)?, parse(end)?);
+            if start > end {
+                return Err(format!(
+                    "`{s}` is not a valid range, {start} is above {end}"
+                ));
+            }
+            Ok(start..=end)
+        }
+        None => {
+            let code = parse(s)?;
+            Ok(code..=code)
+        }
+    }
+}
+
+impl Cli {
+    /// path to the component, required by every subcommand
+    pub fn wasm_path(&self) -> anyhow::Result<PathBuf> {
+        self.wasm.clone().ok_or_else(|| {
+            anyhow::anyhow!(
+                "missing path to WebAssembly component (--wasm or env: GUEST_WASM_PATH)"
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_size_accepts_binary_units() {
+        assert_eq!(parse_size("1024"), Ok(1024));
+        assert_eq!(parse_size("10B"), Ok(10));
+        assert_eq!(parse_size("64KiB"), Ok(64 << 10));
+        assert_eq!(parse_size(" 256MiB "), Ok(256 << 20));
+        assert_eq!(parse_size("2GiB"), Ok(2 << 30));
+    }
+
+    #[test]
+    fn parse_size_rejects_unknown_units_and_overflow() {
+        assert!(parse_size("1TiB").is_err());
+        assert!(parse_size("1MB").is_err());
+        assert!(parse_size("MiB").is_err());
+        assert!(parse_size("").is_err());
+        assert!(parse_size(&format!("{}GiB", usize::MAX)).is_err());
+    }
+
+    #[test]
+    fn parse_code_range_accepts_codes_and_ranges() {
+        assert_eq!(parse_code_range("500"), Ok(500..=500));
+        assert_eq!(parse_code_range("400-499"), Ok(400..=499));
+        assert_eq!(parse_code_range(" 400 - 499 "), Ok(400..=499));
+        assert_eq!(parse_code_range("404-404"), Ok(404..=404));
+    }
+
+    #[test]
+    fn parse_code_range_rejects_reversed_and_malformed_ranges() {
+        assert!(parse_code_range("500-400").is_err());
+        assert!(parse_code_range("400-").is_err());
+        assert!(parse_code_range("-400").is_err());
+        assert!(parse_code_range("4xx").is_err());
+    }
+
+    #[test]
+    fn parse_rate_accepts_positive_numbers_only() {
+        assert_eq!(parse_rate("2.5"), Ok(2.5));
+        assert_eq!(parse_rate("10"), Ok(10.0));
+        assert!(parse_rate("0").is_err());
+        assert!(parse_rate("-1").is_err());
+        assert!(parse_rate("NaN").is_err());
+        assert!(parse_rate("fast").is_err());
+    }
+
+    #[test]
+    fn reversed_no_retry_range_is_a_usage_error() {
+        let error =
+            Cli::try_parse_from(["reconciler", "controller", "--no-retry-codes", "500-400"])
+                .unwrap_err();
+        assert_eq!(error.kind(), clap::error::ErrorKind::ValueValidation);
+    }
+}
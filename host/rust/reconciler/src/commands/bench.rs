@@ -0,0 +1,197 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+
+use crate::cli::{BenchArgs, RuntimeArgs};
+use crate::input::load_object;
+use crate::{call_reconcile, load_reconciler_instance};
+
+/// reconcile the same object repeatedly and report timing statistics, fuel statistics
+/// when fuel is metered and the cost of instantiating the guest
+pub async fn exec(wasm_path: &Path, runtime: &RuntimeArgs, args: BenchArgs) -> Result<()> {
+    let object = load_object(std::slice::from_ref(&args.input))?;
+    let objects_store = super::seed_store(std::slice::from_ref(&object), &args.objects)?;
+
+    let load_start = Instant::now();
+    let mut instance = load_reconciler_instance(wasm_path, objects_store, runtime)
+        .await
+        .map_err(|e| anyhow::anyhow!("Error loading reconciler instance: {:#}", e))?;
+    println!("Load time: {:?}", load_start.elapsed());
+
+    // the load instantiates once, a fresh instance mode instantiates again on every call
+    let mut instantiations: Vec<Duration> = instance.instantiation().into_iter().collect();
+
+    for _ in 0..args.warmup {
+        let _ = call_reconcile(&mut instance, &object.value).await;
+        instance.objects_mut().take_changes();
+    }
+
+    let mut durations = Vec::with_capacity(args.iterations as usize);
+    let mut fuel = Vec::with_capacity(args.iterations as usize);
+    let mut failures = 0;
+    for i in 0..args.iterations {
+        let iteration_start = Instant::now();
+        let result = call_reconcile(&mut instance, &object.value).await;
+        let iteration_duration = iteration_start.elapsed();
+        durations.push(iteration_duration);
+        instance.objects_mut().take_changes();
+
+        let iteration_fuel = instance.fuel_consumed();
+        fuel.extend(iteration_fuel);
+        let iteration_instantiation = instance.instantiation();
+        instantiations.extend(iteration_instantiation);
+        let suffix = [
+            iteration_fuel.map(|consumed| format!("{consumed} fuel")),
+            iteration_instantiation.map(|d| format!("instantiation {d:?}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(", ");
+        let suffix = if suffix.is_empty() {
+            suffix
+        } else {
+            format!(" ({suffix})")
+        };
+
+        match result {
+            Ok(_) => println!(
+                "Iteration {} succeeded in {:?}{}",
+                i + 1,
+                iteration_duration,
+                suffix
+            ),
+            Err(e) => {
+                failures += 1;
+                println!(
+                    "Iteration {} failed in {:?}{}: {}",
+                    i + 1,
+                    iteration_duration,
+                    suffix,
+                    e
+                );
+            }
+        }
+    }
+
+    if let Some(stats) = Stats::from_durations(&mut durations) {
+        println!("Iterations: {} ({} failed)", args.iterations, failures);
+        println!("{stats}");
+    }
+    if let Some(stats) = FuelStats::from_fuel(&mut fuel) {
+        println!("{stats}");
+    }
+    if !instantiations.is_empty() {
+        let total: Duration = instantiations.iter().sum();
+        println!(
+            "Instantiations: {} ({} instance), total {:?}, mean {:?}",
+            instantiations.len(),
+            if runtime.fresh_instance {
+                "fresh"
+            } else {
+                "reused"
+            },
+            total,
+            total / instantiations.len() as u32
+        );
+    }
+
+    Ok(())
+}
+
+/// summary statistics over a set of iteration durations
+struct Stats {
+    total: Duration,
+    min: Duration,
+    max: Duration,
+    mean: Duration,
+    p50: Duration,
+    p95: Duration,
+    stddev: Duration,
+}
+
+impl Stats {
+    fn from_durations(durations: &mut [Duration]) -> Option<Self> {
+        if durations.is_empty() {
+            return None;
+        }
+        durations.sort();
+
+        let total: Duration = durations.iter().sum();
+        let mean = total / durations.len() as u32;
+        let variance = durations
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / durations.len() as f64;
+
+        Some(Self {
+            total,
+            min: durations[0],
+            max: durations[durations.len() - 1],
+            mean,
+            p50: percentile(durations, 50),
+            p95: percentile(durations, 95),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// nearest-rank percentile of sorted values
+fn percentile<T: Copy>(sorted: &[T], pct: usize) -> T {
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Total:  {:?}", self.total)?;
+        writeln!(f, "Min:    {:?}", self.min)?;
+        writeln!(f, "Max:    {:?}", self.max)?;
+        writeln!(f, "Mean:   {:?}", self.mean)?;
+        writeln!(f, "p50:    {:?}", self.p50)?;
+        writeln!(f, "p95:    {:?}", self.p95)?;
+        write!(f, "Stddev: {:?}", self.stddev)
+    }
+}
+
+/// summary statistics over the fuel consumed by a set of iterations
+struct FuelStats {
+    total: u64,
+    min: u64,
+    max: u64,
+    mean: u64,
+    p50: u64,
+    p95: u64,
+}
+
+impl FuelStats {
+    fn from_fuel(fuel: &mut [u64]) -> Option<Self> {
+        if fuel.is_empty() {
+            return None;
+        }
+        fuel.sort();
+
+        let total = fuel.iter().sum();
+        Some(Self {
+            total,
+            min: fuel[0],
+            max: fuel[fuel.len() - 1],
+            mean: total / fuel.len() as u64,
+            p50: percentile(fuel, 50),
+            p95: percentile(fuel, 95),
+        })
+    }
+}
+
+impl std::fmt::Display for FuelStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Fuel total: {}", self.total)?;
+        writeln!(f, "Fuel min:   {}", self.min)?;
+        writeln!(f, "Fuel max:   {}", self.max)?;
+        writeln!(f, "Fuel mean:  {}", self.mean)?;
+        writeln!(f, "Fuel p50:   {}", self.p50)?;
+        write!(f, "Fuel p95:   {}", self.p95)
+    }
+}
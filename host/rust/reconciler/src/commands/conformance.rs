@@ -0,0 +1,95 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::cli::{ConformanceArgs, RuntimeArgs};
+use crate::conformance::Case;
+use crate::input::walk_dir;
+use crate::store::ObjectStore;
+use crate::{component_name, load_named_instance};
+
+/// run every case against every guest, a fresh instance per case,
+/// and print a matrix of the cases each guest passes
+pub async fn exec(
+    wasm_path: Result<PathBuf>,
+    runtime: &RuntimeArgs,
+    args: ConformanceArgs,
+) -> Result<()> {
+    let guests = if args.guests.is_empty() {
+        let path = wasm_path?;
+        vec![(component_name(&path), path)]
+    } else {
+        args.guests
+    };
+    // the name tells the guests apart in the matrix, metrics and traces
+    for (index, (name, _)) in guests.iter().enumerate() {
+        if guests[..index].iter().any(|(other, _)| other == name) {
+            anyhow::bail!("guest `{name}` is given twice, name them with `--guest <name>=<path>`");
+        }
+    }
+    let cases = load_cases(&args.cases)?;
+    if cases.is_empty() {
+        anyhow::bail!("no conformance cases in {}", args.cases.display());
+    }
+
+    // passed[guest][case]
+    let mut passed = Vec::new();
+    for (name, path) in &guests {
+        let mut instance = load_named_instance(name.clone(), path, ObjectStore::new(), runtime)
+            .await
+            .with_context(|| format!("cannot load guest {}", path.display()))?;
+        let mut results = Vec::new();
+        for case in &cases {
+            *instance.objects_mut() = case.store()?;
+            instance.recreate().await?;
+            let key = case
+                .parent()
+                .map_or_else(|| case.name.clone(), |parent| parent.to_string());
+            let result = instance.call_reconcile(&key, &case.input_json()).await;
+
+            let failures = case.check(&result);
+            let label = match &case.description {
+                Some(description) => format!("{} ({description})", case.name),
+                None => case.name.clone(),
+            };
+            for failure in &failures {
+                eprintln!("{name}: {label}: {failure}");
+            }
+            results.push(failures.is_empty());
+        }
+        passed.push(results);
+    }
+
+    let width = cases.iter().map(|case| case.name.len()).max().unwrap_or(0);
+    print!("{:width$}", "case");
+    for (name, _) in &guests {
+        print!("  {name:>6}");
+    }
+    println!();
+    for (i, case) in cases.iter().enumerate() {
+        print!("{:width$}", case.name);
+        for ((name, _), results) in guests.iter().zip(&passed) {
+            let cell = if results[i] { "pass" } else { "FAIL" };
+            print!("  {cell:>w$}", w = name.len().max(6));
+        }
+        println!();
+    }
+
+    let failed = passed.iter().flatten().filter(|passed| !**passed).count();
+    if failed > 0 {
+        anyhow::bail!(
+            "{failed} of {} case runs failed",
+            cases.len() * guests.len()
+        );
+    }
+    Ok(())
+}
+
+/// the cases in the YAML and JSON files below `dir`, in path order
+fn load_cases(dir: &Path) -> Result<Vec<Case>> {
+    walk_dir(dir)
+        .with_context(|| format!("cannot read conformance cases {}", dir.display()))?
+        .iter()
+        .map(|path| Case::load(path))
+        .collect()
+}
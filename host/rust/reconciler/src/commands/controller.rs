@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+
+use crate::backoff::{ExponentialBackoff, RateLimiter, RetryPolicy, TokenBucket};
+use crate::cli::{ControllerArgs, RuntimeArgs};
+use crate::controller::Controller;
+use crate::input::load_objects;
+use crate::load_reconciler_instance;
+use crate::registry::{Registry, RegistryConfig};
+
+/// seed the controller with the input objects and run its loop,
+/// the component path is only required without `--registry`
+pub async fn exec(
+    wasm_path: Result<PathBuf>,
+    runtime: &RuntimeArgs,
+    args: ControllerArgs,
+) -> Result<()> {
+    let objects = load_objects(&args.input)?;
+
+    let objects_store = super::seed_store(&objects, &args.objects)?;
+
+    let mut registry = match &args.registry {
+        Some(path) => {
+            let config = RegistryConfig::load(path)?;
+            Registry::load(&config, objects_store, runtime)
+                .await
+                .map_err(|e| anyhow::anyhow!("Error loading reconciler registry: {:#}", e))?
+        }
+        None => {
+            let wasm_path = wasm_path?;
+            let instance = load_reconciler_instance(&wasm_path, objects_store, runtime)
+                .await
+                .map_err(|e| anyhow::anyhow!("Error loading reconciler instance: {:#}", e))?;
+            Registry::single(wasm_path, instance)
+        }
+    };
+    if args.watch {
+        registry.watch(runtime);
+    }
+
+    let retry_policy = RetryPolicy {
+        no_retry_codes: args.retry.no_retry_codes,
+        max_retries: args.retry.max_retries,
+    };
+    let rate_limiter = RateLimiter::new(
+        ExponentialBackoff::new(args.retry.backoff_base, args.retry.backoff_max),
+        TokenBucket::new(args.retry.retry_rate, args.retry.retry_burst),
+    );
+
+    let mut controller = Controller::new(registry, retry_policy, rate_limiter);
+    for object in objects {
+        controller.enqueue(object)?;
+    }
+
+    controller.run(args.until_idle).await
+}
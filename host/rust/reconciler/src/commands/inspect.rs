@@ -0,0 +1,48 @@
+use std::path::Path;
+
+use anyhow::Result;
+use wasmtime::component::types::ComponentItem;
+use wasmtime::Engine;
+
+use crate::cli::RuntimeArgs;
+use crate::{load_component, new_engine};
+
+/// print the imports and exports of the component
+pub fn exec(wasm_path: &Path, runtime: &RuntimeArgs) -> Result<()> {
+    let engine = new_engine(runtime, 1)?;
+    let component = load_component(&engine, wasm_path, runtime)?;
+    let ty = component.component_type();
+
+    println!("imports:");
+    for (name, item) in ty.imports(&engine) {
+        print_item(&engine, name, &item, 1);
+    }
+    println!("exports:");
+    for (name, item) in ty.exports(&engine) {
+        print_item(&engine, name, &item, 1);
+    }
+
+    Ok(())
+}
+
+fn print_item(engine: &Engine, name: &str, item: &ComponentItem, depth: usize) {
+    let indent = "  ".repeat(depth);
+    match item {
+        ComponentItem::ComponentFunc(func) => println!(
+            "{indent}{name}: func ({} params, {} results)",
+            func.params().len(),
+            func.results().len()
+        ),
+        ComponentItem::CoreFunc(_) => println!("{indent}{name}: core func"),
+        ComponentItem::Module(_) => println!("{indent}{name}: core module"),
+        ComponentItem::Component(_) => println!("{indent}{name}: component"),
+        ComponentItem::ComponentInstance(instance) => {
+            println!("{indent}{name}: instance");
+            for (name, item) in instance.exports(engine) {
+                print_item(engine, name, &item, depth + 1);
+            }
+        }
+        ComponentItem::Type(_) => println!("{indent}{name}: type"),
+        ComponentItem::Resource(_) => println!("{indent}{name}: resource"),
+    }
+}
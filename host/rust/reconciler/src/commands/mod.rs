@@ -0,0 +1,31 @@
+//! Implementations of the command-line subcommands
+
+pub mod bench;
+pub mod conformance;
+pub mod controller;
+pub mod inspect;
+pub mod precompile;
+pub mod replay;
+pub mod run;
+pub mod test;
+pub mod validate;
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+
+use crate::input::{load_objects, InputObject};
+use crate::store::ObjectStore;
+
+/// build the object store the guest can query from the objects to reconcile
+/// and the additional related objects
+pub(crate) fn seed_store(inputs: &[InputObject], objects: &[PathBuf]) -> Result<ObjectStore> {
+    let mut store = ObjectStore::new();
+    for object in inputs.iter().chain(&load_objects(objects)?) {
+        store
+            .insert(object.value.clone())
+            .with_context(|| format!("cannot add {} to the object store", object.source))?;
+    }
+    tracing::debug!("seeded object store with {} objects", store.len());
+    Ok(store)
+}
@@ -0,0 +1,26 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::cli::{PrecompileArgs, RuntimeArgs};
+use crate::new_engine;
+
+/// compile the component with the engine the other commands use, so the
+/// output only loads with the same `--fuel` and `--fresh-instance` settings
+pub fn exec(wasm_path: &Path, runtime: &RuntimeArgs, args: PrecompileArgs) -> Result<()> {
+    let engine = new_engine(runtime, 1)?;
+    let bytes = std::fs::read(wasm_path)
+        .with_context(|| format!("cannot read component from {}", wasm_path.display()))?;
+    let compiled = engine
+        .precompile_component(&bytes)
+        .with_context(|| format!("cannot compile component {}", wasm_path.display()))?;
+
+    let output = args
+        .output
+        .unwrap_or_else(|| wasm_path.with_extension("cwasm"));
+    std::fs::write(&output, compiled)
+        .with_context(|| format!("cannot write {}", output.display()))?;
+
+    println!("{} -> {}", wasm_path.display(), output.display());
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use std::collections::VecDeque;
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::cli::{ReplayArgs, RuntimeArgs};
+use crate::input::files;
+use crate::load_reconciler_instance;
+use crate::store::ObjectStore;
+use crate::trace::{Imports, Trace};
+
+/// call the component with the input of each trace, a fresh instance per trace,
+/// answering its imports from the recorded calls, and print how the call diverged
+pub async fn exec(wasm_path: &Path, runtime: &RuntimeArgs, args: ReplayArgs) -> Result<()> {
+    let mut paths = files(&args.traces)?;
+    paths.retain(|path| path.extension().is_some_and(|e| e == "json"));
+
+    let mut instance = load_reconciler_instance(wasm_path, ObjectStore::new(), runtime)
+        .await
+        .map_err(|e| anyhow::anyhow!("Error loading reconciler instance: {:#}", e))?;
+
+    let mut diverged = 0;
+    for path in &paths {
+        let trace = Trace::load(path)?;
+        if trace.component != instance.name() {
+            tracing::warn!(
+                trace = %path.display(),
+                "recorded with component {}, replaying with {}",
+                trace.component,
+                instance.name()
+            );
+        }
+
+        *instance.objects_mut() = ObjectStore::new();
+        instance.recreate().await?;
+        instance.set_imports(Imports::Replay {
+            calls: VecDeque::from(trace.calls.clone()),
+            diverged: None,
+        });
+        let result = instance
+            .call_reconcile(&trace.key, &trace.input_json())
+            .await;
+
+        let divergences = trace.divergences(&result, instance.output(), instance.imports());
+        if divergences.is_empty() {
+            eprintln!(
+                "{}: replayed {} import calls",
+                path.display(),
+                trace.calls.len()
+            );
+        } else {
+            diverged += 1;
+            for divergence in divergences {
+                eprintln!("{}: diverged: {divergence}", path.display());
+            }
+        }
+    }
+
+    if diverged > 0 {
+        anyhow::bail!("{diverged} of {} replays diverged", paths.len());
+    }
+    Ok(())
+}
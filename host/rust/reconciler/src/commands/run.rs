@@ -0,0 +1,132 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::cli::{RunArgs, RuntimeArgs};
+use crate::diff::unified_diff;
+use crate::drift::{drift, fingerprint};
+use crate::input::load_objects;
+use crate::store::ObjectRef;
+use crate::{call_reconcile, load_reconciler_instance};
+
+/// reconcile each input object in order and print the object with its new status and the children to stdout,
+/// or with `--dry-run` the diffs of the objects the reconcile would change,
+/// what the guest writes to stdout and stderr is reported with the object,
+/// with `--check-drift` each object is first reconciled once more to compare the two runs
+pub async fn exec(wasm_path: &Path, runtime: &RuntimeArgs, args: RunArgs) -> Result<()> {
+    let objects = load_objects(&args.input)?;
+
+    let objects_store = super::seed_store(&objects, &args.objects)?;
+
+    let mut instance = load_reconciler_instance(wasm_path, objects_store, runtime)
+        .await
+        .map_err(|e| anyhow::anyhow!("Error loading reconciler instance: {:#}", e))?;
+
+    let mut failures = 0;
+    let mut drifted = 0;
+    for object in &objects {
+        // the first of the two runs starts from the same store and a fresh instance
+        // as the second one and is discarded afterwards
+        let first = if args.check_drift {
+            let snapshot = instance.objects().clone();
+            instance.recreate().await?;
+            let result = call_reconcile(&mut instance, &object.value).await;
+            let first = fingerprint(&result, instance.objects(), instance.output());
+            *instance.objects_mut() = snapshot;
+            instance.recreate().await?;
+            Some(first)
+        } else {
+            None
+        };
+
+        // a dry run reconciles against a copy of the store that is thrown away afterwards,
+        // so the guest still reads its own writes
+        let snapshot = args.dry_run.then(|| instance.objects().clone());
+
+        let result = call_reconcile(&mut instance, &object.value).await;
+        if let Some(first) = first {
+            let second = fingerprint(&result, instance.objects(), instance.output());
+            if let Some(diff) = drift(&first, &second) {
+                drifted += 1;
+                eprintln!("{}: reconcile drifted between two runs:", object.source);
+                eprint!("{diff}");
+            }
+        }
+        if let Ok(outcome) = &result {
+            eprintln!("{}: {}", object.source, outcome.plan);
+        }
+        let changes = instance.objects_mut().take_changes();
+        // forwarded output is already in the host log
+        if runtime.guest_output_level.is_none() {
+            for (stream, output) in instance.output().streams() {
+                for line in output.lines() {
+                    eprintln!("{}: {stream}: {line}", object.source);
+                }
+            }
+        }
+
+        if let Some(snapshot) = snapshot {
+            // the stored input is not recorded as a change, always diff it
+            let mut changed: Vec<ObjectRef> =
+                ObjectRef::from_value(&object.value).into_iter().collect();
+            for change in changes {
+                if !changed.contains(&change.object_ref) {
+                    changed.push(change.object_ref);
+                }
+            }
+            for object_ref in &changed {
+                print!(
+                    "{}",
+                    unified_diff(
+                        object_ref,
+                        snapshot.get(object_ref),
+                        instance.objects().get(object_ref)
+                    )
+                );
+            }
+            *instance.objects_mut() = snapshot;
+        } else {
+            for change in changes {
+                eprintln!("{}: {}", object.source, change);
+            }
+        }
+
+        match result {
+            Ok(outcome) => {
+                for event in &outcome.events {
+                    eprintln!("{}: event {}", object.source, event);
+                }
+                if outcome.requeue || outcome.requeue_after > 0 {
+                    eprintln!(
+                        "{}: requeue: {}, requeue after: {}s",
+                        object.source, outcome.requeue, outcome.requeue_after
+                    );
+                }
+                if args.dry_run {
+                    continue;
+                }
+                if outcome.status.is_some() {
+                    let parent = ObjectRef::from_value(&object.value)?;
+                    if let Some(parent) = instance.objects().get(&parent) {
+                        println!("{parent}");
+                    }
+                }
+                for child in &outcome.children {
+                    println!("{child}");
+                }
+            }
+            Err(e) => {
+                failures += 1;
+                eprintln!("{}: reconcile failed: {}", object.source, e);
+            }
+        }
+    }
+
+    if failures > 0 {
+        anyhow::bail!("{failures} of {} reconciles failed", objects.len());
+    }
+    if drifted > 0 {
+        anyhow::bail!("{drifted} of {} reconciles drifted", objects.len());
+    }
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use similar::TextDiff;
+
+use crate::cli::{RuntimeArgs, TestArgs};
+use crate::golden::{golden_path, is_golden, normalize, render, snapshot};
+use crate::input::{files, load_object};
+use crate::load_reconciler_instance;
+use crate::store::{ObjectRef, ObjectStore};
+
+/// reconcile each input with a fresh instance and compare the result with its golden file,
+/// with `--update` the golden files are rewritten instead
+pub async fn exec(wasm_path: &Path, runtime: &RuntimeArgs, args: TestArgs) -> Result<()> {
+    let mut inputs = files(&args.inputs)?;
+    inputs.retain(|input| !is_golden(input));
+    if inputs.is_empty() {
+        anyhow::bail!("no test inputs found");
+    }
+
+    let mut instance = load_reconciler_instance(wasm_path, ObjectStore::new(), runtime)
+        .await
+        .map_err(|e| anyhow::anyhow!("Error loading reconciler instance: {:#}", e))?;
+
+    let mut failures = 0;
+    for input in &inputs {
+        let object = load_object(std::slice::from_ref(input))?;
+        let parent = ObjectRef::from_value(&object.value)
+            .with_context(|| format!("invalid test input {}", input.display()))?;
+        let mut objects = super::seed_store(&[], &args.objects)?;
+        objects.insert(object.value.clone())?;
+        *instance.objects_mut() = objects;
+        instance.recreate().await?;
+
+        let result = instance
+            .call_reconcile(&parent.to_string(), &object.value.to_string())
+            .await;
+        let actual = render(&snapshot(&result));
+
+        let golden = golden_path(input);
+        let expected = match std::fs::read_to_string(&golden) {
+            Ok(content) => {
+                let value = serde_json::from_str(&content)
+                    .with_context(|| format!("invalid golden file {}", golden.display()))?;
+                Some(render(&normalize(value)))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e).with_context(|| format!("cannot read {}", golden.display())),
+        };
+
+        if expected.as_deref() == Some(actual.as_str()) {
+            eprintln!("{}: ok", input.display());
+        } else if args.update {
+            std::fs::write(&golden, &actual)
+                .with_context(|| format!("cannot write {}", golden.display()))?;
+            eprintln!("{}: updated {}", input.display(), golden.display());
+        } else if let Some(expected) = expected {
+            failures += 1;
+            eprintln!("{}: differs from {}", input.display(), golden.display());
+            print!(
+                "{}",
+                TextDiff::from_lines(&expected, &actual)
+                    .unified_diff()
+                    .header(&golden.display().to_string(), "actual")
+            );
+        } else {
+            failures += 1;
+            eprintln!(
+                "{}: no golden file {}, run with --update to create it",
+                input.display(),
+                golden.display()
+            );
+        }
+    }
+
+    if failures > 0 {
+        anyhow::bail!("{failures} of {} tests failed", inputs.len());
+    }
+    Ok(())
+}
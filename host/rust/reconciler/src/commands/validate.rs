@@ -0,0 +1,20 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::cli::RuntimeArgs;
+use crate::instance::link;
+use crate::{load_component, new_engine, new_linker};
+
+/// check that the component only imports interfaces the host provides
+/// and exports everything the `reconciler` world requires
+pub fn exec(wasm_path: &Path, runtime: &RuntimeArgs) -> Result<()> {
+    let engine = new_engine(runtime, 1)?;
+    let component = load_component(&engine, wasm_path, runtime)?;
+    let linker = new_linker(&engine)?;
+
+    link(&linker, &component)?;
+
+    println!("{}: ok", wasm_path.display());
+    Ok(())
+}
@@ -0,0 +1,231 @@
+//! Conformance cases every guest is expected to pass
+//!
+//! A case is a YAML or JSON file with the input of one reconcile call, the
+//! objects in the store and the expected result:
+//!
+//! ```yaml
+//! description: an empty input is a bad request
+//! input: ""                 # a string is passed as is, an object as JSON
+//! objects: []               # related objects in the store
+//! expect:
+//!   error: 400
+//! ```
+//!
+//! Instead of an error code a case can expect `requeue`, `requeueAfter`,
+//! `status`, `children` and `events`, only what is given is compared. The
+//! status and children are compared as JSON, whatever their key order, and
+//! children without a namespace get the namespace of the input as the host
+//! does when it applies them. A successful result always needs a status and
+//! children that are JSON objects.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::bindings::{EventKind, ReconcileError, ReconcileResult};
+use crate::store::{ObjectRef, ObjectStore};
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Case {
+    /// the file stem
+    #[serde(skip)]
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub input: Value,
+    #[serde(default)]
+    pub objects: Vec<Value>,
+    pub expect: Expect,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct Expect {
+    /// the error code, the case expects a failed reconcile
+    pub error: Option<u32>,
+    pub requeue: Option<bool>,
+    pub requeue_after: Option<u32>,
+    pub status: Option<Value>,
+    pub children: Option<Vec<Value>>,
+    pub events: Option<Vec<ExpectedEvent>>,
+}
+
+/// an event of the result, only the given fields are compared
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExpectedEvent {
+    pub kind: Option<EventKind>,
+    pub reason: Option<String>,
+    pub message: Option<String>,
+}
+
+impl Case {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("cannot read case {}", path.display()))?;
+        let mut case: Self = serde_yaml::from_str(&content)
+            .with_context(|| format!("invalid case {}", path.display()))?;
+        case.name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Ok(case)
+    }
+
+    /// the argument of the reconcile call
+    pub fn input_json(&self) -> String {
+        match &self.input {
+            Value::String(input) => input.clone(),
+            input => input.to_string(),
+        }
+    }
+
+    /// the reconciled object, none when the input is not an object
+    pub fn parent(&self) -> Option<ObjectRef> {
+        ObjectRef::from_value(&self.input).ok()
+    }
+
+    /// the related objects and the input object
+    pub fn store(&self) -> Result<ObjectStore> {
+        let mut store = ObjectStore::new();
+        for object in &self.objects {
+            store
+                .insert(object.clone())
+                .with_context(|| format!("cannot add an object of case {}", self.name))?;
+        }
+        if self.parent().is_some() {
+            store.insert(self.input.clone())?;
+        }
+        Ok(store)
+    }
+
+    /// why a result does not meet the expectation, empty when it does
+    pub fn check(&self, result: &Result<ReconcileResult, ReconcileError>) -> Vec<String> {
+        let expect = &self.expect;
+        let result = match (result, expect.error) {
+            (Err(e), Some(code)) if e.code == code => return Vec::new(),
+            (Err(e), Some(code)) => {
+                return vec![format!(
+                    "expected error {code}, got error {}: {}",
+                    e.code, e.message
+                )]
+            }
+            (Err(e), None) => return vec![format!("failed with {}: {}", e.code, e.message)],
+            (Ok(_), Some(code)) => return vec![format!("expected error {code}, got a result")],
+            (Ok(result), None) => result,
+        };
+
+        let mut failures = Vec::new();
+        if let Some(requeue) = expect.requeue.filter(|requeue| *requeue != result.requeue) {
+            failures.push(format!(
+                "expected requeue {requeue}, got {}",
+                result.requeue
+            ));
+        }
+        if let Some(after) = expect
+            .requeue_after
+            .filter(|after| *after != result.requeue_after)
+        {
+            failures.push(format!(
+                "expected requeue after {after}s, got {}s",
+                result.requeue_after
+            ));
+        }
+
+        let status = match result.status.as_deref().map(parse_object) {
+            Some(Ok(status)) => Some(status),
+            Some(Err(e)) => {
+                failures.push(format!("status {e}"));
+                None
+            }
+            None => None,
+        };
+        if let Some(expected) = &expect.status {
+            if status.as_ref() != Some(expected) {
+                failures.push(format!(
+                    "expected status {expected}, got {}",
+                    result.status.as_deref().unwrap_or("none")
+                ));
+            }
+        }
+
+        let mut children = Vec::new();
+        for (i, child) in result.children.iter().enumerate() {
+            match parse_object(child).and_then(|child| self.with_namespace(child)) {
+                Ok(child) => children.push(child),
+                Err(e) => failures.push(format!("child {i} {e}")),
+            }
+        }
+        if let Some(expected) = &expect.children {
+            let mut valid = Vec::new();
+            for (i, child) in expected.iter().enumerate() {
+                match self.with_namespace(child.clone()) {
+                    Ok(child) => valid.push(child),
+                    Err(e) => failures.push(format!("expected child {i} {e}")),
+                }
+            }
+            let expected = valid;
+            for child in &expected {
+                if !children.contains(child) {
+                    failures.push(format!("missing child {child}"));
+                }
+            }
+            for child in &children {
+                if !expected.contains(child) {
+                    failures.push(format!("unexpected child {child}"));
+                }
+            }
+        }
+
+        if let Some(expected) = &expect.events {
+            let matches = expected.len() == result.events.len()
+                && expected
+                    .iter()
+                    .zip(&result.events)
+                    .all(|(expected, event)| {
+                        expected.kind.is_none_or(|kind| kind == event.kind)
+                            && expected
+                                .reason
+                                .as_ref()
+                                .is_none_or(|reason| *reason == event.reason)
+                            && expected
+                                .message
+                                .as_ref()
+                                .is_none_or(|message| *message == event.message)
+                    });
+            if !matches {
+                let events: Vec<String> = result.events.iter().map(ToString::to_string).collect();
+                failures.push(format!(
+                    "expected {} events, got [{}]",
+                    expected.len(),
+                    events.join(", ")
+                ));
+            }
+        }
+        failures
+    }
+
+    /// give an object without a namespace the namespace of the input, as the host does
+    fn with_namespace(&self, mut object: Value) -> Result<Value, String> {
+        let Some(metadata) = object.get_mut("metadata").and_then(Value::as_object_mut) else {
+            return Err("metadata is not a JSON object".to_string());
+        };
+        if metadata.get("namespace").is_none_or(Value::is_null) {
+            if let Some(namespace) = self.parent().and_then(|parent| parent.namespace) {
+                metadata.insert("namespace".to_string(), Value::String(namespace));
+            }
+        }
+        Ok(object)
+    }
+}
+
+fn parse_object(json: &str) -> Result<Value, String> {
+    match serde_json::from_str(json) {
+        Ok(object @ Value::Object(_)) => Ok(object),
+        Ok(_) => Err("is not a JSON object".to_string()),
+        Err(e) => Err(format!("is not valid JSON: {e}")),
+    }
+}
@@ -0,0 +1,208 @@
+//! A controller loop that reconciles objects from a work queue and honors the
+//! `requeue` and `requeue-after` fields of the reconcile result
+//!
+//! Objects are reconciled by the component registered for their kind. A write
+//! to an object of a registered kind queues that object too, the way a
+//! controller watching the kind would see it.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+
+use crate::backoff::{RateLimiter, RetryPolicy};
+use crate::bindings::EventKind;
+use crate::input::InputObject;
+use crate::queue::WorkQueue;
+use crate::registry::{GroupVersionKind, Registry};
+use crate::store::{Change, ObjectRef, Operation};
+use crate::ReconcileError;
+
+pub struct Controller {
+    registry: Registry,
+    queue: WorkQueue<ObjectRef>,
+    objects: HashMap<ObjectRef, InputObject>,
+    retry_policy: RetryPolicy,
+    rate_limiter: RateLimiter<ObjectRef>,
+}
+
+impl Controller {
+    pub fn new(
+        registry: Registry,
+        retry_policy: RetryPolicy,
+        rate_limiter: RateLimiter<ObjectRef>,
+    ) -> Self {
+        Self {
+            registry,
+            queue: WorkQueue::new(),
+            objects: HashMap::new(),
+            retry_policy,
+            rate_limiter,
+        }
+    }
+
+    /// track the object and queue it for reconciliation,
+    /// a later object with the same key replaces the earlier one
+    pub fn enqueue(&mut self, object: InputObject) -> Result<()> {
+        let key = ObjectRef::from_value(&object.value)
+            .with_context(|| format!("{}: invalid object", object.source))?;
+        self.objects.insert(key.clone(), object);
+        self.queue.add(key);
+        Ok(())
+    }
+
+    /// process the queue until it is empty when `until_idle` is set,
+    /// otherwise until ctrl-c is received
+    pub async fn run(&mut self, until_idle: bool) -> Result<()> {
+        // one future for the whole run, once polled the handler is installed and
+        // ctrl-c no longer terminates the process, so every wait must select on it
+        let ctrl_c = tokio::signal::ctrl_c();
+        tokio::pin!(ctrl_c);
+
+        loop {
+            if let Some(key) = self.queue.pop() {
+                self.reconcile(key).await;
+                // a guest that always requeues keeps the queue ready, check for
+                // ctrl-c and yield to the runtime before the next reconcile
+                tokio::select! {
+                    biased;
+                    result = &mut ctrl_c => {
+                        result?;
+                        tracing::info!("received ctrl-c, stopping");
+                        return Ok(());
+                    }
+                    _ = tokio::task::yield_now() => {}
+                }
+                continue;
+            }
+
+            let deadline = match self.queue.next_deadline() {
+                Some(deadline) => deadline,
+                None if until_idle => {
+                    tracing::info!("queue is empty, stopping");
+                    return Ok(());
+                }
+                None => {
+                    tracing::info!("queue is empty, waiting for ctrl-c");
+                    (&mut ctrl_c).await?;
+                    return Ok(());
+                }
+            };
+
+            tokio::select! {
+                _ = tokio::time::sleep_until(deadline) => {}
+                result = &mut ctrl_c => {
+                    result?;
+                    tracing::info!("received ctrl-c, stopping");
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    async fn reconcile(&mut self, key: ObjectRef) {
+        self.registry.apply_reloads().await;
+        let Some(object) = self.objects.get(&key) else {
+            return;
+        };
+
+        let result = self.registry.reconcile(&object.value).await;
+        for change in self.registry.objects_mut().take_changes() {
+            tracing::info!(%key, "{change}");
+            self.dispatch(&key, &change);
+        }
+        if let Ok(outcome) = &result {
+            for line in outcome.plan.to_string().lines() {
+                tracing::info!(%key, "{line}");
+            }
+            for event in &outcome.events {
+                match event.kind {
+                    EventKind::Normal => {
+                        tracing::info!(%key, reason = event.reason, "{}", event.message)
+                    }
+                    EventKind::Warning => {
+                        tracing::warn!(%key, reason = event.reason, "{}", event.message)
+                    }
+                }
+            }
+        }
+        if result.is_ok() {
+            self.rate_limiter.forget(&key);
+        }
+
+        match result {
+            Ok(result) if result.requeue => {
+                tracing::info!(%key, "reconciled, requeue");
+                self.queue.add(key);
+            }
+            Ok(result) if result.requeue_after > 0 => {
+                tracing::info!(%key, requeue_after = result.requeue_after, "reconciled, requeue after");
+                self.queue
+                    .add_after(key, Duration::from_secs(result.requeue_after.into()));
+            }
+            Ok(_) => tracing::info!(%key, "reconciled"),
+            Err(e) => self.retry(key, e),
+        }
+    }
+
+    /// queue an object written by the reconcile of `key` if a component is registered
+    /// for its kind, or forget it when it was deleted
+    fn dispatch(&mut self, key: &ObjectRef, change: &Change) {
+        let object_ref = &change.object_ref;
+        if object_ref == key
+            || !self
+                .registry
+                .is_registered(&GroupVersionKind::from(object_ref))
+        {
+            return;
+        }
+        if change.operation == Operation::Delete {
+            self.objects.remove(object_ref);
+            return;
+        }
+        let Some(value) = self.registry.objects().get(object_ref) else {
+            return;
+        };
+        // the status belongs to the reconciler of the object, the stored one is kept
+        let mut value = value.clone();
+        if let Some(fields) = value.as_object_mut() {
+            fields.remove("status");
+        }
+        self.objects.insert(
+            object_ref.clone(),
+            InputObject {
+                source: object_ref.to_string(),
+                value,
+            },
+        );
+        self.queue.add(object_ref.clone());
+    }
+
+    /// requeue a failed object with backoff, unless its error is not retryable
+    /// or it ran out of retries
+    fn retry(&mut self, key: ObjectRef, e: ReconcileError) {
+        if !self.retry_policy.is_retryable(e.code) {
+            tracing::error!(%key, code = e.code, "reconcile failed, not retrying: {}", e.message);
+            self.rate_limiter.forget(&key);
+            return;
+        }
+
+        let retries = self.rate_limiter.retries(&key);
+        if retries >= self.retry_policy.max_retries {
+            tracing::error!(%key, code = e.code, retries, "reconcile failed, giving up: {}", e.message);
+            self.rate_limiter.forget(&key);
+            return;
+        }
+
+        let delay = self.rate_limiter.when(&key);
+        tracing::warn!(
+            %key,
+            code = e.code,
+            retry = retries + 1,
+            ?delay,
+            "reconcile failed, retrying: {}",
+            e.message
+        );
+        self.queue.add_after(key, delay);
+    }
+}
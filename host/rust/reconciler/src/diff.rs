@@ -0,0 +1,42 @@
+//! Unified diffs of stored objects, used to preview the writes of a dry run
+
+use serde_json::Value;
+use similar::TextDiff;
+
+use crate::store::ObjectRef;
+
+/// a unified diff of the pretty-printed object before and after a reconcile,
+/// empty when nothing changed
+///
+/// the resource version is left out since a dry run never commits it
+pub fn unified_diff(
+    object_ref: &ObjectRef,
+    before: Option<&Value>,
+    after: Option<&Value>,
+) -> String {
+    let (old, new) = (render(before), render(after));
+    if old == new {
+        return String::new();
+    }
+    let header = |object: Option<&Value>, side: &str| match object {
+        Some(_) => format!("{side}/{object_ref}"),
+        None => "/dev/null".to_string(),
+    };
+    TextDiff::from_lines(&old, &new)
+        .unified_diff()
+        .header(&header(before, "a"), &header(after, "b"))
+        .to_string()
+}
+
+fn render(object: Option<&Value>) -> String {
+    let Some(object) = object else {
+        return String::new();
+    };
+    let mut object = object.clone();
+    if let Some(metadata) = object["metadata"].as_object_mut() {
+        metadata.remove("resourceVersion");
+    }
+    let mut rendered = serde_json::to_string_pretty(&object).expect("a JSON value serializes");
+    rendered.push('\n');
+    rendered
+}
@@ -0,0 +1,65 @@
+//! Drift check of a reconcile
+//!
+//! With `run --check-drift` every object is reconciled twice by a fresh
+//! instance against the same object store. Both runs are rendered as text,
+//! the result, the store afterwards and what the guest wrote, and any
+//! difference between them is drift: the guest depends on something besides
+//! its input, such as the real time or random numbers.
+
+use std::fmt::Write;
+
+use similar::TextDiff;
+
+use crate::bindings::ReconcileError;
+use crate::outcome::Outcome;
+use crate::output::GuestOutput;
+use crate::store::ObjectStore;
+
+/// everything a reconcile produced, one item per line
+pub fn fingerprint(
+    result: &Result<Outcome, ReconcileError>,
+    objects: &ObjectStore,
+    output: &GuestOutput,
+) -> String {
+    let mut rendered = String::new();
+    match result {
+        Ok(outcome) => {
+            let _ = writeln!(
+                rendered,
+                "requeue: {}, requeue after: {}s",
+                outcome.requeue, outcome.requeue_after
+            );
+            if let Some(status) = &outcome.status {
+                let _ = writeln!(rendered, "status: {status}");
+            }
+            for child in &outcome.children {
+                let _ = writeln!(rendered, "child: {child}");
+            }
+            for event in &outcome.events {
+                let _ = writeln!(rendered, "event: {event}");
+            }
+        }
+        Err(e) => {
+            let _ = writeln!(rendered, "error {}: {}", e.code, e.message);
+        }
+    }
+    for (_, object) in objects.iter() {
+        let _ = writeln!(rendered, "object: {object}");
+    }
+    for (stream, text) in output.streams() {
+        for line in text.lines() {
+            let _ = writeln!(rendered, "{stream}: {line}");
+        }
+    }
+    rendered
+}
+
+/// a unified diff of two fingerprints, none when they are the same
+pub fn drift(first: &str, second: &str) -> Option<String> {
+    (first != second).then(|| {
+        TextDiff::from_lines(first, second)
+            .unified_diff()
+            .header("first", "second")
+            .to_string()
+    })
+}
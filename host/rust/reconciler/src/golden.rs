@@ -0,0 +1,159 @@
+//! Golden files of reconcile results
+//!
+//! The result of a reconcile is rendered as a JSON document: the status and
+//! children are parsed, so a golden file shows them as objects, and keys are
+//! sorted at every level. Golden files are normalized the same way before they
+//! are compared, so their key order and whitespace do not matter. A status or
+//! child that is not valid JSON is kept as a string.
+
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Map, Value};
+
+use crate::bindings::{ReconcileError, ReconcileResult};
+
+/// the golden file of an input, `<stem>.golden.json` next to it
+pub fn golden_path(input: &Path) -> PathBuf {
+    let stem = input.file_stem().unwrap_or_default().to_string_lossy();
+    input.with_file_name(format!("{stem}.golden.json"))
+}
+
+/// whether a file is a golden file rather than an input
+pub fn is_golden(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name.to_string_lossy().ends_with(".golden.json"))
+}
+
+/// the result as a normalized JSON document
+pub fn snapshot(result: &Result<ReconcileResult, ReconcileError>) -> Value {
+    let snapshot = match result {
+        Ok(result) => json!({
+            "requeue": result.requeue,
+            "requeueAfter": result.requeue_after,
+            "status": result.status.as_deref().map(parse),
+            "children": result.children.iter().map(|child| parse(child)).collect::<Vec<_>>(),
+            "events": result.events.iter().map(|event| json!({
+                "kind": event.kind.to_string(),
+                "reason": event.reason,
+                "message": event.message,
+            })).collect::<Vec<_>>(),
+        }),
+        Err(e) => json!({
+            "error": {
+                "code": e.code,
+                "message": e.message,
+            }
+        }),
+    };
+    normalize(snapshot)
+}
+
+/// sort the keys of every object
+pub fn normalize(value: Value) -> Value {
+    match value {
+        Value::Object(object) => {
+            let mut entries: Vec<(String, Value)> = object.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, normalize(value)))
+                    .collect::<Map<_, _>>(),
+            )
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(normalize).collect()),
+        value => value,
+    }
+}
+
+/// pretty-printed with a trailing newline, the content of a golden file
+pub fn render(value: &Value) -> String {
+    let mut rendered = serde_json::to_string_pretty(value).expect("a JSON value serializes");
+    rendered.push('\n');
+    rendered
+}
+
+fn parse(json: &str) -> Value {
+    serde_json::from_str(json).unwrap_or_else(|_| Value::String(json.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::bindings::{Event, EventKind};
+
+    #[test]
+    fn normalize_sorts_keys_at_every_level() {
+        let value: Value =
+            serde_json::from_str(r#"{"b": {"d": 1, "c": [{"f": 2, "e": 3}]}, "a": null}"#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&normalize(value)).unwrap(),
+            r#"{"a":null,"b":{"c":[{"e":3,"f":2}],"d":1}}"#
+        );
+    }
+
+    #[test]
+    fn golden_files_compare_regardless_of_key_order_and_whitespace() {
+        let result = Ok(ReconcileResult {
+            requeue: true,
+            requeue_after: 30,
+            status: Some(r#"{"ready": true, "nodes": 1}"#.to_string()),
+            children: vec![r#"{"metadata": {"name": "node1"}, "kind": "Node"}"#.to_string()],
+            events: vec![Event {
+                kind: EventKind::Normal,
+                reason: "NodesCreated".to_string(),
+                message: "created 1 node".to_string(),
+            }],
+        });
+        let golden = r#"{
+            "status": {"nodes": 1, "ready": true},
+            "requeueAfter": 30, "requeue": true,
+            "events": [{"reason": "NodesCreated", "message": "created 1 node", "kind": "Normal"}],
+            "children": [{"kind": "Node", "metadata": {"name": "node1"}}]
+        }"#;
+
+        let snapshot = snapshot(&result);
+        assert_eq!(
+            render(&snapshot),
+            render(&normalize(serde_json::from_str(golden).unwrap()))
+        );
+        assert_eq!(
+            serde_json::to_string(&snapshot).unwrap(),
+            r#"{"children":[{"kind":"Node","metadata":{"name":"node1"}}],"events":[{"kind":"Normal","message":"created 1 node","reason":"NodesCreated"}],"requeue":true,"requeueAfter":30,"status":{"nodes":1,"ready":true}}"#
+        );
+    }
+
+    #[test]
+    fn invalid_json_is_kept_as_a_string() {
+        let result = Ok(ReconcileResult {
+            requeue: false,
+            requeue_after: 0,
+            status: Some("not json".to_string()),
+            children: Vec::new(),
+            events: Vec::new(),
+        });
+        assert_eq!(snapshot(&result)["status"], json!("not json"));
+    }
+
+    #[test]
+    fn errors_are_snapshotted() {
+        let result = Err(ReconcileError {
+            code: 404,
+            message: "not found".to_string(),
+        });
+        assert_eq!(
+            snapshot(&result),
+            json!({"error": {"code": 404, "message": "not found"}})
+        );
+    }
+
+    #[test]
+    fn golden_path_is_next_to_the_input() {
+        let golden = golden_path(Path::new("tests/topology.yaml"));
+        assert_eq!(golden, Path::new("tests/topology.golden.json"));
+        assert!(is_golden(&golden));
+        assert!(!is_golden(Path::new("tests/topology.yaml")));
+    }
+}
@@ -0,0 +1,237 @@
+use core::fmt::{self, Debug};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use wasmtime_wasi::async_trait;
+use wasmtime_wasi::{ResourceTable, WasiCtx, WasiView};
+
+use crate::bindings::example::reconciler::logging::{self, Level};
+use crate::bindings::example::reconciler::retrieve::{self, LookupError};
+use crate::bindings::example::reconciler::writer::{self, WriteError};
+use crate::limits::Limits;
+use crate::output::OutputPipe;
+use crate::policy::WasiPolicy;
+use crate::selector::LabelSelector;
+use crate::store::{ObjectRef, ObjectStore, StoreError};
+use crate::trace::Imports;
+
+/// This state is used by the Runtime host,
+/// we use it to store the WASI context (implementations of WASI)
+/// and resource tables that components will use when executing
+///
+/// see:
+/// - https://docs.rs/wasmtime-wasi/latest/wasmtime_wasi/trait.WasiView.html
+/// - https://docs.rs/wasmtime-wasi/latest/wasmtime_wasi/fn.add_to_linker_sync.html
+pub struct Ctx {
+    wasi: WasiCtx,
+    table: ResourceTable,
+    /// objects the guest can look up through the `retrieve` interface
+    pub objects: ObjectStore,
+    /// caps enforced through the `ResourceLimiter` impl in `limits`
+    pub limits: Limits,
+    /// guest stdout and stderr, drained after every call, see `output`
+    pub stdout: OutputPipe,
+    pub stderr: OutputPipe,
+    /// whether import calls are recorded or replayed, see `trace`
+    pub imports: Imports,
+}
+
+impl Ctx {
+    /// the guest gets the capabilities granted by `policy` and nothing else
+    pub fn new(objects: ObjectStore, limits: Limits, policy: &WasiPolicy) -> anyhow::Result<Self> {
+        let (stdout, stderr) = (OutputPipe::default(), OutputPipe::default());
+        let mut builder = WasiCtx::builder();
+        builder.stdout(stdout.clone()).stderr(stderr.clone());
+        policy.apply(&mut builder)?;
+        Ok(Self {
+            wasi: builder.build(),
+            table: ResourceTable::new(),
+            objects,
+            limits,
+            stdout,
+            stderr,
+            imports: Imports::default(),
+        })
+    }
+
+    /// answer an import call with `live` unless it is replayed, recording it if requested
+    fn traced<T: Serialize + DeserializeOwned>(
+        &mut self,
+        function: &str,
+        args: Value,
+        live: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        if let Some(result) = self.imports.replay(function, &args) {
+            return result;
+        }
+        let result = live(self);
+        self.imports.record(function, args, &result);
+        result
+    }
+}
+
+// NOTE: you need to use async_trait mostly because that's what the bindings use
+//
+// Unfortunately auto-complete/ cargo-expand will show you a trait *after* async_trait
+// has had it's way with it -- so that's why you get obtusely named lifetimes and hard
+// to read code for humans.
+#[async_trait]
+impl retrieve::Host for Ctx {
+    async fn get(&mut self, reference: retrieve::ObjectRef) -> Result<String, LookupError> {
+        let args = json!({ "reference": reference });
+        self.traced("retrieve.get", args, |ctx| {
+            ctx.objects
+                .get(&ObjectRef::from(reference))
+                .map(|object| object.to_string())
+                .ok_or(LookupError::NotFound)
+        })
+    }
+
+    async fn list_objects(
+        &mut self,
+        kind: String,
+        namespace: Option<String>,
+        label_selector: String,
+    ) -> Result<Vec<String>, LookupError> {
+        let args = json!({
+            "kind": kind,
+            "namespace": namespace,
+            "label_selector": label_selector,
+        });
+        self.traced("retrieve.list_objects", args, |ctx| {
+            let selector = LabelSelector::parse(&label_selector)
+                .map_err(|e| LookupError::Invalid(e.to_string()))?;
+            Ok(ctx
+                .objects
+                .list(&kind, namespace.as_deref(), &selector)
+                .into_iter()
+                .map(|object| object.to_string())
+                .collect())
+        })
+    }
+}
+
+#[async_trait]
+impl writer::Host for Ctx {
+    async fn create(&mut self, object: String) -> Result<String, WriteError> {
+        let args = json!({ "object": object });
+        self.traced("writer.create", args, |ctx| {
+            let object = parse_object(&object)?;
+            Ok(ctx.objects.create(object)?.to_string())
+        })
+    }
+
+    async fn update(&mut self, object: String) -> Result<String, WriteError> {
+        let args = json!({ "object": object });
+        self.traced("writer.update", args, |ctx| {
+            let object = parse_object(&object)?;
+            Ok(ctx.objects.update(object)?.to_string())
+        })
+    }
+
+    async fn patch(
+        &mut self,
+        reference: retrieve::ObjectRef,
+        patch: String,
+    ) -> Result<String, WriteError> {
+        let args = json!({ "reference": reference, "patch": patch });
+        self.traced("writer.patch", args, |ctx| {
+            let patch = parse_object(&patch)?;
+            Ok(ctx
+                .objects
+                .patch(&ObjectRef::from(reference), &patch)?
+                .to_string())
+        })
+    }
+
+    async fn delete(
+        &mut self,
+        reference: retrieve::ObjectRef,
+        resource_version: Option<String>,
+    ) -> Result<(), WriteError> {
+        let args = json!({ "reference": reference, "resource_version": resource_version });
+        self.traced("writer.delete", args, |ctx| {
+            ctx.objects
+                .delete(&ObjectRef::from(reference), resource_version.as_deref())?;
+            Ok(())
+        })
+    }
+}
+
+/// emit a guest log record with the `guest` target, inside the span of the reconcile call
+macro_rules! guest_event {
+    ($level:expr, $($arg:tt)+) => {
+        match $level {
+            Level::Trace => tracing::trace!(target: "guest", $($arg)+),
+            Level::Debug => tracing::debug!(target: "guest", $($arg)+),
+            Level::Info => tracing::info!(target: "guest", $($arg)+),
+            Level::Warn => tracing::warn!(target: "guest", $($arg)+),
+            Level::Error => tracing::error!(target: "guest", $($arg)+),
+        }
+    };
+}
+
+#[async_trait]
+impl logging::Host for Ctx {
+    async fn log(&mut self, level: Level, message: String, fields: Vec<logging::Field>) {
+        let args = json!({ "level": level, "message": message, "fields": fields });
+        self.traced("logging.log", args, |_| ());
+        // field names are dynamic, they are recorded as one JSON object
+        let fields = (!fields.is_empty()).then(|| {
+            let fields: serde_json::Map<_, _> = fields
+                .into_iter()
+                .map(|field| (field.key, serde_json::Value::String(field.value)))
+                .collect();
+            serde_json::Value::Object(fields).to_string()
+        });
+        guest_event!(
+            level,
+            fields = fields.as_deref().map(tracing::field::display),
+            "{message}"
+        );
+    }
+}
+
+fn parse_object(json: &str) -> Result<serde_json::Value, WriteError> {
+    serde_json::from_str(json).map_err(|e| WriteError::Invalid(format!("invalid JSON: {e}")))
+}
+
+impl From<StoreError> for WriteError {
+    fn from(e: StoreError) -> Self {
+        match e {
+            StoreError::NotFound(_) => Self::NotFound,
+            StoreError::AlreadyExists(_) => Self::AlreadyExists,
+            StoreError::Conflict(msg) => Self::Conflict(msg),
+            StoreError::Invalid(msg) => Self::Invalid(msg),
+        }
+    }
+}
+
+impl From<retrieve::ObjectRef> for ObjectRef {
+    fn from(reference: retrieve::ObjectRef) -> Self {
+        Self {
+            api_version: reference.api_version,
+            kind: reference.kind,
+            namespace: reference.namespace,
+            name: reference.name,
+        }
+    }
+}
+
+impl WasiView for Ctx {
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+
+    fn ctx(&mut self) -> &mut WasiCtx {
+        &mut self.wasi
+    }
+}
+
+impl Debug for Ctx {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ctx").field("runtime", &"wasmtime").finish()
+    }
+}
@@ -0,0 +1,147 @@
+//! Loading of objects to reconcile from files, directories and stdin
+//!
+//! Objects can be written as JSON or YAML. YAML files may hold several objects
+//! separated by `---`, the way Kubernetes manifests usually are. Every object is
+//! converted to the compact JSON string that is handed to the guest.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// file extensions picked up when loading a directory
+const EXTENSIONS: &[&str] = &["json", "yaml", "yml"];
+
+/// an object loaded from an input source
+#[derive(Debug, Clone)]
+pub struct InputObject {
+    /// where the object was read from, used in messages
+    pub source: String,
+    pub value: Value,
+}
+
+/// load all objects from the given paths, `-` reads from stdin
+///
+/// directories are walked recursively in file name order and only files
+/// with a `.json`, `.yaml` or `.yml` extension are loaded
+pub fn load_objects(paths: &[PathBuf]) -> Result<Vec<InputObject>> {
+    let mut objects = Vec::new();
+    for path in paths {
+        if path == Path::new("-") {
+            let mut content = String::new();
+            std::io::stdin()
+                .read_to_string(&mut content)
+                .context("cannot read input from stdin")?;
+            objects.extend(parse_documents("<stdin>", &content, Format::Yaml)?);
+        } else if path.is_dir() {
+            for file in walk_dir(path)? {
+                objects.extend(load_file(&file)?);
+            }
+        } else {
+            objects.extend(load_file(path)?);
+        }
+    }
+    Ok(objects)
+}
+
+/// load exactly one object from the given paths
+pub fn load_object(paths: &[PathBuf]) -> Result<InputObject> {
+    let mut objects = load_objects(paths)?;
+    match objects.len() {
+        1 => Ok(objects.remove(0)),
+        0 => bail!("no object found in input"),
+        n => bail!("expected a single object in input, found {n}"),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Format {
+    Json,
+    Yaml,
+}
+
+fn load_file(path: &Path) -> Result<Vec<InputObject>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("cannot read input {}", path.display()))?;
+    let format = match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => Format::Json,
+        _ => Format::Yaml,
+    };
+    parse_documents(&path.display().to_string(), &content, format)
+}
+
+/// parse every document in `content`, skipping empty YAML documents
+fn parse_documents(source: &str, content: &str, format: Format) -> Result<Vec<InputObject>> {
+    let mut objects = Vec::new();
+    match format {
+        Format::Json => {
+            for (i, value) in serde_json::Deserializer::from_str(content)
+                .into_iter::<Value>()
+                .enumerate()
+            {
+                let value = value.with_context(|| format!("invalid JSON in {source}"))?;
+                objects.push(new_object(source, i, value)?);
+            }
+        }
+        Format::Yaml => {
+            for (i, document) in serde_yaml::Deserializer::from_str(content).enumerate() {
+                let value = Value::deserialize(document)
+                    .with_context(|| format!("invalid YAML in {source} (document {})", i + 1))?;
+                if value.is_null() {
+                    continue;
+                }
+                objects.push(new_object(source, i, value)?);
+            }
+        }
+    }
+    Ok(objects)
+}
+
+fn new_object(source: &str, index: usize, value: Value) -> Result<InputObject> {
+    if !value.is_object() {
+        bail!("{source} (document {}) is not an object", index + 1);
+    }
+    Ok(InputObject {
+        source: format!("{source}#{}", index + 1),
+        value,
+    })
+}
+
+/// the given files and the loadable files below the given directories
+pub(crate) fn files(paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            files.extend(walk_dir(path)?);
+        } else {
+            files.push(path.clone());
+        }
+    }
+    Ok(files)
+}
+
+/// list the loadable files below `dir`, sorted by path
+pub(crate) fn walk_dir(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut entries = std::fs::read_dir(dir)
+        .with_context(|| format!("cannot read directory {}", dir.display()))?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<std::io::Result<Vec<_>>>()
+        .with_context(|| format!("cannot read directory {}", dir.display()))?;
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            files.extend(walk_dir(&path)?);
+        } else if path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| EXTENSIONS.contains(&e))
+        {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
@@ -0,0 +1,496 @@
+//! A reconciler instance whose calls are bounded by a deadline
+//!
+//! The engine is configured for epoch interruption and a background thread
+//! increments its epoch every [`EPOCH_TICK`]. Before each call the store gets a
+//! deadline of enough ticks to cover the timeout, a guest that is still running
+//! when the deadline passes traps. A trapped instance cannot be called again, so
+//! it is discarded and a new one is instantiated around the same object store.
+//!
+//! With a fuel budget the engine also meters the instructions a guest executes,
+//! each call gets the full budget and traps once it is used up. Unlike elapsed
+//! time, fuel is the same on every machine.
+//!
+//! Memory, table and instance caps are enforced by the store limiter, see `limits`.
+//!
+//! Guest stdout and stderr are captured per call, see `output`, and with a
+//! record directory every call is written to a trace, see `trace`.
+//!
+//! By default one instance serves every call, so guest state carries over from
+//! one reconcile to the next. In fresh mode each call gets a new instance from
+//! the pre-linked component and only the object store is carried over, the
+//! engine then uses the pooling allocator to keep instantiation cheap.
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use tracing::{Instrument, Level};
+use wasmtime::component::{Component, Linker};
+use wasmtime::{Engine, Store, Trap};
+
+use crate::bindings::{ReconcileError, ReconcileResult, Reconciler, ReconcilerPre};
+use crate::host::Ctx;
+use crate::limits::{instances_exceeded, LimitExceeded, Limits};
+use crate::output::GuestOutput;
+use crate::policy::WasiPolicy;
+use crate::store::ObjectStore;
+use crate::trace::{Imports, Trace};
+
+/// interval at which the engine epoch is incremented, the granularity of timeouts
+pub const EPOCH_TICK: Duration = Duration::from_millis(10);
+
+/// the reconcile error code of a call that ran past its deadline
+pub const TIMEOUT: u32 = 504;
+
+/// the reconcile error code of a call that used up its fuel budget
+pub const OUT_OF_FUEL: u32 = 509;
+
+/// the reconcile error code of a call that grew past one of the [`Limits`]
+pub const LIMIT_EXCEEDED: u32 = 507;
+
+/// bounds on the guest applied to every call
+#[derive(Debug, Clone)]
+pub struct InstanceConfig {
+    pub timeout: Duration,
+    /// fuel budget of a call, none when fuel is not metered
+    pub fuel: Option<u64>,
+    pub limits: Limits,
+    /// instantiate a fresh instance for every call
+    pub fresh: bool,
+    /// host log level guest output is forwarded at, none to only capture it
+    pub output_level: Option<Level>,
+    /// WASI capabilities of the guest
+    pub policy: Arc<WasiPolicy>,
+    /// directory every call is recorded to, none to not record
+    pub record: Option<PathBuf>,
+}
+
+pub struct ReconcilerInstance {
+    /// the component in logs and metrics
+    name: String,
+    pre: ReconcilerPre<Ctx>,
+    store: Store<Ctx>,
+    reconciler: Reconciler,
+    config: InstanceConfig,
+    /// whether the current instance has been called
+    called: bool,
+    /// fuel used by the last call
+    fuel_consumed: Option<u64>,
+    /// time spent instantiating by the last call, or by `new` before the first call
+    instantiation: Option<Duration>,
+    /// number of calls, across recreated instances
+    iterations: u64,
+    /// stdout and stderr of the last call
+    output: GuestOutput,
+    /// how the imports of the last call were answered
+    imports: Imports,
+    _ticker: Arc<EpochTicker>,
+}
+
+impl ReconcilerInstance {
+    /// instantiate the component, the guest can query `objects` through the `retrieve` interface,
+    /// a fuel budget requires an engine that consumes fuel
+    ///
+    /// instances of the same engine share its ticker, every ticker increments the epoch
+    pub async fn new(
+        ticker: Arc<EpochTicker>,
+        name: String,
+        component: Component,
+        linker: Linker<Ctx>,
+        objects: ObjectStore,
+        config: InstanceConfig,
+    ) -> Result<Self> {
+        let pre = link(&linker, &component)?;
+
+        let start = Instant::now();
+        let (store, reconciler) = instantiate(&name, &pre, objects, &config).await?;
+        let instantiation = start.elapsed();
+
+        Ok(Self {
+            name,
+            pre,
+            store,
+            reconciler,
+            config,
+            called: false,
+            fuel_consumed: None,
+            instantiation: Some(instantiation),
+            iterations: 0,
+            output: GuestOutput::default(),
+            imports: Imports::default(),
+            _ticker: ticker,
+        })
+    }
+
+    pub fn objects(&self) -> &ObjectStore {
+        &self.store.data().objects
+    }
+
+    pub fn objects_mut(&mut self) -> &mut ObjectStore {
+        &mut self.store.data_mut().objects
+    }
+
+    /// fuel used by the last call, none when fuel is not metered
+    pub fn fuel_consumed(&self) -> Option<u64> {
+        self.fuel_consumed
+    }
+
+    /// time the last call spent instantiating, none when it reused the instance
+    pub fn instantiation(&self) -> Option<Duration> {
+        self.instantiation
+    }
+
+    /// the component in logs and metrics
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// what the guest wrote to stdout and stderr during the last call
+    pub fn output(&self) -> &GuestOutput {
+        &self.output
+    }
+
+    /// how the imports of the last call were answered, the remaining calls of a replay
+    pub fn imports(&self) -> &Imports {
+        &self.imports
+    }
+
+    /// answer the imports of the next call as given, e.g. from a recording,
+    /// the instance must not be recreated before the call
+    pub fn set_imports(&mut self, imports: Imports) {
+        self.store.data_mut().imports = imports;
+    }
+
+    /// call `reconcile` for the object `key`, a call that runs past the timeout fails with [`TIMEOUT`],
+    /// one that uses up its fuel with [`OUT_OF_FUEL`], one that grows past a
+    /// limit with [`LIMIT_EXCEEDED`] and one that traps otherwise with 500,
+    /// in all cases the instance is replaced by a fresh one
+    ///
+    /// with a record directory the call is written to a trace
+    pub async fn call_reconcile(
+        &mut self,
+        key: &str,
+        input_json: &str,
+    ) -> std::result::Result<ReconcileResult, ReconcileError> {
+        let result = self.call(key, input_json).await;
+        if let (Some(dir), Imports::Record(calls)) = (&self.config.record, &mut self.imports) {
+            let trace = Trace {
+                component: self.name.clone(),
+                key: key.to_string(),
+                input: serde_json::from_str(input_json)
+                    .unwrap_or_else(|_| Value::String(input_json.to_string())),
+                calls: std::mem::take(calls),
+                stdout: self.output.stdout.clone(),
+                stderr: self.output.stderr.clone(),
+                result: result.clone(),
+            };
+            match trace.write(dir, self.iterations) {
+                Ok(path) => tracing::debug!(trace = %path.display(), "recorded the reconcile"),
+                Err(e) => tracing::warn!("cannot record the reconcile: {e:#}"),
+            }
+        }
+        result
+    }
+
+    async fn call(
+        &mut self,
+        key: &str,
+        input_json: &str,
+    ) -> std::result::Result<ReconcileResult, ReconcileError> {
+        self.instantiation = None;
+        if self.config.fresh && self.called {
+            let start = Instant::now();
+            self.recreate()
+                .await
+                .map_err(|e| match e.downcast_ref::<LimitExceeded>() {
+                    Some(exceeded) => ReconcileError {
+                        code: LIMIT_EXCEEDED,
+                        message: exceeded.to_string(),
+                    },
+                    None => ReconcileError {
+                        code: 500,
+                        message: format!("cannot instantiate the reconciler: {e:#}"),
+                    },
+                })?;
+            self.instantiation = Some(start.elapsed());
+        }
+        self.called = true;
+        if self.config.record.is_some() && matches!(self.store.data().imports, Imports::Live) {
+            self.store.data_mut().imports = Imports::Record(Vec::new());
+        }
+
+        self.store
+            .set_epoch_deadline(deadline_ticks(self.config.timeout));
+        if let Some(fuel) = self.config.fuel {
+            self.store
+                .set_fuel(fuel)
+                .expect("fuel is metered since instantiation");
+        }
+
+        // guest log records are emitted inside this span, see the `logging` impl of `Ctx`
+        let span = tracing::info_span!("reconcile", component = %self.name, key);
+        let result = self
+            .reconciler
+            .call_reconcile(&mut self.store, input_json)
+            .instrument(span)
+            .await;
+        self.fuel_consumed = self
+            .config
+            .fuel
+            .map(|fuel| fuel - self.store.get_fuel().unwrap_or_default());
+
+        self.iterations += 1;
+        self.imports = std::mem::take(&mut self.store.data_mut().imports);
+        let ctx = self.store.data();
+        self.output = GuestOutput {
+            key: key.to_string(),
+            iteration: self.iterations,
+            stdout: ctx.stdout.take(),
+            stderr: ctx.stderr.take(),
+        };
+        if let Some(level) = self.config.output_level {
+            self.output.log(level);
+        }
+
+        let e = match result {
+            Ok(result) => return result,
+            Err(e) => e,
+        };
+        let error = match (e.downcast_ref::<Trap>(), e.downcast_ref::<LimitExceeded>()) {
+            (Some(Trap::Interrupt), _) => ReconcileError {
+                code: TIMEOUT,
+                message: format!("reconcile timed out after {:?}", self.config.timeout),
+            },
+            (Some(Trap::OutOfFuel), _) => ReconcileError {
+                code: OUT_OF_FUEL,
+                message: format!(
+                    "reconcile ran out of fuel after {}",
+                    self.config.fuel.unwrap_or_default()
+                ),
+            },
+            (_, Some(exceeded)) => ReconcileError {
+                code: LIMIT_EXCEEDED,
+                message: exceeded.to_string(),
+            },
+            _ => ReconcileError {
+                code: 500,
+                message: format!("Failed to call reconcile: {}", e),
+            },
+        };
+        tracing::warn!("{}, recreating the instance", error.message);
+        if let Err(e) = self.recreate().await {
+            tracing::error!("cannot recreate the instance: {e:#}");
+        }
+        Err(error)
+    }
+
+    /// swap in a new version of the component for the following calls, the object store is kept,
+    /// when the new component cannot be instantiated the current one stays in place
+    pub async fn replace(&mut self, pre: ReconcilerPre<Ctx>) -> Result<()> {
+        let (mut store, reconciler) =
+            instantiate(&self.name, &pre, ObjectStore::new(), &self.config).await?;
+        store.data_mut().objects = std::mem::take(self.objects_mut());
+        self.pre = pre;
+        self.store = store;
+        self.reconciler = reconciler;
+        self.called = false;
+        Ok(())
+    }
+
+    pub fn engine(&self) -> &Engine {
+        self.pre.engine()
+    }
+
+    /// replace the instance with a fresh one that keeps the object store,
+    /// the guest starts over from its initial state
+    ///
+    /// when the new instance cannot be instantiated the object store stays with the current one
+    pub async fn recreate(&mut self) -> Result<()> {
+        let (mut store, reconciler) =
+            instantiate(&self.name, &self.pre, ObjectStore::new(), &self.config).await?;
+        store.data_mut().objects = std::mem::take(self.objects_mut());
+        self.store = store;
+        self.reconciler = reconciler;
+        self.called = false;
+        Ok(())
+    }
+}
+
+/// link the component, checking that the host provides all of its imports
+/// and that it exports everything the `reconciler` world requires
+pub fn link(linker: &Linker<Ctx>, component: &Component) -> Result<ReconcilerPre<Ctx>> {
+    let _span = tracing::info_span!("link").entered();
+    let instance_pre = linker
+        .instantiate_pre(component)
+        .context("component imports cannot be satisfied by the host")?;
+    ReconcilerPre::new(instance_pre).context("component exports do not match the reconciler world")
+}
+
+async fn instantiate(
+    name: &str,
+    pre: &ReconcilerPre<Ctx>,
+    objects: ObjectStore,
+    config: &InstanceConfig,
+) -> Result<(Store<Ctx>, Reconciler)> {
+    // Create the store to manage the state of the component
+    let mut store = Store::<Ctx>::new(
+        pre.engine(),
+        Ctx::new(objects, config.limits, &config.policy)?,
+    );
+    store.limiter(|ctx| ctx);
+
+    // Instantiation runs guest code too, it gets the same budget as a call
+    store.set_epoch_deadline(deadline_ticks(config.timeout));
+    if let Some(fuel) = config.fuel {
+        store
+            .set_fuel(fuel)
+            .context("the engine does not consume fuel")?;
+    }
+
+    // Instantiate the component
+    let reconciler = pre
+        .instantiate_async(&mut store)
+        .instrument(tracing::info_span!("instantiate", component = %name))
+        .await
+        .map_err(|e| instances_exceeded(e, &config.limits))
+        .context("Failed to instantiate the reconciler world")?;
+
+    Ok((store, reconciler))
+}
+
+/// epoch ticks that cover `timeout`
+fn deadline_ticks(timeout: Duration) -> u64 {
+    let ticks = timeout.as_nanos().div_ceil(EPOCH_TICK.as_nanos());
+    u64::try_from(ticks).unwrap_or(u64::MAX)
+}
+
+/// increments the epoch of an engine every [`EPOCH_TICK`] until dropped
+///
+/// a plain thread instead of a tokio task, a guest that never yields blocks
+/// the runtime thread it is called on
+pub struct EpochTicker {
+    stop: Arc<AtomicBool>,
+}
+
+impl EpochTicker {
+    pub fn start(engine: Engine) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stopped = stop.clone();
+        thread::spawn(move || {
+            while !stopped.load(Ordering::Relaxed) {
+                thread::sleep(EPOCH_TICK);
+                engine.increment_epoch();
+            }
+        });
+        Self { stop }
+    }
+}
+
+impl Drop for EpochTicker {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use wasmtime::Config;
+
+    use super::*;
+
+    /// a component whose `reconcile` always traps
+    const TRAPPING: &str = r#"
+        (component
+          (core module $m
+            (memory (export "memory") 1)
+            (func (export "cabi_realloc") (param i32 i32 i32 i32) (result i32)
+              i32.const 1024)
+            (func (export "reconcile") (param i32 i32) (result i32)
+              unreachable))
+          (core instance $i (instantiate $m))
+          (type $ek (enum "normal" "warning"))
+          (export $ekx "event-kind" (type $ek))
+          (type $ev (record (field "kind" $ekx) (field "reason" string) (field "message" string)))
+          (export $evx "event" (type $ev))
+          (type $rr (record (field "requeue" bool) (field "requeue-after" u32) (field "status" (option string)) (field "children" (list string)) (field "events" (list $evx))))
+          (type $re (record (field "code" u32) (field "message" string)))
+          (export $rrx "reconcile-result" (type $rr))
+          (export $rex "reconcile-error" (type $re))
+          (func (export "reconcile") (param "object" string) (result (result $rrx (error $rex)))
+            (canon lift (core func $i "reconcile") (memory $i "memory") (realloc (func $i "cabi_realloc")))))
+    "#;
+
+    async fn trapping_instance() -> ReconcilerInstance {
+        let mut engine_config = Config::default();
+        engine_config.async_support(true);
+        engine_config.wasm_component_model(true);
+        engine_config.epoch_interruption(true);
+        let engine = Engine::new(&engine_config).unwrap();
+        let component = Component::new(&engine, TRAPPING).unwrap();
+        let mut objects = ObjectStore::new();
+        objects
+            .insert(json!({
+                "apiVersion": "infra.kuid.dev/v1alpha1",
+                "kind": "Node",
+                "metadata": {"name": "node1", "namespace": "default"},
+            }))
+            .unwrap();
+        let config = InstanceConfig {
+            timeout: Duration::from_secs(5),
+            fuel: None,
+            limits: Limits::default(),
+            fresh: false,
+            output_level: None,
+            policy: Arc::new(WasiPolicy::default()),
+            record: None,
+        };
+        ReconcilerInstance::new(
+            Arc::new(EpochTicker::start(engine.clone())),
+            "trapping".to_string(),
+            component,
+            Linker::new(&engine),
+            objects,
+            config,
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn failed_recreate_keeps_the_objects() {
+        let mut instance = trapping_instance().await;
+        // the component cannot get its memory anymore
+        instance.config.limits.memory = Some(0);
+
+        assert!(instance.recreate().await.is_err());
+        assert_eq!(instance.objects().len(), 1);
+
+        instance.config.limits.memory = None;
+        instance.recreate().await.unwrap();
+        assert_eq!(instance.objects().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn trap_recreates_the_instance() {
+        let mut instance = trapping_instance().await;
+        for _ in 0..2 {
+            let error = instance
+                .call_reconcile("default/node1", "{}")
+                .await
+                .unwrap_err();
+            assert_eq!(error.code, 500);
+            // a trapped instance that is called again cannot be entered
+            assert!(
+                error.message.contains("error while executing"),
+                "{}",
+                error.message
+            );
+        }
+        assert_eq!(instance.objects().len(), 1);
+    }
+}
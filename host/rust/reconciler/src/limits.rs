@@ -0,0 +1,108 @@
+//! Caps on the memory, tables and instances of a guest
+//!
+//! The caps are enforced by the [`ResourceLimiter`] of [`Ctx`]. Growing past a
+//! cap traps the guest with a [`LimitExceeded`] error instead of letting the
+//! grow fail, so the reconcile error names the limit rather than whatever the
+//! guest does when it runs out of memory. Instances are only counted while a
+//! guest is instantiated, going over that cap is reported the same way.
+
+use std::fmt;
+
+use anyhow::Result;
+use wasmtime::ResourceLimiter;
+
+use crate::host::Ctx;
+
+/// the caps of a store, none leaves the wasmtime default
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Limits {
+    /// bytes of a single linear memory
+    pub memory: Option<usize>,
+    /// elements of a single table
+    pub table_elements: Option<usize>,
+    /// core module and component instances
+    pub instances: Option<usize>,
+}
+
+/// a guest tried to grow past one of the [`Limits`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LimitExceeded {
+    pub limit: &'static str,
+    pub unit: &'static str,
+    pub max: usize,
+    pub desired: usize,
+}
+
+impl fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} limit of {} {} exceeded, the guest requested {} {}",
+            self.limit, self.max, self.unit, self.desired, self.unit
+        )
+    }
+}
+
+impl std::error::Error for LimitExceeded {}
+
+impl ResourceLimiter for Ctx {
+    fn memory_growing(
+        &mut self,
+        _current: usize,
+        desired: usize,
+        _maximum: Option<usize>,
+    ) -> Result<bool> {
+        check("memory", "bytes", self.limits.memory, desired)
+    }
+
+    fn table_growing(
+        &mut self,
+        _current: usize,
+        desired: usize,
+        _maximum: Option<usize>,
+    ) -> Result<bool> {
+        check("table", "elements", self.limits.table_elements, desired)
+    }
+
+    fn instances(&self) -> usize {
+        self.limits
+            .instances
+            .unwrap_or(wasmtime::DEFAULT_INSTANCE_LIMIT)
+    }
+}
+
+/// wasmtime fails an instantiation over [`ResourceLimiter::instances`] with a plain
+/// error, mark it as a [`LimitExceeded`] like the other caps
+pub fn instances_exceeded(error: anyhow::Error, limits: &Limits) -> anyhow::Error {
+    let over_cap = error.chain().any(|e| {
+        e.to_string()
+            .starts_with("resource limit exceeded: instance count too high")
+    });
+    match limits.instances {
+        Some(max) if over_cap => error.context(LimitExceeded {
+            limit: "instance",
+            unit: "instances",
+            max,
+            desired: max + 1,
+        }),
+        _ => error,
+    }
+}
+
+fn check(
+    limit: &'static str,
+    unit: &'static str,
+    max: Option<usize>,
+    desired: usize,
+) -> Result<bool> {
+    match max {
+        Some(max) if desired > max => Err(LimitExceeded {
+            limit,
+            unit,
+            max,
+            desired,
+        }
+        .into()),
+        _ => Ok(true),
+    }
+}
@@ -1,12 +1,13 @@
-use core::fmt::{self, Debug};
-use std::path::PathBuf;
+use std::path::Path;
+use std::sync::Arc;
 use std::time::Instant;
 
 use anyhow::{Context, Result};
+use clap::Parser;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::EnvFilter;
 use wasmtime::component::{Component, Linker};
-use wasmtime::{Config, Engine, Store};
-use wasmtime_wasi::async_trait;
-use wasmtime_wasi::{ResourceTable, WasiCtx, WasiView};
+use wasmtime::{Config, Engine, InstanceAllocationStrategy, PoolingAllocationConfig, Precompiled};
 
 mod bindings {
     //! Code generated by wasmtime component bindgen is sequestered to this module
@@ -15,82 +16,97 @@ mod bindings {
         path: "../../../wit",
         world: "reconciler",
         async: true,
+        additional_derives: [serde::Serialize, serde::Deserialize],
         with: {
             "wasi:io": wasmtime_wasi::bindings::io,
         },
     });
 }
 
-use bindings::{Reconciler, ReconcileError, ReconcileResult};
+mod backoff;
+mod cache;
+mod cli;
+mod commands;
+mod conformance;
+mod controller;
+mod diff;
+mod drift;
+mod golden;
+mod host;
+mod input;
+mod instance;
+mod limits;
+mod metrics;
+mod outcome;
+mod output;
+mod plan;
+mod policy;
+mod queue;
+mod registry;
+mod reload;
+mod selector;
+mod store;
+mod trace;
 
-/// This state is used by the Runtime host,
-/// we use it to store the WASI context (implementations of WASI)
-/// and resource tables that components will use when executing
-///
-/// see:
-/// - https://docs.rs/wasmtime-wasi/latest/wasmtime_wasi/trait.WasiView.html
-/// - https://docs.rs/wasmtime-wasi/latest/wasmtime_wasi/fn.add_to_linker_sync.html
-struct Ctx {
-    wasi: WasiCtx,
-    table: ResourceTable,
-}
+use bindings::ReconcileError;
+use cli::{Cli, Command, RuntimeArgs};
+use host::Ctx;
+use instance::{EpochTicker, ReconcilerInstance};
+use metrics::metrics;
+use outcome::Outcome;
+use serde_json::Value;
+use store::{ObjectRef, ObjectStore};
 
-impl Ctx {
-    pub fn new() -> Self {
-        let wasi = WasiCtx::builder().inherit_stdio().build();
-        Self {
-            wasi,
-            table: ResourceTable::new(),
-        }
+/// create the wasmtime engine used to compile and run components,
+/// `components` is the number of components that are instantiated with it
+fn new_engine(runtime: &RuntimeArgs, components: usize) -> Result<Engine> {
+    let mut engine_config = Config::default();
+    engine_config.async_support(true);
+    engine_config.wasm_component_model(true);
+    // reconcile calls are bounded by an epoch deadline, see `instance`
+    engine_config.epoch_interruption(true);
+    // metering fuel slows the guest down, only do it when there is a budget
+    engine_config.consume_fuel(runtime.fuel.is_some());
+    // a fresh instance per reconcile takes its memories and tables from a pool
+    if runtime.fresh_instance {
+        engine_config.allocation_strategy(InstanceAllocationStrategy::Pooling(pooling_config(
+            runtime, components,
+        )));
     }
-}
 
-// NOTE: you need to use async_trait mostly because that's what the bindings use
-//
-// Unfortunately auto-complete/ cargo-expand will show you a trait *after* async_trait
-// has had it's way with it -- so that's why you get obtusely named lifetimes and hard
-// to read code for humans.
-#[async_trait]
-impl bindings::example::reconciler::retrieve::Host for Ctx {
-    async fn get(&mut self, s: String) -> String {
-        format!("Hello {s}!")
-    }
+    Engine::new(&engine_config).context("cannot create engine from config")
 }
 
-impl WasiView for Ctx {
-    fn table(&mut self) -> &mut ResourceTable {
-        &mut self.table
-    }
+/// the instance pool of the fresh instance mode, each reconcile returns its
+/// instance to the pool before the next one takes it
+///
+/// a component holds two slots while it is recreated or reloaded, the new
+/// instance is created before the old one is dropped
+fn pooling_config(runtime: &RuntimeArgs, components: usize) -> PoolingAllocationConfig {
+    let pooled = u32::try_from(components.max(1).saturating_mul(2)).unwrap_or(u32::MAX);
 
-    fn ctx(&mut self) -> &mut WasiCtx {
-        &mut self.wasi
+    let mut config = PoolingAllocationConfig::default();
+    config
+        .total_component_instances(pooled)
+        // componentize-py and TinyGo components contain many core modules
+        .total_core_instances(pooled.saturating_mul(64))
+        .total_memories(pooled.saturating_mul(8))
+        .total_tables(pooled.saturating_mul(16))
+        .total_stacks(pooled)
+        .max_core_instance_size(16 << 20)
+        .max_component_instance_size(16 << 20);
+    if let Some(max_memory) = runtime.max_memory {
+        config.max_memory_size(max_memory);
     }
-}
-
-impl Debug for Ctx {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Ctx").field("runtime", &"wasmtime").finish()
+    if let Some(table_elements) = runtime.max_table_elements {
+        config.table_elements(table_elements);
     }
+    config
 }
 
-/// load the WASM component and return the instance
-async fn load_reconciler_instance(path: PathBuf) -> Result<(Store<Ctx>, bindings::Reconciler)> {
-    // Initialize the Wasmtime engine
-    let mut engine_config = Config::default();
-    engine_config.async_support(true);
-    engine_config.wasm_component_model(true);
-
-    let engine = Engine::new(&engine_config).context("cannot create engine from config")?;
-
-    // Load the WASM component
-    let component = Component::from_file(&engine, &path).context("Component file not found")?;
-
-    // Create the store to manage the state of the component
-    let states: Ctx = Ctx::new();
-    let mut store = Store::<Ctx>::new(&engine, states);
-
-    // Set up the linker for linking interfaces
-    let mut linker = Linker::new(&engine);
+/// set up a linker with the WASI and host-backed interfaces of the `reconciler` world
+fn new_linker(engine: &Engine) -> Result<Linker<Ctx>> {
+    let mut linker = Linker::new(engine);
 
     // Add WASI implementations to the linker for components to use
     wasmtime_wasi::add_to_linker_async(&mut linker)
@@ -100,80 +116,187 @@ async fn load_reconciler_instance(path: PathBuf) -> Result<(Store<Ctx>, bindings
     bindings::example::reconciler::retrieve::add_to_linker(&mut linker, |ctx| ctx)
         .context("failed to link reconciler")?;
 
-    // Instantiate the component
-    let instance = bindings::Reconciler::instantiate_async(&mut store, &component, &linker)
-        .await
-        .context("Failed to instantiate the reconciler world")?;
+    // Add host-backed support for the `writer` interface to the linker
+    bindings::example::reconciler::writer::add_to_linker(&mut linker, |ctx| ctx)
+        .context("failed to link writer")?;
+
+    // Add host-backed support for the `logging` interface to the linker
+    bindings::example::reconciler::logging::add_to_linker(&mut linker, |ctx| ctx)
+        .context("failed to link logging")?;
 
-    Ok((store, instance))
+    Ok(linker)
 }
 
-// call the reconcile function
+/// load the WASM component from a file, either a `.cwasm` file written by
+/// `precompile` or a component that is compiled, or taken from the cache
+fn load_component(engine: &Engine, path: &Path, runtime: &RuntimeArgs) -> Result<Component> {
+    let _span = tracing::info_span!("load", component = %path.display()).entered();
+    let bytes = std::fs::read(path)
+        .with_context(|| format!("cannot read component from {}", path.display()))?;
+
+    let component = match engine.detect_precompiled(&bytes) {
+        // SAFETY: precompiled components are trusted like the component they were compiled from
+        Some(Precompiled::Component) => unsafe { Component::deserialize(engine, &bytes) }
+            .context("incompatible precompiled component, precompile it again"),
+        Some(Precompiled::Module) => Err(anyhow::anyhow!(
+            "precompiled core module, expected a component"
+        )),
+        None => match runtime.cache() {
+            Some(cache) => cache.load(engine, &bytes),
+            None => Component::new(engine, &bytes),
+        },
+    };
+    component.with_context(|| format!("cannot load component from {}", path.display()))
+}
+
+/// name of a component in logs, the file name without extension
+fn component_name(path: &Path) -> String {
+    path.file_stem()
+        .unwrap_or(path.as_os_str())
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// load the WASM component and return the instance,
+/// the guest can query `objects` through the `retrieve` interface
+async fn load_reconciler_instance(
+    path: &Path,
+    objects: ObjectStore,
+    runtime: &RuntimeArgs,
+) -> Result<ReconcilerInstance> {
+    load_named_instance(component_name(path), path, objects, runtime).await
+}
+
+/// [`load_reconciler_instance`] with the name the component has in logs, metrics and traces
+async fn load_named_instance(
+    name: String,
+    path: &Path,
+    objects: ObjectStore,
+    runtime: &RuntimeArgs,
+) -> Result<ReconcilerInstance> {
+    // Initialize the Wasmtime engine
+    let engine = new_engine(runtime, 1)?;
+
+    // Load the WASM component
+    let component = load_component(&engine, path, runtime)?;
+
+    // Set up the linker for linking interfaces
+    let linker = new_linker(&engine)?;
+
+    ReconcilerInstance::new(
+        Arc::new(EpochTicker::start(engine)),
+        name,
+        component,
+        linker,
+        objects,
+        runtime.instance_config()?,
+    )
+    .await
+}
+
+/// store `object`, call the reconcile function with it and apply the status
+/// and the planned children of the result to the object store,
+/// the call is recorded in the reconcile metrics
 async fn call_reconcile(
-    store: &mut Store<Ctx>,
-    instance: &Reconciler,
-    input_json: String,
-) -> std::result::Result<ReconcileResult, ReconcileError> {
+    instance: &mut ReconcilerInstance,
+    object: &Value,
+) -> std::result::Result<Outcome, ReconcileError> {
+    let start = Instant::now();
+    let result = reconcile_and_apply(instance, object).await;
+
+    let requeue = match &result {
+        Ok(outcome) if outcome.requeue => Some("now"),
+        Ok(outcome) if outcome.requeue_after > 0 => Some("after"),
+        _ => None,
+    };
+    metrics().reconciled(
+        instance.name(),
+        start.elapsed(),
+        result.as_ref().err().map(|e| e.code),
+        requeue,
+    );
+    result
+}
+
+async fn reconcile_and_apply(
+    instance: &mut ReconcilerInstance,
+    object: &Value,
+) -> std::result::Result<Outcome, ReconcileError> {
+    let parent = ObjectRef::from_value(object).map_err(|e| ReconcileError {
+        code: 400,
+        message: format!("invalid input: {e}"),
+    })?;
+
+    // store the input as the current version of the object,
+    // keeping the status written by earlier reconciles
+    let objects = instance.objects_mut();
+    let mut input = object.clone();
+    if input.get("status").is_none() {
+        if let Some(status) = objects.get(&parent).and_then(|stored| stored.get("status")) {
+            input["status"] = status.clone();
+        }
+    }
+    objects.insert(input).map_err(|e| ReconcileError {
+        code: 400,
+        message: format!("invalid input: {e}"),
+    })?;
+    let input_json = objects
+        .get(&parent)
+        .expect("the input was just stored")
+        .to_string();
+
     // Call the reconcile function
     let result = instance
-        .call_reconcile(store, &input_json)
-        .await
+        .call_reconcile(&parent.to_string(), &input_json)
+        .await?;
+
+    let outcome = Outcome::parse(result, &parent, instance.objects())?;
+    outcome
+        .apply(&parent, instance.objects_mut())
         .map_err(|e| ReconcileError {
-            code: 500,
-            message: format!("Failed to call reconcile: {}", e),
-        })??;
+            code: outcome::APPLY_FAILED,
+            message: format!("cannot apply reconcile result: {e}"),
+        })?;
 
-    Ok(result)
+    Ok(outcome)
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let wasm_path = PathBuf::from(
-        std::env::var_os("GUEST_WASM_PATH")
-            .context("missing/invalid path to WebAssembly module (env: GUEST_WASM_PATH)")?,
-    );
+    let cli = Cli::parse();
 
-    // Input JSON
-    let input_json = r#"{"apiVersion":"topo.kubenet.dev/v1alpha1","kind":"Topology","metadata":{"name":"kubenet","namespace":"default"},"spec":{"defaults":{"type":"7220ixr-d3l","provider":"srlinux.nokia.com","version":"24.7.2"},"nodes":[{"name":"node1"},{"name":"node2"}],"links":[{"endpoints":[{"node":"node1","port":1,"endpoint":1},{"node":"node2","port":1,"endpoint":1}]}]}}"#.to_string();
-    //let input_json = r#"{"apiVersion":"topo.kubenet.dev/v1alpha1","kind":"Topology","metadata":{"name":"kubenet","namespace":"default"},"spec":{"defaults":{"type":"7220ixr-d3l","provider":"srlinux.nokia.com","version":"24.7.2"},"nodes":[{"name":"node1"},{"name":"node3"}],"links":[{"endpoints":[{"node":"node1","port":1,"endpoint":1},{"node":"node2","port":1,"endpoint":1}]}]}}"#.to_string();
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .with_span_events(if cli.log_spans {
+            FmtSpan::CLOSE
+        } else {
+            FmtSpan::NONE
+        })
+        .with_writer(std::io::stderr)
+        .init();
 
-    //load the instance
-    let (mut store, instance) = load_reconciler_instance(wasm_path)
-        .await
-        .map_err(|e| anyhow::anyhow!("Error loading reconciler instance: {}", e))?;
+    if let Some(addr) = cli.metrics_addr {
+        metrics::serve(addr).await?;
+    }
 
-    // Measure time taken to run the instance 10 times
-    let start = Instant::now();
+    // a controller with a registry does not need a component path
+    let wasm_path = cli.wasm_path();
 
-    for i in 0..10 {
-        println!("Running iteration: {}", i + 1);
-        // Measure iteration time
-        let iteration_start = Instant::now();
-        match call_reconcile(&mut store, &instance, input_json.clone()).await {
-            Ok(result) => {
-                let iteration_duration = iteration_start.elapsed();
-                println!(
-                    "Reconcile Iteration {} succeeded with output: {:#?}",
-                    i, result
-                );
-                println!(
-                    "Reconcile Iteration {} elapsed time {:?}",
-                    i, iteration_duration
-                );
-            }
-            Err(e) => {
-                let iteration_duration = iteration_start.elapsed();
-                eprintln!("Reconcile Iteration {} failed: {}", i, e);
-                println!(
-                    "Reconcile Iteration {} elapsed time {:?}",
-                    i, iteration_duration
-                );
-            }
+    match cli.command {
+        Command::Run(args) => commands::run::exec(&wasm_path?, &cli.runtime, args).await,
+        Command::Validate => commands::validate::exec(&wasm_path?, &cli.runtime),
+        Command::Bench(args) => commands::bench::exec(&wasm_path?, &cli.runtime, args).await,
+        Command::Inspect => commands::inspect::exec(&wasm_path?, &cli.runtime),
+        Command::Controller(args) => {
+            commands::controller::exec(wasm_path, &cli.runtime, args).await
         }
+        Command::Precompile(args) => commands::precompile::exec(&wasm_path?, &cli.runtime, args),
+        Command::Replay(args) => commands::replay::exec(&wasm_path?, &cli.runtime, args).await,
+        Command::Conformance(args) => {
+            commands::conformance::exec(wasm_path, &cli.runtime, args).await
+        }
+        Command::Test(args) => commands::test::exec(&wasm_path?, &cli.runtime, args).await,
     }
-
-    let duration = start.elapsed();
-    println!("Time taken for 10 iterations: {:?}", duration);
-
-    Ok(())
 }
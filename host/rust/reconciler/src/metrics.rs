@@ -0,0 +1,152 @@
+//! Prometheus metrics of the reconciles, served in the text format
+//!
+//! Every reconcile is counted with the component that handled it, failed ones
+//! also by error code. With `--metrics-addr` the host answers `GET /metrics`
+//! on that address for as long as it runs.
+
+use std::net::SocketAddr;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry, TextEncoder,
+};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+pub struct Metrics {
+    registry: Registry,
+    reconciles: IntCounterVec,
+    errors: IntCounterVec,
+    duration: HistogramVec,
+    requeues: IntCounterVec,
+}
+
+/// the metrics of the process
+pub fn metrics() -> &'static Metrics {
+    static METRICS: OnceLock<Metrics> = OnceLock::new();
+    METRICS.get_or_init(|| Metrics::new().expect("metric definitions are valid"))
+}
+
+impl Metrics {
+    fn new() -> Result<Self> {
+        let registry = Registry::new_custom(Some("reconciler".to_string()), None)?;
+        let reconciles = IntCounterVec::new(
+            Opts::new("reconcile_total", "Reconcile calls"),
+            &["component"],
+        )?;
+        let errors = IntCounterVec::new(
+            Opts::new(
+                "reconcile_errors_total",
+                "Failed reconcile calls by error code",
+            ),
+            &["component", "code"],
+        )?;
+        let duration = HistogramVec::new(
+            HistogramOpts::new(
+                "reconcile_duration_seconds",
+                "Duration of reconcile calls, including applying the result",
+            )
+            .buckets(prometheus::exponential_buckets(0.0001, 4.0, 10)?),
+            &["component"],
+        )?;
+        let requeues = IntCounterVec::new(
+            Opts::new(
+                "reconcile_requeues_total",
+                "Reconciles that requested a requeue, `after` for a delayed one",
+            ),
+            &["component", "requeue"],
+        )?;
+        registry.register(Box::new(reconciles.clone()))?;
+        registry.register(Box::new(errors.clone()))?;
+        registry.register(Box::new(duration.clone()))?;
+        registry.register(Box::new(requeues.clone()))?;
+        Ok(Self {
+            registry,
+            reconciles,
+            errors,
+            duration,
+            requeues,
+        })
+    }
+
+    /// record a reconcile, `error` is its error code and `requeue` none, `now` or `after`
+    pub fn reconciled(
+        &self,
+        component: &str,
+        duration: Duration,
+        error: Option<u32>,
+        requeue: Option<&str>,
+    ) {
+        self.reconciles.with_label_values(&[component]).inc();
+        self.duration
+            .with_label_values(&[component])
+            .observe(duration.as_secs_f64());
+        if let Some(code) = error {
+            self.errors
+                .with_label_values(&[component, &code.to_string()])
+                .inc();
+        }
+        if let Some(requeue) = requeue {
+            self.requeues.with_label_values(&[component, requeue]).inc();
+        }
+    }
+
+    /// the metrics in the Prometheus text format
+    pub fn encode(&self) -> Result<String> {
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+}
+
+/// answer `GET /metrics` on `addr` in the background
+///
+/// a minimal HTTP/1.1 responder, one request per connection is all Prometheus needs
+pub async fn serve(addr: SocketAddr) -> Result<()> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("cannot listen for metrics on {addr}"))?;
+    tracing::info!(%addr, "serving metrics on /metrics");
+
+    tokio::spawn(async move {
+        loop {
+            let mut stream = match listener.accept().await {
+                Ok((stream, _)) => stream,
+                Err(e) => {
+                    tracing::warn!("cannot accept metrics connection: {e}");
+                    continue;
+                }
+            };
+            tokio::spawn(async move {
+                let mut request = [0; 1024];
+                let Ok(n) = stream.read(&mut request).await else {
+                    return;
+                };
+                let response = match request[..n].split(|b| *b == b'\r').next() {
+                    Some(b"GET /metrics HTTP/1.1" | b"GET /metrics HTTP/1.0") => {
+                        match metrics().encode() {
+                            Ok(body) => response("200 OK", TextEncoder::new().format_type(), &body),
+                            Err(e) => {
+                                response("500 Internal Server Error", "text/plain", &e.to_string())
+                            }
+                        }
+                    }
+                    _ => response("404 Not Found", "text/plain", "not found\n"),
+                };
+                if let Err(e) = stream.write_all(response.as_bytes()).await {
+                    tracing::debug!("cannot write metrics response: {e}");
+                }
+            });
+        }
+    });
+    Ok(())
+}
+
+fn response(status: &str, content_type: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
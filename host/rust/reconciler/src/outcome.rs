@@ -0,0 +1,275 @@
+//! The validated result of a reconcile and how it is applied to the object store
+//!
+//! The guest returns the status and the desired children as JSON strings, each
+//! is parsed and the children are planned against the store before anything is
+//! written, so that a malformed result leaves the store untouched. The writes
+//! go to a copy of the store that replaces it once all of them succeeded, a
+//! write that fails halfway leaves the store untouched as well.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::bindings::{Event, EventKind, ReconcileError, ReconcileResult};
+use crate::plan::Plan;
+use crate::store::{ObjectRef, ObjectStore, StoreError};
+
+/// the reconcile error code for a malformed status or child
+pub const INVALID_RESULT: u32 = 422;
+
+/// the reconcile error code for a child that is controlled by another object
+pub const CHILD_CONFLICT: u32 = 409;
+
+/// the reconcile error code when the result cannot be applied to the store
+pub const APPLY_FAILED: u32 = 500;
+
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub requeue: bool,
+    pub requeue_after: u32,
+    pub status: Option<Value>,
+    pub children: Vec<Value>,
+    pub events: Vec<Event>,
+    pub plan: Plan,
+}
+
+impl Outcome {
+    /// parse the status and children of a reconcile result and plan the children,
+    /// children without a namespace get the namespace of the parent and
+    /// a resource version set on a child is ignored
+    pub fn parse(
+        result: ReconcileResult,
+        parent: &ObjectRef,
+        objects: &ObjectStore,
+    ) -> Result<Self, ReconcileError> {
+        let status = result
+            .status
+            .map(|status| match serde_json::from_str(&status) {
+                Ok(status @ Value::Object(_)) => Ok(status),
+                Ok(_) => Err(invalid("status must be a JSON object".to_string())),
+                Err(e) => Err(invalid(format!("status is not valid JSON: {e}"))),
+            })
+            .transpose()?;
+
+        let mut refs = BTreeMap::new();
+        let children: Vec<Value> = result
+            .children
+            .iter()
+            .enumerate()
+            .map(|(i, child)| {
+                let mut child: Value = serde_json::from_str(child)
+                    .map_err(|e| invalid(format!("child {i} is not valid JSON: {e}")))?;
+                if !child.is_object() {
+                    return Err(invalid(format!("child {i} must be a JSON object")));
+                }
+                let Some(metadata) = child.get_mut("metadata").and_then(Value::as_object_mut)
+                else {
+                    return Err(invalid(format!("child {i} metadata must be a JSON object")));
+                };
+                if metadata
+                    .get("annotations")
+                    .is_some_and(|annotations| !annotations.is_object() && !annotations.is_null())
+                {
+                    return Err(invalid(format!(
+                        "child {i} annotations must be a JSON object"
+                    )));
+                }
+                metadata.remove("resourceVersion");
+                match metadata.get("namespace") {
+                    None | Some(Value::Null) => {
+                        if let Some(namespace) = &parent.namespace {
+                            metadata
+                                .insert("namespace".to_string(), Value::String(namespace.clone()));
+                        }
+                    }
+                    Some(Value::String(_)) => {}
+                    Some(_) => {
+                        return Err(invalid(format!("child {i} namespace must be a string")));
+                    }
+                }
+                let child_ref = ObjectRef::from_value(&child)
+                    .map_err(|e| invalid(format!("child {i}: {e}")))?;
+                if let Some(first) = refs.insert(child_ref.clone(), i) {
+                    return Err(invalid(format!(
+                        "child {i} duplicates child {first}, both are {child_ref}"
+                    )));
+                }
+                Ok(child)
+            })
+            .collect::<Result<_, _>>()?;
+
+        let plan = Plan::new(parent, &children, objects).map_err(|e| ReconcileError {
+            code: CHILD_CONFLICT,
+            message: format!("cannot apply children: {e}"),
+        })?;
+
+        Ok(Self {
+            requeue: result.requeue,
+            requeue_after: result.requeue_after,
+            status,
+            children,
+            events: result.events,
+            plan,
+        })
+    }
+
+    /// write the status to the parent and apply the plan of the children,
+    /// the store is only changed when every write succeeds
+    pub fn apply(&self, parent: &ObjectRef, objects: &mut ObjectStore) -> Result<(), StoreError> {
+        let mut staged = objects.clone();
+        if let Some(status) = &self.status {
+            let mut object = staged
+                .get(parent)
+                .cloned()
+                .ok_or_else(|| StoreError::NotFound(parent.clone()))?;
+            if object["status"] != *status {
+                object["status"] = status.clone();
+                staged.update(object)?;
+            }
+        }
+        self.plan.apply(&mut staged)?;
+        *objects = staged;
+        Ok(())
+    }
+}
+
+impl fmt::Display for EventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Normal => "Normal",
+            Self::Warning => "Warning",
+        })
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {}", self.kind, self.reason, self.message)
+    }
+}
+
+fn invalid(message: String) -> ReconcileError {
+    ReconcileError {
+        code: INVALID_RESULT,
+        message: format!("invalid reconcile result: {message}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn parent() -> ObjectRef {
+        ObjectRef {
+            api_version: "topo.kubenet.dev/v1alpha1".to_string(),
+            kind: "Topology".to_string(),
+            namespace: Some("default".to_string()),
+            name: "kubenet".to_string(),
+        }
+    }
+
+    fn result(children: &[Value]) -> ReconcileResult {
+        ReconcileResult {
+            requeue: false,
+            requeue_after: 0,
+            status: None,
+            children: children.iter().map(Value::to_string).collect(),
+            events: Vec::new(),
+        }
+    }
+
+    fn parse(children: &[Value]) -> Result<Outcome, ReconcileError> {
+        Outcome::parse(result(children), &parent(), &ObjectStore::new())
+    }
+
+    fn node(metadata: Value) -> Value {
+        json!({"apiVersion": "infra.kuid.dev/v1alpha1", "kind": "Node", "metadata": metadata})
+    }
+
+    fn rejected(children: &[Value]) -> String {
+        let error = parse(children).unwrap_err();
+        assert_eq!(error.code, INVALID_RESULT);
+        error.message
+    }
+
+    #[test]
+    fn children_get_the_namespace_of_the_parent() {
+        let outcome = parse(&[
+            node(json!({"name": "node1"})),
+            node(json!({"name": "node2", "namespace": null})),
+            node(json!({"name": "node3", "namespace": "other"})),
+        ])
+        .unwrap();
+        let namespaces: Vec<_> = outcome
+            .children
+            .iter()
+            .map(|child| child["metadata"]["namespace"].clone())
+            .collect();
+        assert_eq!(
+            namespaces,
+            [json!("default"), json!("default"), json!("other")]
+        );
+    }
+
+    #[test]
+    fn children_must_be_objects_with_object_metadata() {
+        assert!(rejected(&[json!("node1")]).contains("must be a JSON object"));
+        assert!(rejected(&[json!({"kind": "Node"})]).contains("metadata"));
+        assert!(rejected(&[node(json!("node1"))]).contains("metadata"));
+        assert!(
+            rejected(&[node(json!({"name": "node1", "annotations": []}))]).contains("annotations")
+        );
+    }
+
+    #[test]
+    fn children_must_be_distinct() {
+        let message = rejected(&[
+            node(json!({"name": "node1"})),
+            node(json!({"name": "node2"})),
+            node(json!({"name": "node1", "namespace": "default"})),
+        ]);
+        assert!(message.contains("child 2"), "{message}");
+        assert!(message.contains("child 0"), "{message}");
+    }
+
+    #[test]
+    fn a_failed_apply_leaves_the_store_untouched() {
+        let mut objects = ObjectStore::new();
+        objects
+            .insert(json!({
+                "apiVersion": "topo.kubenet.dev/v1alpha1",
+                "kind": "Topology",
+                "metadata": {"name": "kubenet", "namespace": "default"},
+            }))
+            .unwrap();
+        let mut result = result(&[
+            node(json!({"name": "node1"})),
+            node(json!({"name": "node2"})),
+        ]);
+        result.status = Some(json!({"ready": true}).to_string());
+        let outcome = Outcome::parse(result, &parent(), &objects).unwrap();
+
+        // created after the plan was made, the second create fails
+        objects
+            .insert(node(json!({"name": "node2", "namespace": "default"})))
+            .unwrap();
+        let before: Vec<_> = objects.iter().map(|(_, object)| object.clone()).collect();
+
+        let error = outcome.apply(&parent(), &mut objects).unwrap_err();
+        assert!(matches!(error, StoreError::AlreadyExists(_)));
+        let after: Vec<_> = objects.iter().map(|(_, object)| object.clone()).collect();
+        assert_eq!(before, after);
+        assert!(objects.take_changes().is_empty());
+    }
+
+    #[test]
+    fn child_namespaces_must_be_strings() {
+        for namespace in [json!(1), json!(true), json!({"name": "default"})] {
+            let child = node(json!({"name": "node1", "namespace": namespace}));
+            assert!(rejected(&[child]).contains("namespace must be a string"));
+        }
+    }
+}
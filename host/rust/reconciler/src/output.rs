@@ -0,0 +1,116 @@
+//! Capture of what a guest writes to stdout and stderr
+//!
+//! The WASI context of a guest gets in-memory pipes instead of the host stdio,
+//! so `println!` or `fmt.Println` in a guest no longer ends up between the
+//! objects the host prints. The pipes are drained after every reconcile into a
+//! [`GuestOutput`] tagged with the object key and the iteration of the call.
+//! Each stream keeps at most [`OUTPUT_CAPACITY`] bytes per call, the rest is
+//! dropped instead of failing the guest write.
+
+use std::sync::{Arc, Mutex};
+
+use bytes::Bytes;
+use tracing::Level;
+use wasmtime_wasi::{HostOutputStream, StdoutStream, StreamResult, Subscribe};
+
+/// bytes kept of each stream per reconcile
+pub const OUTPUT_CAPACITY: usize = 1 << 20;
+
+/// a stdout or stderr pipe whose contents the host drains after each call
+#[derive(Debug, Clone, Default)]
+pub struct OutputPipe {
+    buffer: Arc<Mutex<Captured>>,
+}
+
+#[derive(Debug, Default)]
+struct Captured {
+    bytes: Vec<u8>,
+    /// bytes dropped once the capacity was reached
+    dropped: usize,
+}
+
+impl OutputPipe {
+    /// the output written since the last call, lossily decoded as UTF-8
+    pub fn take(&self) -> String {
+        let captured = std::mem::take(&mut *self.buffer.lock().unwrap());
+        let mut output = String::from_utf8_lossy(&captured.bytes).into_owned();
+        if captured.dropped > 0 {
+            output.push_str(&format!("\n[{} bytes dropped]", captured.dropped));
+        }
+        output
+    }
+}
+
+impl StdoutStream for OutputPipe {
+    fn stream(&self) -> Box<dyn HostOutputStream> {
+        Box::new(self.clone())
+    }
+
+    fn isatty(&self) -> bool {
+        false
+    }
+}
+
+#[wasmtime_wasi::async_trait]
+impl HostOutputStream for OutputPipe {
+    fn write(&mut self, bytes: Bytes) -> StreamResult<()> {
+        let mut captured = self.buffer.lock().unwrap();
+        let kept = bytes.len().min(OUTPUT_CAPACITY - captured.bytes.len());
+        captured.bytes.extend_from_slice(&bytes[..kept]);
+        captured.dropped += bytes.len() - kept;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> StreamResult<()> {
+        Ok(())
+    }
+
+    fn check_write(&mut self) -> StreamResult<usize> {
+        // writes past the capacity are dropped, never make the guest wait
+        Ok(OUTPUT_CAPACITY)
+    }
+}
+
+#[wasmtime_wasi::async_trait]
+impl Subscribe for OutputPipe {
+    async fn ready(&mut self) {}
+}
+
+/// what a guest wrote during one reconcile
+#[derive(Debug, Clone, Default)]
+pub struct GuestOutput {
+    /// the reconciled object
+    pub key: String,
+    /// number of the call on the instance, starting at 1
+    pub iteration: u64,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl GuestOutput {
+    /// the non-empty streams by name
+    pub fn streams(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        [
+            ("stdout", self.stdout.as_str()),
+            ("stderr", self.stderr.as_str()),
+        ]
+        .into_iter()
+        .filter(|(_, output)| !output.is_empty())
+    }
+
+    /// emit every line as a host log event at `level`
+    pub fn log(&self, level: Level) {
+        for (stream, output) in self.streams() {
+            for line in output.lines() {
+                let (key, iteration) = (&self.key, self.iteration);
+                match level {
+                    Level::TRACE => tracing::trace!(key, iteration, stream, "{line}"),
+                    Level::DEBUG => tracing::debug!(key, iteration, stream, "{line}"),
+                    Level::INFO => tracing::info!(key, iteration, stream, "{line}"),
+                    Level::WARN => tracing::warn!(key, iteration, stream, "{line}"),
+                    _ => tracing::error!(key, iteration, stream, "{line}"),
+                }
+            }
+        }
+    }
+}
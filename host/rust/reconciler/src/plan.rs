@@ -0,0 +1,452 @@
+//! Server-side-apply style planning of the desired children of a reconcile
+//!
+//! Every child the host applies is marked as owned by the reconciled object
+//! through `metadata.ownerReferences` and records what was applied in the
+//! [`LAST_APPLIED`] annotation. The plan is a three-way diff between the last
+//! applied child, the desired child and the stored child: fields the host
+//! applied before but no longer wants are removed, fields that drifted are
+//! reset and fields that were set by others are left alone. Owned objects that
+//! are no longer desired are pruned.
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use serde_json::{json, Map, Value};
+
+use crate::store::{ObjectRef, ObjectStore, StoreError};
+
+/// annotation holding the child as it was last applied by the host
+pub const LAST_APPLIED: &str = "reconciler.example/last-applied";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    Create(Value),
+    /// a JSON merge patch and the paths of the fields it changes
+    Update {
+        patch: Value,
+        fields: Vec<String>,
+    },
+    Delete,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plan {
+    /// the reconciled object owning the children
+    parent: ObjectRef,
+    actions: Vec<(ObjectRef, Action)>,
+    unchanged: usize,
+}
+
+impl Plan {
+    /// diff the desired children of `parent` against the store
+    ///
+    /// fails with a conflict when a desired child exists and is controlled
+    /// by another object
+    pub fn new(
+        parent: &ObjectRef,
+        children: &[Value],
+        objects: &ObjectStore,
+    ) -> Result<Self, StoreError> {
+        let mut plan = Self {
+            parent: parent.clone(),
+            actions: Vec::new(),
+            unchanged: 0,
+        };
+
+        let mut desired = BTreeSet::new();
+        for child in children {
+            let child_ref =
+                ObjectRef::from_value(child).map_err(|e| StoreError::Invalid(e.to_string()))?;
+            let child = with_owner(child, parent);
+
+            let Some(live) = objects.get(&child_ref) else {
+                plan.actions
+                    .push((child_ref.clone(), Action::Create(child)));
+                desired.insert(child_ref);
+                continue;
+            };
+            if let Some(owner) = controller(live).filter(|owner| !is_owner(owner, parent)) {
+                return Err(StoreError::Conflict(format!(
+                    "{child_ref} is controlled by {} {}",
+                    owner["kind"].as_str().unwrap_or_default(),
+                    owner["name"].as_str().unwrap_or_default(),
+                )));
+            }
+
+            let last_applied = live["metadata"]["annotations"][LAST_APPLIED]
+                .as_str()
+                .and_then(|last| serde_json::from_str(last).ok())
+                .unwrap_or(Value::Null);
+            let patch = three_way_patch(&last_applied, &child, live);
+            if patch.is_empty() {
+                plan.unchanged += 1;
+            } else {
+                let patch = Value::Object(patch);
+                let fields = changed_fields(&patch);
+                plan.actions
+                    .push((child_ref.clone(), Action::Update { patch, fields }));
+            }
+            desired.insert(child_ref);
+        }
+
+        for (object_ref, object) in objects.iter() {
+            if !desired.contains(object_ref) && is_owned_by(object_ref, object, parent) {
+                plan.actions.push((object_ref.clone(), Action::Delete));
+            }
+        }
+
+        Ok(plan)
+    }
+
+    /// write the planned changes to the store
+    pub fn apply(&self, objects: &mut ObjectStore) -> Result<(), StoreError> {
+        for (object_ref, action) in &self.actions {
+            match action {
+                Action::Create(object) => {
+                    objects.create(object.clone())?;
+                }
+                Action::Update { patch, .. } => {
+                    objects.patch(object_ref, patch)?;
+                }
+                Action::Delete => {
+                    objects.delete(object_ref, None)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    fn count(&self, f: impl Fn(&Action) -> bool) -> usize {
+        self.actions.iter().filter(|(_, action)| f(action)).count()
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parent = &self.parent;
+        if self.is_empty() {
+            return write!(
+                f,
+                "plan for {parent}: no changes, {} unchanged",
+                self.unchanged
+            );
+        }
+        write!(
+            f,
+            "plan for {parent}: {} to create, {} to update, {} to delete, {} unchanged",
+            self.count(|action| matches!(action, Action::Create(_))),
+            self.count(|action| matches!(action, Action::Update { .. })),
+            self.count(|action| matches!(action, Action::Delete)),
+            self.unchanged,
+        )?;
+        for (object_ref, action) in &self.actions {
+            match action {
+                Action::Create(_) => write!(f, "\n  + create {object_ref}")?,
+                Action::Update { fields, .. } => {
+                    write!(f, "\n  ~ update {object_ref}: {}", fields.join(", "))?
+                }
+                Action::Delete => write!(f, "\n  - delete {object_ref}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// the owner reference the host sets on the children of `parent`
+fn owner_reference(parent: &ObjectRef) -> Value {
+    json!({
+        "apiVersion": parent.api_version,
+        "kind": parent.kind,
+        "name": parent.name,
+        "controller": true,
+    })
+}
+
+/// the child as it is applied: owned by the parent and annotated with itself
+fn with_owner(child: &Value, parent: &ObjectRef) -> Value {
+    let mut child = child.clone();
+    let Some(metadata) = child.get_mut("metadata").and_then(Value::as_object_mut) else {
+        return child;
+    };
+    metadata.insert(
+        "ownerReferences".to_string(),
+        json!([owner_reference(parent)]),
+    );
+
+    let mut last_applied = child.clone();
+    if let Some(annotations) = last_applied
+        .pointer_mut("/metadata/annotations")
+        .and_then(Value::as_object_mut)
+    {
+        annotations.remove(LAST_APPLIED);
+    }
+    let last_applied = Value::String(last_applied.to_string());
+
+    let annotations = child
+        .pointer_mut("/metadata")
+        .and_then(Value::as_object_mut)
+        .map(|metadata| metadata.entry("annotations").or_insert(Value::Null));
+    match annotations {
+        Some(Value::Object(annotations)) => {
+            annotations.insert(LAST_APPLIED.to_string(), last_applied);
+        }
+        Some(annotations @ Value::Null) => {
+            *annotations = json!({ LAST_APPLIED: last_applied });
+        }
+        // rejected when the result is parsed
+        _ => {}
+    }
+    child
+}
+
+/// the owner reference marked as controller
+fn controller(object: &Value) -> Option<&Value> {
+    object["metadata"]["ownerReferences"]
+        .as_array()?
+        .iter()
+        .find(|owner| owner["controller"] == Value::Bool(true))
+}
+
+fn is_owner(owner: &Value, parent: &ObjectRef) -> bool {
+    owner["apiVersion"] == parent.api_version.as_str()
+        && owner["kind"] == parent.kind.as_str()
+        && owner["name"] == parent.name.as_str()
+}
+
+/// owner references do not name a namespace, a namespaced parent
+/// only owns objects in its own namespace
+fn is_owned_by(object_ref: &ObjectRef, object: &Value, parent: &ObjectRef) -> bool {
+    (parent.namespace.is_none() || object_ref.namespace == parent.namespace)
+        && controller(object).is_some_and(|owner| is_owner(owner, parent))
+}
+
+/// the merge patch that turns `live` into `desired`, removing the fields
+/// of `last_applied` that are no longer desired
+fn three_way_patch(last_applied: &Value, desired: &Value, live: &Value) -> Map<String, Value> {
+    let mut patch = Map::new();
+    if let Some(last_applied) = last_applied.as_object() {
+        for key in last_applied.keys() {
+            if desired.get(key).is_none() && live.get(key).is_some() {
+                patch.insert(key.clone(), Value::Null);
+            }
+        }
+    }
+    let Some(desired) = desired.as_object() else {
+        return patch;
+    };
+    for (key, value) in desired {
+        match (value, live.get(key)) {
+            (Value::Object(_), Some(live @ Value::Object(_))) => {
+                let nested = three_way_patch(&last_applied[key], value, live);
+                if !nested.is_empty() {
+                    patch.insert(key.clone(), Value::Object(nested));
+                }
+            }
+            (value, Some(live)) if value == live => {}
+            (value, _) => {
+                patch.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    patch
+}
+
+/// the dotted paths of the fields a patch sets or removes,
+/// leaving out the last-applied annotation that changes with every update
+fn changed_fields(patch: &Value) -> Vec<String> {
+    fn collect(value: &Value, path: &str, fields: &mut Vec<String>) {
+        match value.as_object() {
+            Some(object) if !object.is_empty() => {
+                for (key, value) in object {
+                    let path = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{path}.{key}")
+                    };
+                    collect(value, &path, fields);
+                }
+            }
+            _ => fields.push(path.to_string()),
+        }
+    }
+
+    let mut fields = Vec::new();
+    collect(patch, "", &mut fields);
+    let last_applied = format!("metadata.annotations.{LAST_APPLIED}");
+    fields.retain(|field| *field != last_applied);
+    if fields.is_empty() {
+        fields.push("metadata.annotations".to_string());
+    }
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parent() -> ObjectRef {
+        ObjectRef {
+            api_version: "topo.kubenet.dev/v1alpha1".to_string(),
+            kind: "Topology".to_string(),
+            namespace: Some("default".to_string()),
+            name: "kubenet".to_string(),
+        }
+    }
+
+    fn node(name: &str, spec: Value) -> Value {
+        json!({
+            "apiVersion": "infra.kuid.dev/v1alpha1",
+            "kind": "Node",
+            "metadata": {"name": name, "namespace": "default"},
+            "spec": spec,
+        })
+    }
+
+    /// a store with the parent and the children applied by a first reconcile
+    fn applied(children: &[Value]) -> ObjectStore {
+        let mut objects = ObjectStore::new();
+        objects
+            .insert(json!({
+                "apiVersion": "topo.kubenet.dev/v1alpha1",
+                "kind": "Topology",
+                "metadata": {"name": "kubenet", "namespace": "default"},
+            }))
+            .unwrap();
+        Plan::new(&parent(), children, &objects)
+            .unwrap()
+            .apply(&mut objects)
+            .unwrap();
+        objects
+    }
+
+    #[test]
+    fn three_way_patch_removes_fields_no_longer_desired() {
+        let last_applied = json!({"spec": {"a": 1, "b": 2}});
+        let desired = json!({"spec": {"a": 1}});
+        let live = json!({"spec": {"a": 1, "b": 2, "c": 3}});
+        assert_eq!(
+            Value::Object(three_way_patch(&last_applied, &desired, &live)),
+            json!({"spec": {"b": null}})
+        );
+    }
+
+    #[test]
+    fn three_way_patch_resets_drift_and_keeps_fields_set_by_others() {
+        let last_applied = json!({"spec": {"a": 1}});
+        let desired = json!({"spec": {"a": 1}});
+        let live = json!({"spec": {"a": 2, "c": 3}});
+        assert_eq!(
+            Value::Object(three_way_patch(&last_applied, &desired, &live)),
+            json!({"spec": {"a": 1}})
+        );
+    }
+
+    #[test]
+    fn three_way_patch_is_empty_when_in_sync() {
+        let desired = json!({"spec": {"a": 1, "list": [1, 2]}});
+        let live = json!({"spec": {"a": 1, "list": [1, 2], "c": 3}});
+        assert!(three_way_patch(&desired, &desired, &live).is_empty());
+    }
+
+    #[test]
+    fn changed_fields_are_dotted_paths_without_the_last_applied_annotation() {
+        let patch = json!({
+            "metadata": {"annotations": {LAST_APPLIED: "{}"}},
+            "spec": {"a": 1, "b": null},
+        });
+        assert_eq!(changed_fields(&patch), vec!["spec.a", "spec.b"]);
+
+        let patch = json!({"metadata": {"annotations": {LAST_APPLIED: "{}"}}});
+        assert_eq!(changed_fields(&patch), vec!["metadata.annotations"]);
+    }
+
+    #[test]
+    fn with_owner_records_the_child_without_adding_fields() {
+        let child = with_owner(&node("node1", json!({"a": 1})), &parent());
+        assert_eq!(
+            child["metadata"]["ownerReferences"],
+            json!([owner_reference(&parent())])
+        );
+        let last_applied: Value = serde_json::from_str(
+            child["metadata"]["annotations"][LAST_APPLIED]
+                .as_str()
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(last_applied["metadata"].get("annotations").is_none());
+        assert_eq!(last_applied["spec"], json!({"a": 1}));
+    }
+
+    #[test]
+    fn with_owner_ignores_malformed_metadata() {
+        let child = json!({"metadata": {"name": "node1", "annotations": []}});
+        let applied = with_owner(&child, &parent());
+        assert_eq!(applied["metadata"]["annotations"], json!([]));
+
+        let child = json!({"metadata": "node1"});
+        assert_eq!(with_owner(&child, &parent()), child);
+    }
+
+    #[test]
+    fn plan_creates_missing_children() {
+        let objects = applied(&[]);
+        let plan = Plan::new(&parent(), &[node("node1", json!({}))], &objects).unwrap();
+        assert!(matches!(plan.actions[..], [(_, Action::Create(_))]));
+    }
+
+    #[test]
+    fn plan_updates_changed_children_and_prunes_dropped_ones() {
+        let objects = applied(&[node("node1", json!({"a": 1})), node("node2", json!({}))]);
+        let plan = Plan::new(&parent(), &[node("node1", json!({"a": 2}))], &objects).unwrap();
+
+        let node1 = ObjectRef::from_value(&node("node1", json!({}))).unwrap();
+        let node2 = ObjectRef::from_value(&node("node2", json!({}))).unwrap();
+        assert_eq!(plan.actions.len(), 2);
+        assert!(matches!(
+            &plan.actions[0],
+            (object_ref, Action::Update { fields, .. })
+                if *object_ref == node1 && *fields == vec!["spec.a".to_string()]
+        ));
+        assert_eq!(plan.actions[1], (node2, Action::Delete));
+    }
+
+    #[test]
+    fn plan_leaves_children_in_sync_unchanged() {
+        let children = [node("node1", json!({"a": 1}))];
+        let objects = applied(&children);
+        let plan = Plan::new(&parent(), &children, &objects).unwrap();
+        assert!(plan.is_empty());
+        assert_eq!(plan.unchanged, 1);
+    }
+
+    #[test]
+    fn plan_does_not_take_over_children_of_another_controller() {
+        let mut objects = applied(&[]);
+        let mut child = node("node1", json!({}));
+        child["metadata"]["ownerReferences"] = json!([{
+            "apiVersion": "topo.kubenet.dev/v1alpha1",
+            "kind": "Topology",
+            "name": "other",
+            "controller": true,
+        }]);
+        objects.insert(child).unwrap();
+
+        let result = Plan::new(&parent(), &[node("node1", json!({}))], &objects);
+        assert!(matches!(result, Err(StoreError::Conflict(_))));
+    }
+
+    #[test]
+    fn plan_does_not_prune_objects_owned_in_another_namespace() {
+        let mut objects = applied(&[]);
+        let mut other = node("node1", json!({}));
+        other["metadata"]["namespace"] = json!("other");
+        objects.insert(with_owner(&other, &parent())).unwrap();
+
+        let plan = Plan::new(&parent(), &[], &objects).unwrap();
+        assert!(plan.is_empty());
+    }
+}
@@ -0,0 +1,179 @@
+//! WASI capabilities granted to a component
+//!
+//! The `reconciler` world imports filesystem, environment, clocks and random,
+//! componentize-py even imports all of WASI whether it is used or not. By
+//! default a guest gets none of the host: no preopened directories and no
+//! environment variables. A policy grants exactly what a component needs:
+//!
+//! ```yaml
+//! preopens:
+//!   - host: templates
+//!     guest: /templates
+//!   - host: /var/lib/reconciler
+//!     guest: /state
+//!     mode: rw
+//! env:
+//!   - RUST_BACKTRACE        # the value of the host
+//!   - LOG_FORMAT=json       # a fixed value
+//! clocks: virtual
+//! random: virtual
+//! seed: 42                  # of the virtual random, defaults to 0
+//! ```
+//!
+//! Virtual clocks start at the unix epoch and advance by [`VIRTUAL_TICK`] on
+//! every read, virtual random bytes come from a PRNG seeded with `seed`, so a
+//! guest sees the same time and the same random numbers on every run.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::Deserialize;
+use wasmtime_wasi::{DirPerms, FilePerms, HostMonotonicClock, HostWallClock, WasiCtxBuilder};
+
+/// the time a virtual clock advances on every read
+pub const VIRTUAL_TICK: Duration = Duration::from_millis(1);
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WasiPolicy {
+    /// host directories the guest can open
+    #[serde(default)]
+    pub preopens: Vec<Preopen>,
+    /// environment variables, `NAME` passes the host value, `NAME=value` sets one
+    #[serde(default)]
+    pub env: Vec<String>,
+    #[serde(default)]
+    pub clocks: Source,
+    #[serde(default)]
+    pub random: Source,
+    /// seed of the PRNG behind virtual random
+    #[serde(default)]
+    pub seed: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Preopen {
+    /// directory on the host, relative to the file the policy is read from
+    pub host: PathBuf,
+    /// path the guest opens it with
+    pub guest: String,
+    #[serde(default)]
+    pub mode: Access,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum Access {
+    #[default]
+    #[serde(rename = "ro")]
+    ReadOnly,
+    #[serde(rename = "rw")]
+    ReadWrite,
+}
+
+/// where clock readings or random bytes come from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    /// the host
+    #[default]
+    Real,
+    /// deterministic, the same on every run
+    Virtual,
+}
+
+impl WasiPolicy {
+    /// read a YAML or JSON policy file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("cannot read WASI policy {}", path.display()))?;
+        let mut policy: Self = serde_yaml::from_str(&content)
+            .with_context(|| format!("invalid WASI policy {}", path.display()))?;
+        policy.resolve(path.parent().unwrap_or(Path::new("")));
+        Ok(policy)
+    }
+
+    /// make relative preopen directories relative to `dir`
+    pub fn resolve(&mut self, dir: &Path) {
+        for preopen in &mut self.preopens {
+            preopen.host = dir.join(&preopen.host);
+        }
+    }
+
+    /// grant the capabilities of the policy
+    pub fn apply(&self, builder: &mut WasiCtxBuilder) -> Result<()> {
+        for preopen in &self.preopens {
+            let (dir_perms, file_perms) = match preopen.mode {
+                Access::ReadOnly => (DirPerms::READ, FilePerms::READ),
+                Access::ReadWrite => (DirPerms::all(), FilePerms::all()),
+            };
+            builder
+                .preopened_dir(&preopen.host, &preopen.guest, dir_perms, file_perms)
+                .with_context(|| format!("cannot preopen {}", preopen.host.display()))?;
+        }
+
+        for var in &self.env {
+            match var.split_once('=') {
+                Some((name, value)) => {
+                    builder.env(name, value);
+                }
+                None => {
+                    if let Ok(value) = std::env::var(var) {
+                        builder.env(var, value);
+                    }
+                }
+            }
+        }
+
+        if self.clocks == Source::Virtual {
+            builder
+                .wall_clock(VirtualClock::default())
+                .monotonic_clock(VirtualClock::default());
+        }
+        if self.random == Source::Virtual {
+            builder
+                .secure_random(StdRng::seed_from_u64(self.seed))
+                .insecure_random(StdRng::seed_from_u64(self.seed))
+                .insecure_random_seed(self.seed.into());
+        }
+        Ok(())
+    }
+}
+
+/// a clock at the unix epoch that advances by [`VIRTUAL_TICK`] on every read
+#[derive(Debug, Default)]
+struct VirtualClock {
+    /// nanoseconds since the epoch
+    now: AtomicU64,
+}
+
+impl VirtualClock {
+    fn tick(&self) -> u64 {
+        let tick = VIRTUAL_TICK.as_nanos() as u64;
+        self.now.fetch_add(tick, Ordering::Relaxed) + tick
+    }
+}
+
+impl HostWallClock for VirtualClock {
+    fn resolution(&self) -> Duration {
+        VIRTUAL_TICK
+    }
+
+    fn now(&self) -> Duration {
+        Duration::from_nanos(self.tick())
+    }
+}
+
+impl HostMonotonicClock for VirtualClock {
+    fn resolution(&self) -> u64 {
+        VIRTUAL_TICK.as_nanos() as u64
+    }
+
+    fn now(&self) -> u64 {
+        self.tick()
+    }
+}
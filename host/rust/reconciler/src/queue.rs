@@ -0,0 +1,158 @@
+//! A work queue of object keys that supports delayed requeues
+//!
+//! Modeled after the client-go workqueue: a key is queued at most once, adding a
+//! key that is already ready is a no-op, and a delayed add only ever moves the
+//! deadline of a waiting key earlier.
+
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+#[derive(Debug)]
+pub struct WorkQueue<K> {
+    /// keys ready to be processed, in insertion order
+    ready: VecDeque<K>,
+    ready_set: HashSet<K>,
+    /// keys waiting for their deadline, indexed both ways
+    waiting: BTreeSet<(Instant, K)>,
+    deadlines: HashMap<K, Instant>,
+}
+
+impl<K: Clone + Eq + Hash + Ord> Default for WorkQueue<K> {
+    fn default() -> Self {
+        Self {
+            ready: VecDeque::new(),
+            ready_set: HashSet::new(),
+            waiting: BTreeSet::new(),
+            deadlines: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash + Ord> WorkQueue<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// queue the key for immediate processing
+    pub fn add(&mut self, key: K) {
+        self.remove_waiting(&key);
+        if self.ready_set.insert(key.clone()) {
+            self.ready.push_back(key);
+        }
+    }
+
+    /// queue the key for processing once `delay` has passed
+    pub fn add_after(&mut self, key: K, delay: Duration) {
+        if delay.is_zero() {
+            return self.add(key);
+        }
+        if self.ready_set.contains(&key) {
+            return;
+        }
+        let deadline = Instant::now() + delay;
+        if let Some(existing) = self.deadlines.get(&key) {
+            if *existing <= deadline {
+                return;
+            }
+            self.remove_waiting(&key);
+        }
+        self.deadlines.insert(key.clone(), deadline);
+        self.waiting.insert((deadline, key));
+    }
+
+    /// take the next key that is ready, promoting waiting keys whose deadline has passed
+    pub fn pop(&mut self) -> Option<K> {
+        self.promote(Instant::now());
+        let key = self.ready.pop_front()?;
+        self.ready_set.remove(&key);
+        Some(key)
+    }
+
+    /// the earliest deadline of a waiting key
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.waiting.first().map(|(deadline, _)| *deadline)
+    }
+
+    fn promote(&mut self, now: Instant) {
+        while let Some((deadline, _)) = self.waiting.first() {
+            if *deadline > now {
+                break;
+            }
+            let (_, key) = self.waiting.pop_first().expect("first entry exists");
+            self.deadlines.remove(&key);
+            if self.ready_set.insert(key.clone()) {
+                self.ready.push_back(key);
+            }
+        }
+    }
+
+    fn remove_waiting(&mut self, key: &K) {
+        if let Some(deadline) = self.deadlines.remove(key) {
+            self.waiting.remove(&(deadline, key.clone()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECOND: Duration = Duration::from_secs(1);
+
+    fn drain(queue: &mut WorkQueue<&'static str>) -> Vec<&'static str> {
+        std::iter::from_fn(|| queue.pop()).collect()
+    }
+
+    #[test]
+    fn ready_keys_are_queued_once_in_insertion_order() {
+        let mut queue = WorkQueue::new();
+        queue.add("a");
+        queue.add("b");
+        queue.add("a");
+        assert_eq!(drain(&mut queue), ["a", "b"]);
+
+        queue.add("a");
+        assert_eq!(drain(&mut queue), ["a"]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn delayed_keys_become_ready_at_their_deadline() {
+        let mut queue = WorkQueue::new();
+        queue.add_after("a", SECOND);
+        assert_eq!(queue.pop(), None);
+        assert_eq!(queue.next_deadline(), Some(Instant::now() + SECOND));
+
+        tokio::time::advance(SECOND).await;
+        assert_eq!(drain(&mut queue), ["a"]);
+        assert_eq!(queue.next_deadline(), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn delayed_adds_only_move_the_deadline_earlier() {
+        let mut queue = WorkQueue::new();
+        queue.add_after("a", 10 * SECOND);
+        queue.add_after("a", SECOND);
+        queue.add_after("a", 5 * SECOND);
+        assert_eq!(queue.next_deadline(), Some(Instant::now() + SECOND));
+
+        tokio::time::advance(SECOND).await;
+        assert_eq!(drain(&mut queue), ["a"]);
+        assert_eq!(queue.next_deadline(), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn adding_a_waiting_key_makes_it_ready_once() {
+        let mut queue = WorkQueue::new();
+        queue.add_after("a", SECOND);
+        queue.add("a");
+        assert_eq!(queue.next_deadline(), None);
+
+        queue.add_after("a", SECOND);
+        queue.add_after("a", Duration::ZERO);
+        assert_eq!(drain(&mut queue), ["a"]);
+        assert_eq!(queue.next_deadline(), None);
+    }
+}
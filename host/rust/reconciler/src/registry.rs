@@ -0,0 +1,301 @@
+//! Several reconciler components hosted at once, routed by apiVersion and kind
+//!
+//! A registry config maps the GroupVersionKinds the controller reconciles to
+//! the component that reconciles them:
+//!
+//! ```yaml
+//! components:
+//!   - apiVersion: topo.kubenet.dev/v1alpha1
+//!     kind: Topology
+//!     wasm: topology.wasm
+//!   - apiVersion: infra.kuid.dev/v1alpha1
+//!     kind: Node
+//!     wasm: node.wasm
+//! ```
+//!
+//! Components are named after their file in logs, metrics and traces, so the
+//! files of two components must have different names.
+//!
+//! All components are compiled by one engine, each component gets its own
+//! instance and store. There is a single object store, it is moved into the
+//! store of a component for the duration of a reconcile, so every guest reads
+//! the writes of the others.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+use crate::bindings::ReconcileError;
+use crate::cli::RuntimeArgs;
+use crate::instance::{EpochTicker, ReconcilerInstance};
+use crate::outcome::Outcome;
+use crate::policy::WasiPolicy;
+use crate::reload::{self, Reload};
+use crate::store::{ObjectRef, ObjectStore};
+use crate::{call_reconcile, component_name, load_component, new_engine, new_linker};
+
+/// the reconcile error code of an object no component is registered for
+pub const NO_COMPONENT: u32 = 404;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GroupVersionKind {
+    pub api_version: String,
+    pub kind: String,
+}
+
+impl From<&ObjectRef> for GroupVersionKind {
+    fn from(object_ref: &ObjectRef) -> Self {
+        Self {
+            api_version: object_ref.api_version.clone(),
+            kind: object_ref.kind.clone(),
+        }
+    }
+}
+
+impl fmt::Display for GroupVersionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.api_version, self.kind)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RegistryConfig {
+    pub components: Vec<ComponentEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ComponentEntry {
+    pub api_version: String,
+    pub kind: String,
+    /// path to the component, relative to the registry config
+    pub wasm: PathBuf,
+    /// WASI capabilities of the component, replaces `--wasi-policy`,
+    /// preopened directories are relative to the registry config
+    #[serde(default)]
+    pub wasi: Option<WasiPolicy>,
+}
+
+impl RegistryConfig {
+    /// read the YAML or JSON config, resolving component paths against its directory
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("cannot read registry config {}", path.display()))?;
+        let mut config: Self = serde_yaml::from_str(&content)
+            .with_context(|| format!("invalid registry config {}", path.display()))?;
+
+        if config.components.is_empty() {
+            bail!("registry config {} has no components", path.display());
+        }
+        let dir = path.parent().unwrap_or(Path::new(""));
+        let mut seen = BTreeMap::new();
+        let mut names: BTreeMap<String, PathBuf> = BTreeMap::new();
+        for entry in &mut config.components {
+            entry.wasm = dir.join(&entry.wasm);
+            if let Some(policy) = &mut entry.wasi {
+                policy.resolve(dir);
+            }
+            if let Some(wasm) = seen.insert(entry.gvk(), entry.wasm.clone()) {
+                bail!(
+                    "{} is registered twice, for {} and {}",
+                    entry.gvk(),
+                    wasm.display(),
+                    entry.wasm.display()
+                );
+            }
+            // traces and metrics tell components apart by name only
+            let name = component_name(&entry.wasm);
+            match names.get(&name) {
+                Some(wasm) if *wasm != entry.wasm => bail!(
+                    "{} and {} are both named `{name}`, rename one of them",
+                    wasm.display(),
+                    entry.wasm.display()
+                ),
+                Some(_) => {}
+                None => {
+                    names.insert(name, entry.wasm.clone());
+                }
+            }
+        }
+        Ok(config)
+    }
+}
+
+impl ComponentEntry {
+    fn gvk(&self) -> GroupVersionKind {
+        GroupVersionKind {
+            api_version: self.api_version.clone(),
+            kind: self.kind.clone(),
+        }
+    }
+}
+
+pub struct Registry {
+    /// one instance per component, kinds registered for the same path share it
+    instances: Vec<ReconcilerInstance>,
+    /// component path of each instance
+    paths: Vec<PathBuf>,
+    routes: BTreeMap<GroupVersionKind, usize>,
+    /// instance reconciling every kind without a route, the component given with `--wasm`
+    fallback: Option<usize>,
+    objects: ObjectStore,
+    /// new versions of the components, set once they are watched
+    reloads: Option<UnboundedReceiver<Reload>>,
+}
+
+impl Registry {
+    /// a registry of a single component that reconciles every kind
+    pub fn single(path: PathBuf, mut instance: ReconcilerInstance) -> Self {
+        let objects = std::mem::take(instance.objects_mut());
+        Self {
+            instances: vec![instance],
+            paths: vec![path],
+            routes: BTreeMap::new(),
+            fallback: Some(0),
+            objects,
+            reloads: None,
+        }
+    }
+
+    /// compile and instantiate every component of the config with a shared engine
+    pub async fn load(
+        config: &RegistryConfig,
+        objects: ObjectStore,
+        runtime: &RuntimeArgs,
+    ) -> Result<Self> {
+        let components = config
+            .components
+            .iter()
+            .map(|entry| &entry.wasm)
+            .collect::<BTreeSet<_>>()
+            .len();
+        let engine = new_engine(runtime, components)?;
+        let linker = new_linker(&engine)?;
+        let ticker = Arc::new(EpochTicker::start(engine.clone()));
+        let default_config = runtime.instance_config()?;
+
+        let mut paths: Vec<PathBuf> = Vec::new();
+        let mut instances = Vec::new();
+        let mut routes = BTreeMap::new();
+        let mut policies: Vec<Option<&WasiPolicy>> = Vec::new();
+        for entry in &config.components {
+            let index = match paths.iter().position(|path| *path == entry.wasm) {
+                Some(index) if policies[index] != entry.wasi.as_ref() => bail!(
+                    "{} is registered with different WASI policies, the entries of a component must share one",
+                    entry.wasm.display()
+                ),
+                Some(index) => index,
+                None => {
+                    let mut instance_config = default_config.clone();
+                    if let Some(policy) = &entry.wasi {
+                        instance_config.policy = Arc::new(runtime.policy(policy.clone()));
+                    }
+                    let component = load_component(&engine, &entry.wasm, runtime)?;
+                    let instance = ReconcilerInstance::new(
+                        ticker.clone(),
+                        component_name(&entry.wasm),
+                        component,
+                        linker.clone(),
+                        ObjectStore::new(),
+                        instance_config,
+                    )
+                    .await
+                    .with_context(|| format!("cannot instantiate {}", entry.wasm.display()))?;
+                    paths.push(entry.wasm.clone());
+                    policies.push(entry.wasi.as_ref());
+                    instances.push(instance);
+                    instances.len() - 1
+                }
+            };
+            tracing::info!(gvk = %entry.gvk(), component = %entry.wasm.display(), "registered component");
+            routes.insert(entry.gvk(), index);
+        }
+
+        Ok(Self {
+            instances,
+            paths,
+            routes,
+            fallback: None,
+            objects,
+            reloads: None,
+        })
+    }
+
+    /// watch the component files, new versions are swapped in by [`Registry::apply_reloads`]
+    pub fn watch(&mut self, runtime: &RuntimeArgs) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        for (index, (instance, path)) in self.instances.iter().zip(&self.paths).enumerate() {
+            reload::watch(
+                instance.engine().clone(),
+                runtime.clone(),
+                index,
+                path.clone(),
+                sender.clone(),
+            );
+        }
+        self.reloads = Some(receiver);
+    }
+
+    /// swap in the components that changed since the last call
+    pub async fn apply_reloads(&mut self) {
+        let Some(reloads) = &mut self.reloads else {
+            return;
+        };
+        while let Ok(reload) = reloads.try_recv() {
+            match self.instances[reload.index].replace(reload.pre).await {
+                Ok(()) => tracing::info!(component = %reload.path.display(), "reloaded component"),
+                Err(e) => tracing::error!(
+                    component = %reload.path.display(),
+                    "keeping the current component, the new one cannot be instantiated: {e:#}"
+                ),
+            }
+        }
+    }
+
+    pub fn objects(&self) -> &ObjectStore {
+        &self.objects
+    }
+
+    pub fn objects_mut(&mut self) -> &mut ObjectStore {
+        &mut self.objects
+    }
+
+    /// whether a component is registered for the kind, a fallback component does not count
+    pub fn is_registered(&self, gvk: &GroupVersionKind) -> bool {
+        self.routes.contains_key(gvk)
+    }
+
+    /// reconcile the object with the component registered for its kind,
+    /// an object no component is registered for fails with [`NO_COMPONENT`]
+    pub async fn reconcile(&mut self, object: &Value) -> Result<Outcome, ReconcileError> {
+        let gvk = GroupVersionKind {
+            api_version: object["apiVersion"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            kind: object["kind"].as_str().unwrap_or_default().to_string(),
+        };
+        let index = self
+            .routes
+            .get(&gvk)
+            .copied()
+            .or(self.fallback)
+            .ok_or_else(|| ReconcileError {
+                code: NO_COMPONENT,
+                message: format!("no component is registered for {gvk}"),
+            })?;
+
+        let instance = &mut self.instances[index];
+        *instance.objects_mut() = std::mem::take(&mut self.objects);
+        let result = call_reconcile(instance, object).await;
+        self.objects = std::mem::take(instance.objects_mut());
+        result
+    }
+}
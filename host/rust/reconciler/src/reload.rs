@@ -0,0 +1,95 @@
+//! Hot reload of components whose file changes while the controller runs
+//!
+//! A task per component polls the size and modification time of its file.
+//! Once the file changed and then stayed the same for one more poll, so a
+//! build that is still writing it is not picked up, the component is compiled
+//! and linked on a blocking thread. Only a component that satisfies the
+//! `reconciler` world is sent to the controller, which swaps it in between two
+//! reconciles. A component that fails to compile or link is logged and the
+//! current one keeps reconciling.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use anyhow::Result;
+use tokio::sync::mpsc::UnboundedSender;
+use wasmtime::Engine;
+
+use crate::bindings::ReconcilerPre;
+use crate::cli::RuntimeArgs;
+use crate::host::Ctx;
+use crate::instance::link;
+use crate::{load_component, new_linker};
+
+/// interval at which component files are checked for changes
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// a new version of a component, compiled and linked
+pub struct Reload {
+    /// index of the component in the registry
+    pub index: usize,
+    pub path: PathBuf,
+    pub pre: ReconcilerPre<Ctx>,
+}
+
+/// poll the component file until the receiver of `reloads` is dropped
+pub fn watch(
+    engine: Engine,
+    runtime: RuntimeArgs,
+    index: usize,
+    path: PathBuf,
+    reloads: UnboundedSender<Reload>,
+) {
+    tokio::spawn(async move {
+        let mut loaded = stat(&path);
+        let mut pending = None;
+        let mut interval = tokio::time::interval(POLL_INTERVAL);
+        while !reloads.is_closed() {
+            interval.tick().await;
+            let current = stat(&path);
+            if current.is_none() || current == loaded {
+                pending = None;
+                continue;
+            }
+            if current != pending {
+                pending = current;
+                continue;
+            }
+            loaded = current;
+            pending = None;
+
+            tracing::info!(component = %path.display(), "component changed, recompiling");
+            let (engine, runtime, compiled) = (engine.clone(), runtime.clone(), path.clone());
+            let result = tokio::task::spawn_blocking(move || compile(&engine, &runtime, compiled))
+                .await
+                .map_err(anyhow::Error::from)
+                .and_then(|result| result);
+            match result {
+                Ok(pre) => {
+                    let reload = Reload {
+                        index,
+                        path: path.clone(),
+                        pre,
+                    };
+                    if reloads.send(reload).is_err() {
+                        return;
+                    }
+                }
+                Err(e) => tracing::error!(
+                    component = %path.display(),
+                    "keeping the current component, the new one cannot be loaded: {e:#}"
+                ),
+            }
+        }
+    });
+}
+
+fn compile(engine: &Engine, runtime: &RuntimeArgs, path: PathBuf) -> Result<ReconcilerPre<Ctx>> {
+    let component = load_component(engine, &path, runtime)?;
+    link(&new_linker(engine)?, &component)
+}
+
+fn stat(path: &Path) -> Option<(SystemTime, u64)> {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
@@ -0,0 +1,242 @@
+//! Kubernetes label selectors as used by `retrieve.list-objects`
+//!
+//! Supports the equality-based (`key=value`, `key==value`, `key!=value`) and
+//! set-based (`key in (a,b)`, `key notin (a,b)`, `key`, `!key`) requirements,
+//! separated by commas. All requirements must match.
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Requirement {
+    Equals(String, String),
+    NotEquals(String, String),
+    In(String, Vec<String>),
+    NotIn(String, Vec<String>),
+    Exists(String),
+    NotExists(String),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LabelSelector {
+    requirements: Vec<Requirement>,
+}
+
+impl LabelSelector {
+    /// parse a selector, an empty selector matches every object
+    pub fn parse(selector: &str) -> Result<Self> {
+        let requirements = split_requirements(selector)?
+            .into_iter()
+            .map(parse_requirement)
+            .collect::<Result<_>>()?;
+        Ok(Self { requirements })
+    }
+
+    pub fn matches(&self, labels: &BTreeMap<String, String>) -> bool {
+        self.requirements
+            .iter()
+            .all(|requirement| match requirement {
+                Requirement::Equals(key, value) => labels.get(key) == Some(value),
+                Requirement::NotEquals(key, value) => labels.get(key) != Some(value),
+                Requirement::In(key, values) => labels.get(key).is_some_and(|v| values.contains(v)),
+                Requirement::NotIn(key, values) => {
+                    !labels.get(key).is_some_and(|v| values.contains(v))
+                }
+                Requirement::Exists(key) => labels.contains_key(key),
+                Requirement::NotExists(key) => !labels.contains_key(key),
+            })
+    }
+
+    /// match against the `metadata.labels` of an object
+    pub fn matches_object(&self, object: &Value) -> bool {
+        let labels = object["metadata"]["labels"]
+            .as_object()
+            .map(|labels| {
+                labels
+                    .iter()
+                    .filter_map(|(k, v)| Some((k.clone(), v.as_str()?.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.matches(&labels)
+    }
+}
+
+/// split on the commas that are not inside a `(...)` value set
+fn split_requirements(selector: &str) -> Result<Vec<&str>> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in selector.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => bail!("unbalanced `)` in label selector `{selector}`"),
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&selector[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        bail!("unbalanced `(` in label selector `{selector}`");
+    }
+    parts.push(&selector[start..]);
+
+    Ok(parts
+        .into_iter()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect())
+}
+
+fn parse_requirement(requirement: &str) -> Result<Requirement> {
+    if let Some(key) = requirement.strip_prefix('!') {
+        return Ok(Requirement::NotExists(parse_key(key)?));
+    }
+    if let Some((key, value)) = requirement.split_once("!=") {
+        return Ok(Requirement::NotEquals(
+            parse_key(key)?,
+            value.trim().to_string(),
+        ));
+    }
+    if let Some((key, value)) = requirement
+        .split_once("==")
+        .or_else(|| requirement.split_once('='))
+    {
+        return Ok(Requirement::Equals(
+            parse_key(key)?,
+            value.trim().to_string(),
+        ));
+    }
+    if let Some((key, values)) = requirement.split_once(" notin ") {
+        return Ok(Requirement::NotIn(parse_key(key)?, parse_values(values)?));
+    }
+    if let Some((key, values)) = requirement.split_once(" in ") {
+        return Ok(Requirement::In(parse_key(key)?, parse_values(values)?));
+    }
+    Ok(Requirement::Exists(parse_key(requirement)?))
+}
+
+fn parse_key(key: &str) -> Result<String> {
+    let key = key.trim();
+    if key.is_empty() || key.contains(char::is_whitespace) {
+        bail!("invalid label key `{key}`");
+    }
+    Ok(key.to_string())
+}
+
+/// parse a `(a, b, c)` value set
+fn parse_values(values: &str) -> Result<Vec<String>> {
+    let Some(values) = values
+        .trim()
+        .strip_prefix('(')
+        .and_then(|v| v.strip_suffix(')'))
+    else {
+        bail!("invalid value set `{}`, expected `(a,b)`", values.trim());
+    };
+    Ok(values.split(',').map(|v| v.trim().to_string()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn labels(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn matches(selector: &str, pairs: &[(&str, &str)]) -> bool {
+        LabelSelector::parse(selector)
+            .unwrap()
+            .matches(&labels(pairs))
+    }
+
+    #[test]
+    fn empty_selector_matches_everything() {
+        assert!(matches("", &[]));
+        assert!(matches(" , ", &[("role", "leaf")]));
+    }
+
+    #[test]
+    fn equality_requirements() {
+        assert!(matches("role=leaf", &[("role", "leaf")]));
+        assert!(matches("role == leaf", &[("role", "leaf")]));
+        assert!(!matches("role=leaf", &[("role", "spine")]));
+        assert!(!matches("role=leaf", &[]));
+        assert!(matches("role!=leaf", &[("role", "spine")]));
+        assert!(matches("role!=leaf", &[]));
+        assert!(!matches("role!=leaf", &[("role", "leaf")]));
+    }
+
+    #[test]
+    fn set_requirements() {
+        assert!(matches("role in (leaf, spine)", &[("role", "spine")]));
+        assert!(!matches("role in (leaf,spine)", &[("role", "superspine")]));
+        assert!(!matches("role in (leaf,spine)", &[]));
+        assert!(matches(
+            "role notin (leaf,spine)",
+            &[("role", "superspine")]
+        ));
+        assert!(matches("role notin (leaf,spine)", &[]));
+        assert!(!matches("role notin (leaf,spine)", &[("role", "leaf")]));
+    }
+
+    #[test]
+    fn existence_requirements() {
+        assert!(matches("role", &[("role", "leaf")]));
+        assert!(!matches("role", &[]));
+        assert!(matches("!role", &[]));
+        assert!(!matches("!role", &[("role", "leaf")]));
+    }
+
+    #[test]
+    fn all_requirements_must_match() {
+        let selector = "topo.kubenet.dev/topology=kubenet,role in (leaf,spine),!draining";
+        assert!(matches(
+            selector,
+            &[("topo.kubenet.dev/topology", "kubenet"), ("role", "leaf")]
+        ));
+        assert!(!matches(
+            selector,
+            &[
+                ("topo.kubenet.dev/topology", "kubenet"),
+                ("role", "leaf"),
+                ("draining", "true")
+            ]
+        ));
+    }
+
+    #[test]
+    fn malformed_selectors_are_rejected() {
+        for selector in [
+            "role in (leaf",
+            "role in leaf)",
+            "role in leaf",
+            "=leaf",
+            "!",
+            "my role=leaf",
+        ] {
+            assert!(
+                LabelSelector::parse(selector).is_err(),
+                "`{selector}` should not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn objects_are_matched_on_their_string_labels() {
+        let selector = LabelSelector::parse("role=leaf").unwrap();
+        assert!(selector.matches_object(&json!({"metadata": {"labels": {"role": "leaf"}}})));
+        assert!(!selector.matches_object(&json!({"metadata": {"labels": {"role": 1}}})));
+        assert!(!selector.matches_object(&json!({"metadata": {}})));
+    }
+}
@@ -0,0 +1,488 @@
+//! In-memory object store that guests can query through the `retrieve` interface
+//!
+//! Objects are keyed by apiVersion, kind, namespace and name, the same way the
+//! Kubernetes API server identifies them. Every stored object carries a
+//! `metadata.resourceVersion` that changes on each write, writes that name an
+//! outdated resource version fail with a conflict.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+
+use crate::selector::LabelSelector;
+
+/// fully qualified reference to an object
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ObjectRef {
+    pub api_version: String,
+    pub kind: String,
+    pub namespace: Option<String>,
+    pub name: String,
+}
+
+impl ObjectRef {
+    /// build the reference from the `apiVersion`, `kind` and `metadata` of an object
+    pub fn from_value(value: &Value) -> Result<Self> {
+        let field = |value: &Value, name: &str| {
+            value[name]
+                .as_str()
+                .map(str::to_string)
+                .with_context(|| format!("object has no {name}"))
+        };
+        Ok(Self {
+            api_version: field(value, "apiVersion")?,
+            kind: field(value, "kind")?,
+            namespace: value["metadata"]["namespace"].as_str().map(str::to_string),
+            name: field(&value["metadata"], "name").context("object has no metadata")?,
+        })
+    }
+}
+
+impl fmt::Display for ObjectRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}/{}/{}",
+            self.api_version,
+            self.kind,
+            self.namespace.as_deref().unwrap_or_default(),
+            self.name
+        )
+    }
+}
+
+/// a failed write to the store
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StoreError {
+    NotFound(ObjectRef),
+    AlreadyExists(ObjectRef),
+    /// the message names the expected and the stored resource version
+    Conflict(String),
+    Invalid(String),
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(object_ref) => write!(f, "{object_ref} not found"),
+            Self::AlreadyExists(object_ref) => write!(f, "{object_ref} already exists"),
+            Self::Conflict(msg) | Self::Invalid(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Create,
+    Update,
+    Patch,
+    Delete,
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Create => "created",
+            Self::Update => "updated",
+            Self::Patch => "patched",
+            Self::Delete => "deleted",
+        })
+    }
+}
+
+/// a successful write, recorded so the host can report what a reconcile changed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub operation: Operation,
+    pub object_ref: ObjectRef,
+    /// resource version written, none for deletes
+    pub resource_version: Option<String>,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.operation, self.object_ref)?;
+        if let Some(resource_version) = &self.resource_version {
+            write!(f, " (resourceVersion {resource_version})")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ObjectStore {
+    objects: BTreeMap<ObjectRef, Value>,
+    /// last resource version handed out
+    version: u64,
+    /// writes since the changes were last taken
+    changes: Vec<Change>,
+}
+
+impl ObjectStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, object_ref: &ObjectRef) -> Option<&Value> {
+        self.objects.get(object_ref)
+    }
+
+    /// the objects of `kind` of every apiVersion, optionally restricted to a namespace,
+    /// whose labels match the selector, sorted by namespace, name and apiVersion
+    pub fn list(
+        &self,
+        kind: &str,
+        namespace: Option<&str>,
+        selector: &LabelSelector,
+    ) -> Vec<&Value> {
+        let mut objects: Vec<_> = self
+            .objects
+            .iter()
+            .filter(|(object_ref, object)| {
+                object_ref.kind == kind
+                    && namespace.is_none_or(|ns| object_ref.namespace.as_deref() == Some(ns))
+                    && selector.matches_object(object)
+            })
+            .collect();
+        objects.sort_by(|(a, _), (b, _)| {
+            (&a.namespace, &a.name, &a.api_version).cmp(&(&b.namespace, &b.name, &b.api_version))
+        });
+        objects.into_iter().map(|(_, object)| object).collect()
+    }
+
+    /// all stored objects, sorted by reference
+    pub fn iter(&self) -> impl Iterator<Item = (&ObjectRef, &Value)> {
+        self.objects.iter()
+    }
+
+    /// add the object or replace the stored object with the same reference,
+    /// used to seed the store so the write is not recorded as a change
+    pub fn insert(&mut self, mut object: Value) -> Result<ObjectRef> {
+        let object_ref = ObjectRef::from_value(&object)?;
+        self.set_resource_version(&mut object);
+        self.objects.insert(object_ref.clone(), object);
+        Ok(object_ref)
+    }
+
+    /// store a new object, returning it with its resource version
+    pub fn create(&mut self, mut object: Value) -> Result<Value, StoreError> {
+        let object_ref = parse_ref(&object)?;
+        if self.objects.contains_key(&object_ref) {
+            return Err(StoreError::AlreadyExists(object_ref));
+        }
+        if resource_version(&object).is_some() {
+            return Err(StoreError::Invalid(format!(
+                "{object_ref}: resourceVersion must not be set on create"
+            )));
+        }
+
+        self.set_resource_version(&mut object);
+        self.commit(Operation::Create, object_ref, object)
+    }
+
+    /// replace a stored object, if the object carries a resource version
+    /// it must match the stored one
+    pub fn update(&mut self, mut object: Value) -> Result<Value, StoreError> {
+        let object_ref = parse_ref(&object)?;
+        self.current(&object_ref, resource_version(&object))?;
+
+        self.set_resource_version(&mut object);
+        self.commit(Operation::Update, object_ref, object)
+    }
+
+    /// apply a JSON merge patch (RFC 7386) to a stored object, if the patch
+    /// carries a resource version it must match the stored one
+    pub fn patch(&mut self, object_ref: &ObjectRef, patch: &Value) -> Result<Value, StoreError> {
+        if !patch.is_object() {
+            return Err(StoreError::Invalid(
+                "patch must be a JSON object".to_string(),
+            ));
+        }
+        let mut object = self.current(object_ref, resource_version(patch))?.clone();
+        merge_patch(&mut object, patch);
+        if parse_ref(&object)? != *object_ref {
+            return Err(StoreError::Invalid(format!(
+                "{object_ref}: patch must not change apiVersion, kind, namespace or name"
+            )));
+        }
+
+        self.set_resource_version(&mut object);
+        self.commit(Operation::Patch, object_ref.clone(), object)
+    }
+
+    /// remove a stored object, if a resource version is given it must match the stored one
+    pub fn delete(
+        &mut self,
+        object_ref: &ObjectRef,
+        resource_version: Option<&str>,
+    ) -> Result<Value, StoreError> {
+        self.current(object_ref, resource_version)?;
+        let object = self
+            .objects
+            .remove(object_ref)
+            .expect("current checked the object exists");
+        self.changes.push(Change {
+            operation: Operation::Delete,
+            object_ref: object_ref.clone(),
+            resource_version: None,
+        });
+        Ok(object)
+    }
+
+    /// the writes since the last call
+    pub fn take_changes(&mut self) -> Vec<Change> {
+        std::mem::take(&mut self.changes)
+    }
+
+    pub fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    /// the stored object, checking the expected resource version if there is one
+    fn current(
+        &self,
+        object_ref: &ObjectRef,
+        expected: Option<&str>,
+    ) -> Result<&Value, StoreError> {
+        let current = self
+            .objects
+            .get(object_ref)
+            .ok_or_else(|| StoreError::NotFound(object_ref.clone()))?;
+        if let Some(expected) = expected {
+            let actual = resource_version(current).unwrap_or_default();
+            if expected != actual {
+                return Err(StoreError::Conflict(format!(
+                    "{object_ref} has resourceVersion {actual}, the write expected {expected}"
+                )));
+            }
+        }
+        Ok(current)
+    }
+
+    fn set_resource_version(&mut self, object: &mut Value) {
+        self.version += 1;
+        object["metadata"]["resourceVersion"] = Value::String(self.version.to_string());
+    }
+
+    fn commit(
+        &mut self,
+        operation: Operation,
+        object_ref: ObjectRef,
+        object: Value,
+    ) -> Result<Value, StoreError> {
+        self.changes.push(Change {
+            operation,
+            object_ref: object_ref.clone(),
+            resource_version: resource_version(&object).map(str::to_string),
+        });
+        self.objects.insert(object_ref, object.clone());
+        Ok(object)
+    }
+}
+
+fn parse_ref(object: &Value) -> Result<ObjectRef, StoreError> {
+    ObjectRef::from_value(object).map_err(|e| StoreError::Invalid(e.to_string()))
+}
+
+fn resource_version(object: &Value) -> Option<&str> {
+    object["metadata"]["resourceVersion"].as_str()
+}
+
+/// apply a JSON merge patch as described in RFC 7386
+fn merge_patch(target: &mut Value, patch: &Value) {
+    let Some(patch) = patch.as_object() else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Default::default());
+    }
+    let target = target.as_object_mut().expect("target is an object");
+    for (key, value) in patch {
+        if value.is_null() {
+            target.remove(key);
+        } else {
+            merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn node(namespace: &str, name: &str) -> Value {
+        json!({
+            "apiVersion": "infra.kuid.dev/v1alpha1",
+            "kind": "Node",
+            "metadata": {"name": name, "namespace": namespace},
+        })
+    }
+
+    fn node_ref(namespace: &str, name: &str) -> ObjectRef {
+        ObjectRef::from_value(&node(namespace, name)).unwrap()
+    }
+
+    fn names(objects: Vec<&Value>) -> Vec<String> {
+        objects
+            .iter()
+            .map(|object| {
+                format!(
+                    "{}/{}/{}",
+                    object["apiVersion"].as_str().unwrap(),
+                    object["metadata"]["namespace"].as_str().unwrap(),
+                    object["metadata"]["name"].as_str().unwrap()
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_write_gets_a_new_resource_version() {
+        let mut store = ObjectStore::new();
+        let created = store.create(node("default", "node1")).unwrap();
+        assert_eq!(created["metadata"]["resourceVersion"], "1");
+
+        let updated = store.update(created).unwrap();
+        assert_eq!(updated["metadata"]["resourceVersion"], "2");
+
+        let patched = store
+            .patch(&node_ref("default", "node1"), &json!({"spec": {"x": 1}}))
+            .unwrap();
+        assert_eq!(patched["metadata"]["resourceVersion"], "3");
+        assert_eq!(store.take_changes().len(), 3);
+        assert!(store.take_changes().is_empty());
+    }
+
+    #[test]
+    fn writes_with_an_outdated_resource_version_conflict() {
+        let mut store = ObjectStore::new();
+        let created = store.create(node("default", "node1")).unwrap();
+        store.update(created.clone()).unwrap();
+
+        assert!(matches!(
+            store.update(created),
+            Err(StoreError::Conflict(_))
+        ));
+        let object_ref = node_ref("default", "node1");
+        assert!(matches!(
+            store.patch(&object_ref, &json!({"metadata": {"resourceVersion": "1"}})),
+            Err(StoreError::Conflict(_))
+        ));
+        assert!(matches!(
+            store.delete(&object_ref, Some("1")),
+            Err(StoreError::Conflict(_))
+        ));
+        assert!(store.delete(&object_ref, Some("2")).is_ok());
+    }
+
+    #[test]
+    fn writes_without_a_resource_version_always_apply() {
+        let mut store = ObjectStore::new();
+        store.create(node("default", "node1")).unwrap();
+        store.create(node("default", "node1")).unwrap_err();
+
+        assert!(store.update(node("default", "node1")).is_ok());
+        assert!(store.delete(&node_ref("default", "node1"), None).is_ok());
+        assert_eq!(
+            store.delete(&node_ref("default", "node1"), None),
+            Err(StoreError::NotFound(node_ref("default", "node1")))
+        );
+    }
+
+    #[test]
+    fn create_rejects_a_resource_version_and_duplicates() {
+        let mut store = ObjectStore::new();
+        let mut object = node("default", "node1");
+        object["metadata"]["resourceVersion"] = json!("7");
+        assert!(matches!(store.create(object), Err(StoreError::Invalid(_))));
+
+        store.create(node("default", "node1")).unwrap();
+        assert_eq!(
+            store.create(node("default", "node1")),
+            Err(StoreError::AlreadyExists(node_ref("default", "node1")))
+        );
+    }
+
+    #[test]
+    fn merge_patch_follows_rfc_7386() {
+        let mut target = json!({"a": "b", "c": {"d": "e", "f": "g"}, "list": [1, 2]});
+        merge_patch(
+            &mut target,
+            &json!({"a": "z", "c": {"f": null}, "list": [3], "new": {"x": 1}}),
+        );
+        assert_eq!(
+            target,
+            json!({"a": "z", "c": {"d": "e"}, "list": [3], "new": {"x": 1}})
+        );
+
+        let mut target = json!({"a": "b"});
+        merge_patch(&mut target, &json!(["c"]));
+        assert_eq!(target, json!(["c"]));
+
+        let mut target = json!("scalar");
+        merge_patch(&mut target, &json!({"a": {"b": null}}));
+        assert_eq!(target, json!({"a": {}}));
+    }
+
+    #[test]
+    fn patch_must_keep_the_identity_of_the_object() {
+        let mut store = ObjectStore::new();
+        store.create(node("default", "node1")).unwrap();
+        let object_ref = node_ref("default", "node1");
+        assert!(matches!(
+            store.patch(&object_ref, &json!({"metadata": {"name": "node2"}})),
+            Err(StoreError::Invalid(_))
+        ));
+        assert!(matches!(
+            store.patch(&object_ref, &json!("spec")),
+            Err(StoreError::Invalid(_))
+        ));
+        assert_eq!(
+            store.get(&object_ref).unwrap()["metadata"]["resourceVersion"],
+            "1"
+        );
+    }
+
+    #[test]
+    fn list_filters_by_kind_namespace_and_labels_in_namespace_and_name_order() {
+        let mut store = ObjectStore::new();
+        for (namespace, name) in [("b", "node1"), ("a", "node2"), ("a", "node1")] {
+            store.insert(node(namespace, name)).unwrap();
+        }
+        let mut v2 = node("a", "node1");
+        v2["apiVersion"] = json!("infra.kuid.dev/v1beta1");
+        store.insert(v2).unwrap();
+        let mut labeled = node("a", "node3");
+        labeled["metadata"]["labels"] = json!({"role": "leaf"});
+        store.insert(labeled).unwrap();
+        store
+            .insert(json!({"apiVersion": "v1", "kind": "Link", "metadata": {"name": "link1", "namespace": "a"}}))
+            .unwrap();
+
+        let all = LabelSelector::default();
+        assert_eq!(
+            names(store.list("Node", None, &all)),
+            [
+                "infra.kuid.dev/v1alpha1/a/node1",
+                "infra.kuid.dev/v1beta1/a/node1",
+                "infra.kuid.dev/v1alpha1/a/node2",
+                "infra.kuid.dev/v1alpha1/a/node3",
+                "infra.kuid.dev/v1alpha1/b/node1",
+            ]
+        );
+        assert_eq!(store.list("Node", Some("b"), &all).len(), 1);
+
+        let leaves = LabelSelector::parse("role=leaf").unwrap();
+        assert_eq!(
+            names(store.list("Node", Some("a"), &leaves)),
+            ["infra.kuid.dev/v1alpha1/a/node3"]
+        );
+    }
+}
@@ -0,0 +1,357 @@
+//! Recorded reconciles, replayed to reproduce them offline
+//!
+//! With `--record <dir>` every reconcile call writes a [`Trace`] to the
+//! directory: the input object, each call the guest made to the `retrieve`,
+//! `writer` and `logging` imports with its arguments and result, what the
+//! guest wrote to stdout and stderr and the result of the call. `replay`
+//! calls a component with the recorded input and answers its imports from the
+//! recording instead of an object store. The first import call that does not
+//! match the recording ends the replay, it and every later call are answered by
+//! an empty object store, and any difference to the recording is reported.
+//!
+//! WASI clocks and random are not recorded, a guest that uses them replays
+//! exactly only when recorded and replayed with `--deterministic`.
+
+use std::collections::VecDeque;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::bindings::{ReconcileError, ReconcileResult};
+use crate::output::GuestOutput;
+
+/// the start time and process id of this process, same for all its traces
+fn run_id() -> &'static str {
+    static RUN_ID: OnceLock<String> = OnceLock::new();
+    RUN_ID.get_or_init(|| {
+        let started = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        format!("{}-{}", started.as_secs(), std::process::id())
+    })
+}
+
+/// one reconcile call
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Trace {
+    pub component: String,
+    /// the reconciled object
+    pub key: String,
+    pub input: Value,
+    pub calls: Vec<ImportCall>,
+    #[serde(default)]
+    pub stdout: String,
+    #[serde(default)]
+    pub stderr: String,
+    pub result: Result<ReconcileResult, ReconcileError>,
+}
+
+/// a call of the guest to a host import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportCall {
+    /// `<interface>.<function>`, e.g. `retrieve.get`
+    pub function: String,
+    /// the arguments by name
+    pub args: Value,
+    pub result: Value,
+}
+
+/// how the host answers the import calls of a guest
+#[derive(Debug, Default)]
+pub enum Imports {
+    /// from the object store
+    #[default]
+    Live,
+    /// from the object store, every call is recorded
+    Record(Vec<ImportCall>),
+    /// from a recording until the guest makes a call that was not recorded
+    Replay {
+        calls: VecDeque<ImportCall>,
+        /// the call that did not match the recording
+        diverged: Option<String>,
+    },
+}
+
+impl Imports {
+    /// the recorded result of a call, none when the call is answered live
+    pub fn replay<T: DeserializeOwned>(&mut self, function: &str, args: &Value) -> Option<T> {
+        let Self::Replay { calls, diverged } = self else {
+            return None;
+        };
+        if diverged.is_some() {
+            return None;
+        }
+        let divergence = match calls.pop_front() {
+            Some(call) if call.function == function && call.args == *args => {
+                match serde_json::from_value(call.result) {
+                    Ok(result) => return Some(result),
+                    Err(e) => format!("the recorded result of {function} is invalid: {e}"),
+                }
+            }
+            Some(call) => format!(
+                "the guest called {function}({args}), the recording has {}({})",
+                call.function, call.args
+            ),
+            None => format!("the guest called {function}({args}) after the last recorded call"),
+        };
+        *diverged = Some(divergence);
+        None
+    }
+
+    /// record a call that was answered live
+    pub fn record<T: Serialize>(&mut self, function: &str, args: Value, result: &T) {
+        if let Self::Record(calls) = self {
+            calls.push(ImportCall {
+                function: function.to_string(),
+                args,
+                result: serde_json::to_value(result).expect("import results serialize"),
+            });
+        }
+    }
+}
+
+impl Trace {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("cannot read trace {}", path.display()))?;
+        serde_json::from_str(&content).with_context(|| format!("invalid trace {}", path.display()))
+    }
+
+    /// the argument of the reconcile call
+    pub fn input_json(&self) -> String {
+        match &self.input {
+            Value::String(input) => input.clone(),
+            input => input.to_string(),
+        }
+    }
+
+    /// write the trace to `<dir>/<component>-<run>-<iteration>.json`, the run
+    /// keeps processes recording to the same directory apart, an existing trace
+    /// is never overwritten
+    pub fn write(&self, dir: &Path, iteration: u64) -> Result<PathBuf> {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("cannot create trace directory {}", dir.display()))?;
+        let path = dir.join(format!(
+            "{}-{}-{iteration:06}.json",
+            self.component,
+            run_id()
+        ));
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all((content + "\n").as_bytes()))
+            .with_context(|| format!("cannot write trace {}", path.display()))?;
+        Ok(path)
+    }
+
+    /// how a replay of the trace differs from the recording, empty when it does not
+    pub fn divergences(
+        &self,
+        result: &Result<ReconcileResult, ReconcileError>,
+        output: &GuestOutput,
+        imports: &Imports,
+    ) -> Vec<String> {
+        let mut divergences = Vec::new();
+        if let Imports::Replay { calls, diverged } = imports {
+            match diverged {
+                Some(divergence) => divergences.push(divergence.clone()),
+                None => {
+                    if let Some(call) = calls.front() {
+                        divergences.push(format!(
+                            "the guest made {} fewer import calls, the first missing is {}({})",
+                            calls.len(),
+                            call.function,
+                            call.args
+                        ));
+                    }
+                }
+            }
+        }
+        for (stream, recorded, replayed) in [
+            ("stdout", &self.stdout, &output.stdout),
+            ("stderr", &self.stderr, &output.stderr),
+        ] {
+            if recorded != replayed {
+                divergences.push(format!(
+                    "{stream} differs, recorded {recorded:?}, replayed {replayed:?}"
+                ));
+            }
+        }
+        let (recorded, replayed) = (render(&self.result), render(result));
+        if recorded != replayed {
+            divergences.push(format!(
+                "result differs, recorded {recorded}, replayed {replayed}"
+            ));
+        }
+        divergences
+    }
+}
+
+fn render(result: &Result<ReconcileResult, ReconcileError>) -> Value {
+    serde_json::to_value(result).expect("reconcile results serialize")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn call(function: &str, args: Value, result: Value) -> ImportCall {
+        ImportCall {
+            function: function.to_string(),
+            args,
+            result,
+        }
+    }
+
+    fn replay(calls: Vec<ImportCall>) -> Imports {
+        Imports::Replay {
+            calls: calls.into(),
+            diverged: None,
+        }
+    }
+
+    fn trace(calls: Vec<ImportCall>) -> Trace {
+        Trace {
+            component: "reconciler".to_string(),
+            key: "default/node1".to_string(),
+            input: json!({}),
+            calls,
+            stdout: String::new(),
+            stderr: String::new(),
+            result: Err(ReconcileError {
+                code: 404,
+                message: "not found".to_string(),
+            }),
+        }
+    }
+
+    #[test]
+    fn record_keeps_calls_in_order() {
+        let mut imports = Imports::Record(Vec::new());
+        imports.record("retrieve.get", json!({"name": "node1"}), &Some("{}"));
+        imports.record("logging.log", json!({"message": "hi"}), &());
+        let Imports::Record(calls) = imports else {
+            unreachable!()
+        };
+        let functions: Vec<_> = calls.iter().map(|call| call.function.as_str()).collect();
+        assert_eq!(functions, ["retrieve.get", "logging.log"]);
+        assert_eq!(calls[0].result, json!("{}"));
+
+        let mut live = Imports::Live;
+        live.record("retrieve.get", json!({}), &1);
+        assert!(matches!(live, Imports::Live));
+    }
+
+    #[test]
+    fn replay_answers_calls_in_recorded_order() {
+        let mut imports = replay(vec![
+            call("retrieve.get", json!({"name": "node1"}), json!(1)),
+            call("retrieve.get", json!({"name": "node2"}), json!(2)),
+        ]);
+        assert_eq!(
+            imports.replay::<u32>("retrieve.get", &json!({"name": "node1"})),
+            Some(1)
+        );
+        assert_eq!(
+            imports.replay::<u32>("retrieve.get", &json!({"name": "node2"})),
+            Some(2)
+        );
+        assert!(trace(Vec::new())
+            .divergences(&trace(Vec::new()).result, &GuestOutput::default(), &imports)
+            .is_empty());
+        assert_eq!(
+            Imports::Live.replay::<u32>("retrieve.get", &json!({})),
+            None
+        );
+    }
+
+    #[test]
+    fn replay_reports_a_call_out_of_order() {
+        let mut imports = replay(vec![
+            call("retrieve.get", json!({"name": "node1"}), json!(1)),
+            call("retrieve.get", json!({"name": "node2"}), json!(2)),
+        ]);
+        assert_eq!(
+            imports.replay::<u32>("retrieve.get", &json!({"name": "node2"})),
+            None
+        );
+        // once diverged every later call is answered live, even a recorded one
+        assert_eq!(
+            imports.replay::<u32>("retrieve.get", &json!({"name": "node2"})),
+            None
+        );
+        let trace = trace(Vec::new());
+        assert_eq!(
+            trace.divergences(&trace.result, &GuestOutput::default(), &imports),
+            [
+                r#"the guest called retrieve.get({"name":"node2"}), the recording has retrieve.get({"name":"node1"})"#
+            ]
+        );
+    }
+
+    #[test]
+    fn replay_reports_extra_and_missing_calls() {
+        let mut imports = replay(Vec::new());
+        assert_eq!(imports.replay::<u32>("retrieve.get", &json!({})), None);
+        let Imports::Replay { diverged, .. } = &imports else {
+            unreachable!()
+        };
+        assert_eq!(
+            diverged.as_deref(),
+            Some("the guest called retrieve.get({}) after the last recorded call")
+        );
+
+        let imports = replay(vec![call(
+            "logging.log",
+            json!({"message": "hi"}),
+            json!(null),
+        )]);
+        let trace = trace(Vec::new());
+        assert_eq!(
+            trace.divergences(&trace.result, &GuestOutput::default(), &imports),
+            [
+                r#"the guest made 1 fewer import calls, the first missing is logging.log({"message":"hi"})"#
+            ]
+        );
+    }
+
+    #[test]
+    fn replay_reports_an_invalid_recorded_result() {
+        let mut imports = replay(vec![call("retrieve.get", json!({}), json!("one"))]);
+        assert_eq!(imports.replay::<u32>("retrieve.get", &json!({})), None);
+        let Imports::Replay { diverged, .. } = &imports else {
+            unreachable!()
+        };
+        assert!(diverged
+            .as_deref()
+            .unwrap()
+            .starts_with("the recorded result of retrieve.get is invalid"));
+    }
+
+    #[test]
+    fn replay_reports_different_output_and_result() {
+        let trace = trace(Vec::new());
+        let output = GuestOutput {
+            stdout: "hello\n".to_string(),
+            ..GuestOutput::default()
+        };
+        let result = Err(ReconcileError {
+            code: 500,
+            message: "failed".to_string(),
+        });
+        let divergences = trace.divergences(&result, &output, &replay(Vec::new()));
+        assert_eq!(divergences.len(), 2);
+        assert!(divergences[0].starts_with("stdout differs"));
+        assert!(divergences[1].starts_with("result differs"));
+    }
+}